@@ -0,0 +1,59 @@
+// src/api/clients/getclienthealth.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ClientHealth {
+    pub site_id: Option<String>,
+    pub score_detail: Option<Vec<ScoreDetail>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ScoreDetail {
+    pub score_category: Option<ScoreCategory>,
+    pub score_value: Option<f64>,
+    pub client_count: Option<i64>,
+    pub client_unique_count: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ScoreCategory {
+    pub score_category: Option<String>,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClientHealthResponse {
+    response: Vec<ClientHealth>,
+}
+
+/// Overall client health scores, broken down by client type (ALL, WIRED, WIRELESS).
+pub async fn get_client_health(config: &Config, token: &Token) -> Result<Vec<ClientHealth>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/client-health", config.dnac_url);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve client health: {}", resp.status()));
+    }
+
+    Ok(resp.json::<ClientHealthResponse>().await?.response)
+}
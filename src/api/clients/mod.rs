@@ -1,2 +1,3 @@
 pub mod getclientdetail;
 pub mod getclientenrichment;
+pub mod getclienthealth;
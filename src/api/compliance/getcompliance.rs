@@ -0,0 +1,124 @@
+// src/api/compliance/getcompliance.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+/// Compliance of one device for one compliance type.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ComplianceDetail {
+    pub device_uuid: Option<String>,
+    pub compliance_type: Option<String>,
+    pub status: Option<String>,
+    pub state: Option<String>,
+    pub category: Option<String>,
+    pub display_name: Option<String>,
+    pub last_sync_time: Option<i64>,
+    pub last_update_time: Option<i64>,
+    pub remediation_supported: Option<bool>,
+    #[serde(default)]
+    pub source_info_list: Vec<SourceInfo>,
+}
+
+/// What a compliance type checked, with the differences it found.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SourceInfo {
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+    pub source_enum: Option<String>,
+    #[serde(rename = "type")]
+    pub source_type: Option<String>,
+    pub count: Option<i64>,
+    #[serde(default)]
+    pub diff_list: Vec<ComplianceDiff>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ComplianceDiff {
+    pub op: Option<String>,
+    pub path: Option<String>,
+    pub display_name: Option<String>,
+    pub configured_value: Option<String>,
+    pub intended_value: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ComplianceDetailResponse {
+    response: Vec<ComplianceDetail>,
+}
+
+fn client(config: &Config) -> Result<Client> {
+    Ok(Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?)
+}
+
+/// Compliance of every device, one entry per device and compliance type.
+/// `compliance_type` limits the result to one type, e.g. `RUNNING_CONFIG`.
+pub async fn get_all_compliance(
+    config: &Config,
+    token: &Token,
+    compliance_type: Option<&str>,
+) -> Result<Vec<ComplianceDetail>> {
+    let client = client(config)?;
+    let url = format!("{}/dna/intent/api/v1/compliance/detail", config.dnac_url);
+
+    let mut all_details: Vec<ComplianceDetail> = Vec::new();
+    let mut offset = 1;
+    let limit = 500;
+
+    loop {
+        let mut query = vec![("offset", offset.to_string()), ("limit", limit.to_string())];
+        if let Some(compliance_type) = compliance_type {
+            query.push(("complianceType", compliance_type.to_string()));
+        }
+
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .query(&query)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve compliance: {}", resp.status()));
+        }
+
+        let details = resp.json::<ComplianceDetailResponse>().await?.response;
+        let count = details.len();
+        all_details.extend(details);
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_details)
+}
+
+/// Compliance of one device for each compliance type, with the differences found.
+pub async fn get_device_compliance(config: &Config, token: &Token, device_id: &str) -> Result<Vec<ComplianceDetail>> {
+    let url = format!("{}/dna/intent/api/v1/compliance/{}/detail", config.dnac_url, device_id);
+
+    let resp = client(config)?
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("diffList", "true")])
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve device compliance: {}", resp.status()));
+    }
+
+    Ok(resp.json::<ComplianceDetailResponse>().await?.response)
+}
@@ -0,0 +1,4 @@
+// src/api/compliance/mod.rs
+
+pub mod getcompliance;
+pub mod runcompliance;
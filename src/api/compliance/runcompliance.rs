@@ -0,0 +1,54 @@
+// src/api/compliance/runcompliance.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ComplianceRunRequest<'a> {
+    trigger_full: bool,
+    device_uuids: &'a [String],
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    categories: &'a [String],
+}
+
+/// Start a compliance check of the given devices. An empty `categories`
+/// checks every compliance type.
+pub async fn run_compliance(
+    config: &Config,
+    token: &Token,
+    device_ids: &[String],
+    categories: &[String],
+) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/compliance/", config.dnac_url);
+    let request = ComplianceRunRequest {
+        trigger_full: categories.is_empty(),
+        device_uuids: device_ids,
+        categories,
+    };
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to start compliance check: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
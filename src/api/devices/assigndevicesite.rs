@@ -0,0 +1,45 @@
+// src/api/devices/assigndevicesite.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AssignRequest<'a> {
+    device_ids: [&'a str; 1],
+    site_id: &'a str,
+}
+
+/// Assign a device to a site (a building or floor).
+pub async fn assign_to_site(config: &Config, token: &Token, device_id: &str, site_id: &str) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/networkDevices/assignToSite/apply", config.dnac_url);
+    let request = AssignRequest {
+        device_ids: [device_id],
+        site_id,
+    };
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to assign device to site: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -0,0 +1,34 @@
+// src/api/devices/deletedevice.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+
+/// Remove a device from inventory. With `clean_config`, the configuration
+/// Catalyst Center pushed to it is removed first.
+pub async fn delete_device(config: &Config, token: &Token, device_id: &str, clean_config: bool) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/network-device/{}", config.dnac_url, device_id);
+
+    let resp = client
+        .delete(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("cleanConfig", clean_config.to_string())])
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to delete device: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -0,0 +1,143 @@
+// src/api/devices/getdeviceconfig.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+struct RunningConfigResponse {
+    response: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ConfigArchive {
+    pub device_id: Option<String>,
+    pub device_name: Option<String>,
+    pub ip_address: Option<String>,
+    #[serde(default)]
+    pub versions: Vec<ArchiveVersion>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ArchiveVersion {
+    pub id: String,
+    pub created_by: Option<String>,
+    pub created_time: Option<i64>,
+    pub startup_running_status: Option<String>,
+    #[serde(default)]
+    pub files: Vec<ArchiveFile>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ArchiveFile {
+    pub file_id: String,
+    pub file_type: Option<String>,
+    pub download_path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfigArchiveResponse {
+    response: Vec<ConfigArchive>,
+}
+
+fn client(config: &Config) -> Result<Client> {
+    Ok(Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?)
+}
+
+/// The device's current running config, as last collected by Catalyst Center.
+pub async fn get_running_config(config: &Config, token: &Token, device_id: &str) -> Result<String> {
+    let url = format!("{}/dna/intent/api/v1/network-device/{}/config", config.dnac_url, device_id);
+
+    let resp = client(config)?
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve running config: {}", resp.status()));
+    }
+
+    Ok(resp.json::<RunningConfigResponse>().await?.response)
+}
+
+/// Archived config versions of a device, newest first.
+pub async fn get_config_archive(config: &Config, token: &Token, device_id: &str) -> Result<Vec<ArchiveVersion>> {
+    let url = format!("{}/dna/intent/api/v1/network-device-config", config.dnac_url);
+
+    let resp = client(config)?
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("deviceId", device_id)])
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve config archive: {}", resp.status()));
+    }
+
+    let mut versions: Vec<ArchiveVersion> = resp
+        .json::<ConfigArchiveResponse>()
+        .await?
+        .response
+        .into_iter()
+        .flat_map(|archive| archive.versions)
+        .collect();
+    versions.sort_by_key(|version| std::cmp::Reverse(version.created_time));
+    Ok(versions)
+}
+
+/// Contents of an archived config file, with secrets masked.
+pub async fn download_config_file(config: &Config, token: &Token, file_id: &str) -> Result<String> {
+    let url = format!(
+        "{}/dna/intent/api/v1/networkDeviceConfigFiles/{}/downloadMasked",
+        config.dnac_url, file_id
+    );
+
+    let resp = client(config)?
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to download config file {}: {}", file_id, resp.status()));
+    }
+
+    Ok(resp.text().await?)
+}
+
+/// The running config of a device, either live or from an archive version.
+pub async fn fetch_config(
+    config: &Config,
+    token: &Token,
+    device_id: &str,
+    version: Option<&str>,
+) -> Result<String> {
+    let version = match version {
+        Some(version) => version,
+        None => return get_running_config(config, token, device_id).await,
+    };
+
+    let versions = get_config_archive(config, token, device_id).await?;
+    let file = versions
+        .iter()
+        .find(|v| v.id == version)
+        .ok_or_else(|| anyhow!("No archive version {} for this device", version))?
+        .files
+        .iter()
+        .find(|file| file.file_type.as_deref() == Some("RUNNINGCONFIG"))
+        .ok_or_else(|| anyhow!("Archive version {} has no running config", version))?;
+
+    download_config_file(config, token, &file.file_id).await
+}
@@ -0,0 +1,47 @@
+// src/api/devices/getdevicedetail.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct DeviceDetail {
+    pub nw_device_name: Option<String>,
+    pub software_version: Option<String>,
+    pub ha_status: Option<String>,
+    pub redundancy_mode: Option<String>,
+    pub redundancy_state: Option<String>,
+    pub redundancy_peer_state: Option<String>,
+    pub redundancy_unit: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeviceDetailResponse {
+    response: DeviceDetail,
+}
+
+/// Assurance detail of one device, including HA redundancy for controllers.
+pub async fn get_device_detail(config: &Config, token: &Token, device_id: &str) -> Result<DeviceDetail> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/device-detail", config.dnac_url);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("searchBy", device_id), ("identifier", "uuid")])
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve device detail: {}", resp.status()));
+    }
+
+    Ok(resp.json::<DeviceDetailResponse>().await?.response)
+}
@@ -0,0 +1,97 @@
+// src/api/devices/getdevicehealth.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct DeviceHealth {
+    pub name: Option<String>,
+    pub uuid: Option<String>,
+    pub model: Option<String>,
+    pub os_version: Option<String>,
+    pub ip_address: Option<String>,
+    pub mac_address: Option<String>,
+    pub device_family: Option<String>,
+    pub device_type: Option<String>,
+    pub location: Option<String>,
+    pub reachability_health: Option<String>,
+    pub overall_health: Option<f64>,
+    pub issue_count: Option<i64>,
+    pub cpu_health: Option<f64>,
+    pub memory_utilization_health: Option<f64>,
+    /// Clients per radio; reported for access points
+    pub client_count: Option<ClientCount>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ClientCount {
+    pub radio0: Option<i64>,
+    pub radio1: Option<i64>,
+    pub radio2: Option<i64>,
+}
+
+impl ClientCount {
+    pub fn total(&self) -> i64 {
+        [self.radio0, self.radio1, self.radio2].iter().flatten().sum()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DeviceHealthResponse {
+    response: Vec<DeviceHealth>,
+}
+
+/// Health of every device, including the site hierarchy each one is assigned to.
+pub async fn get_all_device_health(config: &Config, token: &Token) -> Result<Vec<DeviceHealth>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let mut all_health: Vec<DeviceHealth> = Vec::new();
+    let mut offset = 1;
+    let limit = 500;
+
+    loop {
+        let url = format!(
+            "{}/dna/intent/api/v1/device-health?offset={}&limit={}",
+            config.dnac_url, offset, limit
+        );
+
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve device health: {}", resp.status()));
+        }
+
+        let health = resp.json::<DeviceHealthResponse>().await?.response;
+        let count = health.len();
+        all_health.extend(health);
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_health)
+}
+
+/// Site hierarchy of each device, keyed by device id.
+pub async fn get_device_sites(config: &Config, token: &Token) -> Result<HashMap<String, String>> {
+    let health = get_all_device_health(config, token).await?;
+
+    Ok(health
+        .into_iter()
+        .filter_map(|device| Some((device.uuid?, device.location?)))
+        .collect())
+}
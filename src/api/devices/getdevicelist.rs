@@ -2,9 +2,9 @@ use crate::api::authentication::auth::{self, Token};
 use crate::app::config::Config;
 use anyhow::{anyhow, Result};
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(non_snake_case)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
@@ -2,3 +2,9 @@
 
 pub mod getdevicelist;
 pub mod devicedetailenrichment;
+pub mod getdevicehealth;
+pub mod getdeviceconfig;
+pub mod syncdevice;
+pub mod deletedevice;
+pub mod assigndevicesite;
+pub mod getdevicedetail;
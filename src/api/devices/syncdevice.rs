@@ -0,0 +1,34 @@
+// src/api/devices/syncdevice.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+
+/// Resynchronize a device's inventory with Catalyst Center.
+pub async fn sync_device(config: &Config, token: &Token, device_id: &str) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/network-device/sync", config.dnac_url);
+
+    let resp = client
+        .put(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("forceSync", "false")])
+        .json(&[device_id])
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to start resync: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -0,0 +1,90 @@
+// src/api/discovery/getcredentials.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+/// Global credentials by type, as configured under Design > Network Settings.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalCredentials {
+    #[serde(default)]
+    pub cli_credential: Vec<Credential>,
+    #[serde(default)]
+    pub snmp_v2c_read: Vec<Credential>,
+    #[serde(default)]
+    pub snmp_v2c_write: Vec<Credential>,
+    #[serde(default)]
+    pub snmp_v3: Vec<Credential>,
+    #[serde(default)]
+    pub https_read: Vec<Credential>,
+    #[serde(default)]
+    pub https_write: Vec<Credential>,
+    #[serde(default)]
+    pub netconf_credential: Vec<Credential>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Credential {
+    pub id: Option<String>,
+    pub description: Option<String>,
+    pub name: Option<String>,
+    pub username: Option<String>,
+}
+
+impl GlobalCredentials {
+    /// The credentials of each type whose description (or name) is `profile`,
+    /// labelled with their type. A profile is the set of credentials sharing
+    /// one description, e.g. a CLI and an SNMPv3 credential both named `branch`.
+    pub fn profile(&self, profile: &str) -> Vec<(&'static str, &Credential)> {
+        let kinds = [
+            ("CLI", &self.cli_credential),
+            ("SNMPv2c Read", &self.snmp_v2c_read),
+            ("SNMPv2c Write", &self.snmp_v2c_write),
+            ("SNMPv3", &self.snmp_v3),
+            ("HTTPS Read", &self.https_read),
+            ("HTTPS Write", &self.https_write),
+            ("NETCONF", &self.netconf_credential),
+        ];
+        kinds
+            .into_iter()
+            .flat_map(|(kind, credentials)| credentials.iter().map(move |credential| (kind, credential)))
+            .filter(|(_, credential)| {
+                credential
+                    .description
+                    .as_deref()
+                    .or(credential.name.as_deref())
+                    .is_some_and(|label| label.eq_ignore_ascii_case(profile))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CredentialsResponse {
+    response: GlobalCredentials,
+}
+
+pub async fn get_global_credentials(config: &Config, token: &Token) -> Result<GlobalCredentials> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v2/global-credential", config.dnac_url);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve global credentials: {}", resp.status()));
+    }
+
+    Ok(resp.json::<CredentialsResponse>().await?.response)
+}
@@ -0,0 +1,157 @@
+// src/api/discovery/getdiscovery.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Discovery {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub discovery_type: Option<String>,
+    pub ip_address_list: Option<String>,
+    /// `In Progress` or `Complete`
+    pub discovery_condition: Option<String>,
+    /// `Active` while running, `Inactive` once finished or stopped
+    pub discovery_status: Option<String>,
+    pub num_devices: Option<i64>,
+    pub protocol_order: Option<String>,
+    pub retry: Option<i64>,
+    pub time_out: Option<i64>,
+    #[serde(default)]
+    pub global_credential_id_list: Vec<String>,
+}
+
+impl Discovery {
+    pub fn is_complete(&self) -> bool {
+        self.discovery_condition.as_deref() == Some("Complete")
+            || self.discovery_status.as_deref() == Some("Inactive")
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct DiscoveredDevice {
+    pub id: Option<String>,
+    pub hostname: Option<String>,
+    pub management_ip_address: Option<String>,
+    pub platform_id: Option<String>,
+    pub reachability_status: Option<String>,
+    pub reachability_failure_reason: Option<String>,
+    pub inventory_collection_status: Option<String>,
+    pub cli_status: Option<String>,
+    pub snmp_status: Option<String>,
+    pub netconf_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscoveriesResponse {
+    response: Vec<Discovery>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscoveryResponse {
+    response: Discovery,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscoveredDevicesResponse {
+    response: Vec<DiscoveredDevice>,
+}
+
+pub async fn get_discoveries(config: &Config, token: &Token) -> Result<Vec<Discovery>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let mut all_discoveries: Vec<Discovery> = Vec::new();
+    let mut start_index = 1;
+    let limit = 500;
+
+    loop {
+        let url = format!("{}/dna/intent/api/v1/discovery/{}/{}", config.dnac_url, start_index, limit);
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve discoveries: {}", resp.status()));
+        }
+
+        let discoveries = resp.json::<DiscoveriesResponse>().await?.response;
+        let count = discoveries.len();
+        all_discoveries.extend(discoveries);
+
+        if count < limit {
+            break;
+        }
+        start_index += limit;
+    }
+
+    Ok(all_discoveries)
+}
+
+pub async fn get_discovery(config: &Config, token: &Token, discovery_id: &str) -> Result<Discovery> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/discovery/{}", config.dnac_url, discovery_id);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve discovery {}: {}", discovery_id, resp.status()));
+    }
+
+    Ok(resp.json::<DiscoveryResponse>().await?.response)
+}
+
+/// Devices a discovery found, whether or not they could be managed.
+pub async fn get_discovered_devices(config: &Config, token: &Token, discovery_id: &str) -> Result<Vec<DiscoveredDevice>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let mut all_devices: Vec<DiscoveredDevice> = Vec::new();
+    let mut start_index = 1;
+    let limit = 500;
+
+    loop {
+        let url = format!(
+            "{}/dna/intent/api/v1/discovery/{}/network-device/{}/{}",
+            config.dnac_url, discovery_id, start_index, limit
+        );
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve discovered devices: {}", resp.status()));
+        }
+
+        let devices = resp.json::<DiscoveredDevicesResponse>().await?.response;
+        let count = devices.len();
+        all_devices.extend(devices);
+
+        if count < limit {
+            break;
+        }
+        start_index += limit;
+    }
+
+    Ok(all_devices)
+}
@@ -0,0 +1,5 @@
+// src/api/discovery/mod.rs
+
+pub mod getcredentials;
+pub mod startdiscovery;
+pub mod getdiscovery;
@@ -0,0 +1,60 @@
+// src/api/discovery/startdiscovery.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscoveryRequest<'a> {
+    name: &'a str,
+    discovery_type: &'a str,
+    ip_address_list: &'a str,
+    global_credential_id_list: &'a [String],
+    protocol_order: &'a str,
+}
+
+/// Start a discovery of `ip_address_list` using global credentials. The list
+/// is a single address, or for a `Range` discovery comma-separated ranges
+/// such as `10.0.0.1-10.0.0.50`.
+pub async fn start_discovery(
+    config: &Config,
+    token: &Token,
+    name: &str,
+    discovery_type: &str,
+    ip_address_list: &str,
+    credential_ids: &[String],
+) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/discovery", config.dnac_url);
+    let request = DiscoveryRequest {
+        name,
+        discovery_type,
+        ip_address_list,
+        global_credential_id_list: credential_ids,
+        protocol_order: "ssh,telnet",
+    };
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to start discovery: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -0,0 +1,52 @@
+// src/api/images/activateimage.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ActivationRequest<'a> {
+    device_uuid: &'a str,
+    image_uuid_list: [&'a str; 1],
+    activate_lower_image_version: bool,
+    device_upgrade_mode: &'a str,
+    distribute_if_needed: bool,
+}
+
+/// Boot a device into an image, distributing it first if it is not on flash.
+/// The device reloads.
+pub async fn activate_image(config: &Config, token: &Token, device_id: &str, image_id: &str) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/image/activation/device", config.dnac_url);
+    let request = [ActivationRequest {
+        device_uuid: device_id,
+        image_uuid_list: [image_id],
+        activate_lower_image_version: false,
+        device_upgrade_mode: "currentlyExists",
+        distribute_if_needed: true,
+    }];
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to start image activation: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -0,0 +1,45 @@
+// src/api/images/distributeimage.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DistributionRequest<'a> {
+    device_uuid: &'a str,
+    image_uuid: &'a str,
+}
+
+/// Copy an image to a device's flash.
+pub async fn distribute_image(config: &Config, token: &Token, device_id: &str, image_id: &str) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/image/distribution", config.dnac_url);
+    let request = [DistributionRequest {
+        device_uuid: device_id,
+        image_uuid: image_id,
+    }];
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to start image distribution: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -0,0 +1,90 @@
+// src/api/images/getimages.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SoftwareImage {
+    pub image_uuid: Option<String>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub display_version: Option<String>,
+    pub family: Option<String>,
+    pub image_type: Option<String>,
+    pub is_tagged_golden: Option<bool>,
+    #[serde(default)]
+    pub image_series: Vec<String>,
+    #[serde(default)]
+    pub applicable_devices_for_image: Vec<ApplicableDevice>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ApplicableDevice {
+    pub mdf_id: Option<String>,
+    pub product_name: Option<String>,
+    #[serde(default)]
+    pub product_id: Vec<String>,
+}
+
+impl SoftwareImage {
+    /// Version as shown in the GUI, e.g. `17.9.4a`.
+    pub fn version(&self) -> &str {
+        self.display_version
+            .as_deref()
+            .or(self.version.as_deref())
+            .unwrap_or("N/A")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ImageResponse {
+    response: Vec<SoftwareImage>,
+}
+
+/// Images in the repository. With `golden_only`, just those tagged golden.
+pub async fn get_images(config: &Config, token: &Token, golden_only: bool) -> Result<Vec<SoftwareImage>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/image/importation", config.dnac_url);
+    let mut all_images: Vec<SoftwareImage> = Vec::new();
+    let mut offset = 1;
+    let limit = 100;
+
+    loop {
+        let mut query = vec![("offset", offset.to_string()), ("limit", limit.to_string())];
+        if golden_only {
+            query.push(("isTaggedGolden", "true".to_string()));
+        }
+
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .query(&query)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve images: {}", resp.status()));
+        }
+
+        let images = resp.json::<ImageResponse>().await?.response;
+        let count = images.len();
+        all_images.extend(images);
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_images)
+}
@@ -0,0 +1,5 @@
+// src/api/images/mod.rs
+
+pub mod getimages;
+pub mod distributeimage;
+pub mod activateimage;
@@ -0,0 +1,131 @@
+// src/api/interfaces/getinterfaces.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Interface {
+    pub id: Option<String>,
+    pub device_id: Option<String>,
+    pub port_name: Option<String>,
+    pub description: Option<String>,
+    pub admin_status: Option<String>,
+    pub status: Option<String>,
+    pub speed: Option<String>,
+    pub duplex: Option<String>,
+    pub vlan_id: Option<String>,
+    pub native_vlan_id: Option<String>,
+    pub voice_vlan: Option<String>,
+    pub port_mode: Option<String>,
+    pub port_type: Option<String>,
+    pub interface_type: Option<String>,
+    pub media_type: Option<String>,
+    pub mac_address: Option<String>,
+    pub ipv4_address: Option<String>,
+    pub ipv4_mask: Option<String>,
+    pub mtu: Option<String>,
+    pub if_index: Option<String>,
+    pub last_updated: Option<String>,
+    pub mapped_physical_interface_name: Option<String>,
+    /// Hostname of the owning device, filled in locally for fleet-wide listings
+    #[serde(default)]
+    pub device_name: Option<String>,
+    /// PoE status, filled in locally from the PoE detail API
+    #[serde(default)]
+    pub poe: Option<InterfacePoe>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct InterfacePoe {
+    pub interface_name: Option<String>,
+    pub admin_status: Option<String>,
+    pub oper_status: Option<String>,
+    pub max_port_power: Option<String>,
+    pub allocated_power: Option<String>,
+    pub port_power_drawn: Option<String>,
+}
+
+/// Error and discard counters from Assurance.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct InterfaceStats {
+    pub interface_name: Option<String>,
+    pub rx_errors: Option<i64>,
+    pub tx_errors: Option<i64>,
+    pub rx_discards: Option<i64>,
+    pub tx_discards: Option<i64>,
+    pub crc_errors: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListResponse<T> {
+    response: Vec<T>,
+}
+
+/// All interfaces of a device.
+pub async fn get_device_interfaces(config: &Config, token: &Token, device_id: &str) -> Result<Vec<Interface>> {
+    let url = format!(
+        "{}/dna/intent/api/v1/interface/network-device/{}",
+        config.dnac_url, device_id
+    );
+    get_list(config, token, &url, &[], "interfaces").await
+}
+
+/// PoE status of every PoE-capable interface of a device.
+pub async fn get_device_poe(config: &Config, token: &Token, device_id: &str) -> Result<Vec<InterfacePoe>> {
+    let url = format!(
+        "{}/dna/intent/api/v1/network-device/{}/interface/poe-detail",
+        config.dnac_url, device_id
+    );
+    get_list(config, token, &url, &[], "PoE detail").await
+}
+
+/// Error counters for one interface of a device.
+pub async fn get_interface_stats(
+    config: &Config,
+    token: &Token,
+    device_id: &str,
+    interface_name: &str,
+) -> Result<Option<InterfaceStats>> {
+    let url = format!("{}/dna/data/api/v1/interfaces", config.dnac_url);
+    let query = [
+        ("networkDeviceId", device_id),
+        ("interfaceName", interface_name),
+        ("view", "statistics"),
+    ];
+    Ok(get_list(config, token, &url, &query, "interface statistics").await?.into_iter().next())
+}
+
+async fn get_list<T: DeserializeOwned>(
+    config: &Config,
+    token: &Token,
+    url: &str,
+    query: &[(&str, &str)],
+    what: &str,
+) -> Result<Vec<T>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let resp = client
+        .get(url)
+        .header("X-Auth-Token", &token.value)
+        .query(query)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve {}: {}", what, resp.status()));
+    }
+
+    Ok(resp.json::<ListResponse<T>>().await?.response)
+}
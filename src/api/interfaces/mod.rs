@@ -0,0 +1,4 @@
+// src/api/interfaces/mod.rs
+
+pub mod getinterfaces;
+pub mod updateinterface;
@@ -0,0 +1,64 @@
+// src/api/interfaces/updateinterface.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+
+/// Interface settings to change. Unset fields are left as they are.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `UP` or `DOWN`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vlan_id: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_vlan_id: Option<u16>,
+}
+
+impl InterfaceUpdate {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Update an interface. With `preview` set the device is not changed; the
+/// task's result holds the CLI that would be pushed.
+pub async fn update_interface(
+    config: &Config,
+    token: &Token,
+    interface_id: &str,
+    update: &InterfaceUpdate,
+    preview: bool,
+) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/interface/{}", config.dnac_url, interface_id);
+    let mode = if preview { "Preview" } else { "Deploy" };
+
+    let resp = client
+        .put(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("deploymentMode", mode)])
+        .json(update)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to update interface: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -4,7 +4,7 @@ use crate::app::config::Config;
 use crate::api::authentication::auth::Token;
 use anyhow::{anyhow, Result};
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Deserialize)]
@@ -16,7 +16,7 @@ pub struct IssueListResponse {
     pub response: Option<Vec<Issue>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(non_snake_case)]
 #[allow(dead_code)]
 pub struct Issue {
@@ -3,3 +3,14 @@ pub mod devices;
 pub mod clients;
 pub mod issues;
 pub mod wireless;
+pub mod sites;
+pub mod topology;
+pub mod pathtrace;
+pub mod interfaces;
+pub mod tasks;
+pub mod compliance;
+pub mod images;
+pub mod templates;
+pub mod pnp;
+pub mod discovery;
+pub mod tags;
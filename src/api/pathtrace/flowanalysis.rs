@@ -0,0 +1,173 @@
+// src/api/pathtrace/flowanalysis.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowAnalysisRequest {
+    #[serde(rename = "sourceIP")]
+    pub source_ip: String,
+    #[serde(rename = "destIP")]
+    pub dest_ip: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_port: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_port: Option<String>,
+    pub inclusions: Vec<String>,
+    pub periodic_refresh: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct FlowAnalysisStarted {
+    pub flow_analysis_id: String,
+    pub task_id: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StartResponse {
+    response: FlowAnalysisStarted,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct FlowAnalysis {
+    pub request: Option<FlowAnalysisStatus>,
+    pub network_elements_info: Option<Vec<NetworkElement>>,
+    pub detailed_status: Option<DetailedStatus>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct FlowAnalysisStatus {
+    pub id: Option<String>,
+    pub status: Option<String>,
+    pub failure_reason: Option<String>,
+    #[serde(rename = "sourceIP")]
+    pub source_ip: Option<String>,
+    #[serde(rename = "destIP")]
+    pub dest_ip: Option<String>,
+    pub protocol: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct DetailedStatus {
+    pub acl_trace_calculation: Option<String>,
+    pub acl_trace_calculation_failure_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct NetworkElement {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub ip: Option<String>,
+    #[serde(rename = "type")]
+    pub element_type: Option<String>,
+    pub role: Option<String>,
+    pub link_information_source: Option<String>,
+    pub ingress_interface: Option<ElementInterface>,
+    pub egress_interface: Option<ElementInterface>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ElementInterface {
+    pub physical_interface: Option<PhysicalInterface>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct PhysicalInterface {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub acl_analysis: Option<AclAnalysis>,
+    pub qos_statistics: Option<Vec<QosStatistics>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct AclAnalysis {
+    pub acl_name: Option<String>,
+    pub result: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct QosStatistics {
+    pub class_map_name: Option<String>,
+    pub drop_rate: Option<i64>,
+    pub queue_total_drops: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FlowAnalysisResponse {
+    response: FlowAnalysis,
+}
+
+/// Start a path trace. The analysis runs asynchronously; poll it with `get_flow_analysis`.
+pub async fn start_flow_analysis(
+    config: &Config,
+    token: &Token,
+    request: &FlowAnalysisRequest,
+) -> Result<FlowAnalysisStarted> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/flow-analysis", config.dnac_url);
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to start path trace: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<StartResponse>().await?.response)
+}
+
+pub async fn get_flow_analysis(config: &Config, token: &Token, id: &str) -> Result<FlowAnalysis> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/flow-analysis/{}", config.dnac_url, id);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve path trace: {}", resp.status()));
+    }
+
+    Ok(resp.json::<FlowAnalysisResponse>().await?.response)
+}
@@ -0,0 +1,3 @@
+// src/api/pathtrace/mod.rs
+
+pub mod flowanalysis;
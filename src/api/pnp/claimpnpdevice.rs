@@ -0,0 +1,103 @@
+// src/api/pnp/claimpnpdevice.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+/// What to provision a PnP device with when claiming it to a site.
+#[derive(Debug, Default)]
+pub struct ClaimOptions {
+    pub image_id: Option<String>,
+    pub template_id: Option<String>,
+    pub template_params: Vec<(String, String)>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SiteClaimRequest<'a> {
+    device_id: &'a str,
+    site_id: &'a str,
+    #[serde(rename = "type")]
+    claim_type: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image_info: Option<ImageInfo<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config_info: Option<ConfigInfo<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageInfo<'a> {
+    image_id: &'a str,
+    skip: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigInfo<'a> {
+    config_id: &'a str,
+    config_parameters: Vec<ConfigParameter<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigParameter<'a> {
+    key: &'a str,
+    value: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaimResponse {
+    response: Option<String>,
+}
+
+/// Claim a PnP device to a site. Returns Catalyst Center's status message.
+pub async fn claim_device(
+    config: &Config,
+    token: &Token,
+    device_id: &str,
+    site_id: &str,
+    options: &ClaimOptions,
+) -> Result<String> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/onboarding/pnp-device/site-claim", config.dnac_url);
+    let request = SiteClaimRequest {
+        device_id,
+        site_id,
+        claim_type: "Default",
+        image_info: options.image_id.as_deref().map(|image_id| ImageInfo { image_id, skip: false }),
+        config_info: options.template_id.as_deref().map(|config_id| ConfigInfo {
+            config_id,
+            config_parameters: options
+                .template_params
+                .iter()
+                .map(|(key, value)| ConfigParameter { key, value })
+                .collect(),
+        }),
+    };
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to claim device: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp
+        .json::<ClaimResponse>()
+        .await?
+        .response
+        .unwrap_or_else(|| "Claimed".to_string()))
+}
@@ -0,0 +1,84 @@
+// src/api/pnp/getpnpdevices.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct PnpDevice {
+    pub id: Option<String>,
+    pub device_info: Option<PnpDeviceInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct PnpDeviceInfo {
+    pub serial_number: Option<String>,
+    pub name: Option<String>,
+    pub pid: Option<String>,
+    /// `Unclaimed`, `Planned`, `Onboarding`, `Provisioned` or `Error`
+    pub state: Option<String>,
+    pub onb_state: Option<String>,
+    pub last_contact: Option<i64>,
+    pub image_version: Option<String>,
+    pub site_name: Option<String>,
+    pub source: Option<String>,
+    pub stack: Option<bool>,
+    pub error_details: Option<serde_json::Value>,
+}
+
+impl PnpDevice {
+    pub fn serial(&self) -> &str {
+        self.device_info
+            .as_ref()
+            .and_then(|info| info.serial_number.as_deref())
+            .unwrap_or("Unknown")
+    }
+}
+
+/// Devices in the PnP queue, optionally only those in `state`.
+pub async fn get_pnp_devices(config: &Config, token: &Token, state: Option<&str>) -> Result<Vec<PnpDevice>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/onboarding/pnp-device", config.dnac_url);
+    let mut all_devices: Vec<PnpDevice> = Vec::new();
+    let mut offset = 0;
+    let limit = 500;
+
+    loop {
+        let mut query = vec![("offset", offset.to_string()), ("limit", limit.to_string())];
+        if let Some(state) = state {
+            query.push(("state", state.to_string()));
+        }
+
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .query(&query)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve PnP devices: {}", resp.status()));
+        }
+
+        // This endpoint returns a bare list rather than a `response` wrapper
+        let devices = resp.json::<Vec<PnpDevice>>().await?;
+        let count = devices.len();
+        all_devices.extend(devices);
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_devices)
+}
@@ -0,0 +1,5 @@
+// src/api/pnp/mod.rs
+
+pub mod getpnpdevices;
+pub mod claimpnpdevice;
+pub mod resetpnpdevice;
@@ -0,0 +1,61 @@
+// src/api/pnp/resetpnpdevice.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResetRequest<'a> {
+    device_reset_list: [DeviceReset<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceReset<'a> {
+    device_id: &'a str,
+    config_list: [(); 0],
+}
+
+#[derive(Debug, Deserialize)]
+struct ResetResponse {
+    message: Option<String>,
+}
+
+/// Reset a PnP device in the Error state so it can be onboarded again.
+pub async fn reset_device(config: &Config, token: &Token, device_id: &str) -> Result<String> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/onboarding/pnp-device/reset", config.dnac_url);
+    let request = ResetRequest {
+        device_reset_list: [DeviceReset {
+            device_id,
+            config_list: [],
+        }],
+    };
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to reset device: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp
+        .json::<ResetResponse>()
+        .await?
+        .message
+        .unwrap_or_else(|| "Reset".to_string()))
+}
@@ -0,0 +1,67 @@
+// src/api/sites/getsitehealth.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SiteHealth {
+    pub site_name: Option<String>,
+    pub site_id: Option<String>,
+    pub site_type: Option<String>,
+    pub parent_site_name: Option<String>,
+    pub network_health_average: Option<f64>,
+    pub healthy_network_device_percentage: Option<f64>,
+    pub healthy_clients_percentage: Option<f64>,
+    pub client_health_wired: Option<f64>,
+    pub client_health_wireless: Option<f64>,
+    pub number_of_clients: Option<i64>,
+    pub number_of_network_device: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SiteHealthResponse {
+    response: Vec<SiteHealth>,
+}
+
+pub async fn get_all_site_health(config: &Config, token: &Token) -> Result<Vec<SiteHealth>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let mut all_health: Vec<SiteHealth> = Vec::new();
+    let mut offset = 1;
+    let limit = 50; // API maximum for site health
+
+    loop {
+        let url = format!(
+            "{}/dna/intent/api/v1/site-health?offset={}&limit={}",
+            config.dnac_url, offset, limit
+        );
+
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve site health: {}", resp.status()));
+        }
+
+        let health = resp.json::<SiteHealthResponse>().await?.response;
+        let count = health.len();
+        all_health.extend(health);
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_health)
+}
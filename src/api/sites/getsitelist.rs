@@ -0,0 +1,61 @@
+// src/api/sites/getsitelist.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Site {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub parent_id: Option<String>,
+    pub site_name_hierarchy: Option<String>,
+    pub site_hierarchy: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SitesResponse {
+    response: Vec<Site>,
+}
+
+pub async fn get_all_sites(config: &Config, token: &Token) -> Result<Vec<Site>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let mut all_sites: Vec<Site> = Vec::new();
+    let mut offset = 1;
+    let limit = 500;
+
+    loop {
+        let url = format!(
+            "{}/dna/intent/api/v1/site?offset={}&limit={}",
+            config.dnac_url, offset, limit
+        );
+
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve sites: {}", resp.status()));
+        }
+
+        let sites = resp.json::<SitesResponse>().await?.response;
+        let count = sites.len();
+        all_sites.extend(sites);
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_sites)
+}
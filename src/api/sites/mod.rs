@@ -0,0 +1,4 @@
+// src/api/sites/mod.rs
+
+pub mod getsitelist;
+pub mod getsitehealth;
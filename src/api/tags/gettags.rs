@@ -0,0 +1,153 @@
+// src/api/tags/gettags.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+/// Member type of tagged devices in the tag APIs.
+pub const DEVICE_MEMBER: &str = "networkdevice";
+/// Member type of tagged interfaces in the tag APIs.
+pub const INTERFACE_MEMBER: &str = "interface";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Tag {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub system_tag: Option<bool>,
+    /// Rules that select members automatically; empty for static tags
+    #[serde(default)]
+    pub dynamic_rules: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TagMember {
+    pub id: Option<String>,
+    pub instance_uuid: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagsResponse {
+    response: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MembersResponse {
+    response: Vec<TagMember>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CountResponse {
+    response: i64,
+}
+
+pub async fn get_tags(config: &Config, token: &Token) -> Result<Vec<Tag>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/tag", config.dnac_url);
+    let mut all_tags: Vec<Tag> = Vec::new();
+    let mut offset = 1;
+    let limit = 500;
+
+    loop {
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .query(&[("offset", offset), ("limit", limit)])
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve tags: {}", resp.status()));
+        }
+
+        let tags = resp.json::<TagsResponse>().await?.response;
+        let count = tags.len();
+        all_tags.extend(tags);
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_tags)
+}
+
+/// The tag named `name`. Tag names are unique but matched case-insensitively.
+pub async fn get_tag_by_name(config: &Config, token: &Token, name: &str) -> Result<Tag> {
+    get_tags(config, token)
+        .await?
+        .into_iter()
+        .find(|tag| tag.name.as_deref().is_some_and(|tag_name| tag_name.eq_ignore_ascii_case(name)))
+        .ok_or_else(|| anyhow!("No tag named '{}'", name))
+}
+
+/// Ids of a tag's members of one type, including those matched by dynamic rules.
+pub async fn get_tag_member_ids(config: &Config, token: &Token, tag_id: &str, member_type: &str) -> Result<Vec<String>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/tag/{}/member", config.dnac_url, tag_id);
+    let mut all_ids: Vec<String> = Vec::new();
+    let mut offset = 1;
+    let limit = 500;
+
+    loop {
+        let resp = client
+            .get(&url)
+            .header("X-Auth-Token", &token.value)
+            .query(&[
+                ("memberType", member_type.to_string()),
+                ("offset", offset.to_string()),
+                ("limit", limit.to_string()),
+            ])
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(anyhow!("Failed to retrieve tag members: {}", resp.status()));
+        }
+
+        let members = resp.json::<MembersResponse>().await?.response;
+        let count = members.len();
+        all_ids.extend(members.into_iter().filter_map(|member| member.instance_uuid.or(member.id)));
+
+        if count < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(all_ids)
+}
+
+pub async fn get_tag_member_count(config: &Config, token: &Token, tag_id: &str, member_type: &str) -> Result<i64> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/tag/{}/member/count", config.dnac_url, tag_id);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("memberType", member_type)])
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to count tag members: {}", resp.status()));
+    }
+
+    Ok(resp.json::<CountResponse>().await?.response)
+}
@@ -0,0 +1,4 @@
+// src/api/tags/mod.rs
+
+pub mod gettags;
+pub mod updatetags;
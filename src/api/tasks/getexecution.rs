@@ -0,0 +1,88 @@
+// src/api/tasks/getexecution.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Reference to a business API execution, returned instead of a task by
+/// older write APIs such as enterprise SSIDs and wireless profiles.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ExecutionRef {
+    pub execution_id: String,
+    pub execution_status_url: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Execution {
+    pub bapi_execution_id: Option<String>,
+    pub bapi_name: Option<String>,
+    /// `IN_PROGRESS`, `SUCCESS` or `FAILURE`
+    pub status: Option<String>,
+    pub bapi_error: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+}
+
+pub async fn get_execution(config: &Config, token: &Token, execution_id: &str) -> Result<Execution> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!(
+        "{}/dna/intent/api/v1/dnacaap/management/execution-status/{}",
+        config.dnac_url, execution_id
+    );
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve execution {}: {}", execution_id, resp.status()));
+    }
+
+    Ok(resp.json::<Execution>().await?)
+}
+
+/// Poll an execution until it finishes. Errors if it fails or `timeout` passes.
+pub async fn wait_for_execution(
+    config: &Config,
+    token: &Token,
+    execution_id: &str,
+    timeout: Duration,
+) -> Result<Execution> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let execution = get_execution(config, token, execution_id).await?;
+        match execution.status.as_deref() {
+            Some("SUCCESS") => return Ok(execution),
+            Some("FAILURE") => {
+                return Err(anyhow!(
+                    "Execution failed: {}",
+                    execution.bapi_error.as_deref().unwrap_or("no reason given")
+                ))
+            }
+            _ => {}
+        }
+        if Instant::now() >= deadline {
+            return Err(anyhow!(
+                "Execution {} did not finish within {}s",
+                execution_id,
+                timeout.as_secs()
+            ));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,101 @@
+// src/api/tasks/gettask.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Reference to an asynchronous task, as returned by most write APIs.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TaskRef {
+    pub task_id: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TaskRefResponse {
+    pub response: TaskRef,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Task {
+    pub id: Option<String>,
+    pub is_error: Option<bool>,
+    pub failure_reason: Option<String>,
+    pub error_code: Option<String>,
+    pub progress: Option<String>,
+    pub data: Option<String>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+}
+
+impl Task {
+    pub fn is_done(&self) -> bool {
+        self.end_time.is_some() || self.is_error.unwrap_or(false)
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.is_error.unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TaskResponse {
+    response: Task,
+}
+
+pub async fn get_task(config: &Config, token: &Token, task_id: &str) -> Result<Task> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/task/{}", config.dnac_url, task_id);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve task {}: {}", task_id, resp.status()));
+    }
+
+    Ok(resp.json::<TaskResponse>().await?.response)
+}
+
+/// Poll a task until it finishes. Errors if the task fails or `timeout` passes.
+pub async fn wait_for_task(config: &Config, token: &Token, task_id: &str, timeout: Duration) -> Result<Task> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let task = get_task(config, token, task_id).await?;
+        if task.is_error() {
+            return Err(anyhow!(
+                "Task failed: {}",
+                task.failure_reason
+                    .clone()
+                    .or_else(|| task.progress.clone())
+                    .unwrap_or_else(|| "no reason given".to_string())
+            ));
+        }
+        if task.is_done() {
+            return Ok(task);
+        }
+        if Instant::now() >= deadline {
+            return Err(anyhow!(
+                "Task {} did not finish within {}s",
+                task_id,
+                timeout.as_secs()
+            ));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
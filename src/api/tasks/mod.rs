@@ -0,0 +1,4 @@
+// src/api/tasks/mod.rs
+
+pub mod gettask;
+pub mod getexecution;
@@ -0,0 +1,112 @@
+// src/api/templates/deploytemplate.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeployRequest<'a> {
+    template_id: &'a str,
+    force_push_template: bool,
+    target_info: [TargetInfo<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+struct TargetInfo<'a> {
+    id: &'a str,
+    #[serde(rename = "type")]
+    target_type: &'a str,
+    params: &'a Map<String, Value>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PreviewRequest<'a> {
+    template_id: &'a str,
+    params: &'a Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplatePreview {
+    pub cli_preview: Option<String>,
+    #[serde(default)]
+    pub validation_errors: Value,
+}
+
+fn client(config: &Config) -> Result<Client> {
+    Ok(Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?)
+}
+
+/// Deploy a committed template version to one device, by management IP.
+pub async fn deploy_template(
+    config: &Config,
+    token: &Token,
+    template_id: &str,
+    device_ip: &str,
+    params: &Map<String, Value>,
+) -> Result<TaskRef> {
+    let url = format!(
+        "{}/dna/intent/api/v2/template-programmer/template/deploy",
+        config.dnac_url
+    );
+    let request = DeployRequest {
+        template_id,
+        force_push_template: false,
+        target_info: [TargetInfo {
+            id: device_ip,
+            target_type: "MANAGED_DEVICE_IP",
+            params,
+        }],
+    };
+
+    let resp = client(config)?
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to deploy template: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
+
+/// Have Catalyst Center render a template with the given parameters.
+pub async fn preview_template(
+    config: &Config,
+    token: &Token,
+    template_id: &str,
+    params: &Map<String, Value>,
+) -> Result<TemplatePreview> {
+    let url = format!(
+        "{}/dna/intent/api/v1/template-programmer/template/preview",
+        config.dnac_url
+    );
+
+    let resp = client(config)?
+        .put(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&PreviewRequest { template_id, params })
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to preview template: {}", resp.status()));
+    }
+
+    Ok(resp.json::<TemplatePreview>().await?)
+}
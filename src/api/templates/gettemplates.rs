@@ -0,0 +1,131 @@
+// src/api/templates/gettemplates.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+/// A template as listed by the template programmer.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TemplateSummary {
+    pub name: Option<String>,
+    pub template_id: Option<String>,
+    pub project_name: Option<String>,
+    pub project_id: Option<String>,
+    pub composite: Option<bool>,
+    #[serde(default)]
+    pub versions_info: Vec<TemplateVersion>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TemplateVersion {
+    pub id: Option<String>,
+    pub version: Option<String>,
+    pub version_time: Option<i64>,
+    pub author: Option<String>,
+    pub description: Option<String>,
+}
+
+impl TemplateSummary {
+    /// The newest committed version, which is what gets deployed.
+    pub fn latest_version(&self) -> Option<&TemplateVersion> {
+        self.versions_info.iter().max_by_key(|version| {
+            version
+                .version
+                .as_deref()
+                .and_then(|version| version.parse::<u64>().ok())
+                .unwrap_or(0)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Template {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub project_name: Option<String>,
+    pub description: Option<String>,
+    pub software_type: Option<String>,
+    /// `VELOCITY` or `JINJA`
+    pub language: Option<String>,
+    pub template_content: Option<String>,
+    #[serde(default)]
+    pub template_params: Vec<TemplateParam>,
+    #[serde(default)]
+    pub device_types: Vec<TemplateDeviceType>,
+    pub version: Option<String>,
+    pub last_update_time: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TemplateParam {
+    pub parameter_name: Option<String>,
+    pub data_type: Option<String>,
+    pub default_value: Option<String>,
+    pub description: Option<String>,
+    pub display_name: Option<String>,
+    pub required: Option<bool>,
+    pub binding: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TemplateDeviceType {
+    pub product_family: Option<String>,
+    pub product_series: Option<String>,
+}
+
+fn client(config: &Config) -> Result<Client> {
+    Ok(Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?)
+}
+
+/// Every template in every project.
+pub async fn get_templates(config: &Config, token: &Token) -> Result<Vec<TemplateSummary>> {
+    let url = format!("{}/dna/intent/api/v1/template-programmer/template", config.dnac_url);
+
+    let resp = client(config)?
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve templates: {}", resp.status()));
+    }
+
+    // This endpoint returns a bare list rather than a `response` wrapper
+    Ok(resp.json::<Vec<TemplateSummary>>().await?)
+}
+
+/// A template with its content and parameters, at its latest version.
+pub async fn get_template(config: &Config, token: &Token, template_id: &str) -> Result<Template> {
+    let url = format!(
+        "{}/dna/intent/api/v1/template-programmer/template/{}",
+        config.dnac_url, template_id
+    );
+
+    let resp = client(config)?
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .query(&[("latestVersion", "true")])
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve template: {}", resp.status()));
+    }
+
+    Ok(resp.json::<Template>().await?)
+}
@@ -0,0 +1,4 @@
+// src/api/templates/mod.rs
+
+pub mod gettemplates;
+pub mod deploytemplate;
@@ -0,0 +1,89 @@
+// src/api/topology/gettopology.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct NetworkTopology {
+    pub id: Option<String>,
+    #[serde(default)]
+    pub nodes: Vec<NetworkNode>,
+    #[serde(default)]
+    pub links: Vec<NetworkLink>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct NetworkNode {
+    pub id: Option<String>,
+    pub label: Option<String>,
+    pub ip: Option<String>,
+    pub device_type: Option<String>,
+    pub family: Option<String>,
+    pub platform_id: Option<String>,
+    pub role: Option<String>,
+    pub software_version: Option<String>,
+    pub node_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct NetworkLink {
+    pub id: Option<String>,
+    pub source: Option<String>,
+    pub target: Option<String>,
+    pub start_port_name: Option<String>,
+    pub start_port_ipv4_address: Option<String>,
+    pub start_port_speed: Option<String>,
+    pub end_port_name: Option<String>,
+    pub end_port_ipv4_address: Option<String>,
+    pub end_port_speed: Option<String>,
+    pub link_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TopologyResponse {
+    response: NetworkTopology,
+}
+
+/// Physical (CDP/LLDP) topology of all devices.
+pub async fn get_physical_topology(config: &Config, token: &Token) -> Result<NetworkTopology> {
+    get_topology(config, token, "physical-topology").await
+}
+
+/// Layer 2 topology of a VLAN, e.g. `vlan100`.
+pub async fn get_l2_topology(config: &Config, token: &Token, vlan: &str) -> Result<NetworkTopology> {
+    get_topology(config, token, &format!("l2/{}", vlan)).await
+}
+
+/// Layer 3 topology of a routing protocol: ospf, eigrp, isis or static.
+pub async fn get_l3_topology(config: &Config, token: &Token, protocol: &str) -> Result<NetworkTopology> {
+    get_topology(config, token, &format!("l3/{}", protocol)).await
+}
+
+async fn get_topology(config: &Config, token: &Token, path: &str) -> Result<NetworkTopology> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/topology/{}", config.dnac_url, path);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve topology: {}", resp.status()));
+    }
+
+    Ok(resp.json::<TopologyResponse>().await?.response)
+}
@@ -0,0 +1,3 @@
+// src/api/topology/mod.rs
+
+pub mod gettopology;
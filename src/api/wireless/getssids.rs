@@ -0,0 +1,89 @@
+// src/api/wireless/getssids.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// `radioPolicy` that steers dual-band clients to 5 GHz.
+pub const BAND_SELECT: &str = "Dual band operation with band select";
+/// `radioPolicy` for plain dual-band operation.
+pub const DUAL_BAND: &str = "Dual band operation (2.4GHz and 5GHz)";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct EnterpriseSsid {
+    pub instance_uuid: Option<String>,
+    pub group_uuid: Option<String>,
+    pub inherited_group_name: Option<String>,
+    #[serde(default)]
+    pub ssid_details: Vec<SsidDetails>,
+}
+
+/// One SSID's settings. Fields not modelled here are kept in `other` so an
+/// update sends back everything it read.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SsidDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wlan_type: Option<String>,
+    /// e.g. `WPA2_ENTERPRISE`, `WPA3_PERSONAL` or `OPEN`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traffic_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radio_policy: Option<String>,
+    /// `Adaptive`, `Enable` or `Disable`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fast_transition: Option<String>,
+    #[serde(rename = "enableBroadcastSSID", skip_serializing_if = "Option::is_none")]
+    pub enable_broadcast_ssid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_fabric: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_server: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SsidResponse {
+    response: Vec<EnterpriseSsid>,
+}
+
+/// Every enterprise SSID defined under Design > Network Settings > Wireless.
+pub async fn get_enterprise_ssids(config: &Config, token: &Token) -> Result<Vec<SsidDetails>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/enterprise-ssid", config.dnac_url);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve SSIDs: {}", resp.status()));
+    }
+
+    Ok(resp
+        .json::<SsidResponse>()
+        .await?
+        .response
+        .into_iter()
+        .flat_map(|group| group.ssid_details)
+        .collect())
+}
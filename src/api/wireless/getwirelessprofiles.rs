@@ -0,0 +1,93 @@
+// src/api/wireless/getwirelessprofiles.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WirelessProfile {
+    pub profile_details: ProfileDetails,
+}
+
+/// A wireless network profile: which SSIDs are broadcast at which sites, and
+/// how each is switched. Unmodelled fields are kept in `other` for updates.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileDetails {
+    pub name: Option<String>,
+    /// Site hierarchies the profile is assigned to
+    #[serde(default)]
+    pub sites: Vec<String>,
+    #[serde(default)]
+    pub ssid_details: Vec<ProfileSsid>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileSsid {
+    pub name: Option<String>,
+    /// WLC interface the SSID is centrally switched on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wlan_profile_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_profile_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_fabric: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flex_connect: Option<FlexConnect>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexConnect {
+    pub enable_flex_connect: Option<bool>,
+    /// VLAN the SSID is locally switched to at FlexConnect APs
+    pub local_to_vlan: Option<u16>,
+}
+
+impl ProfileDetails {
+    /// This profile's entry for an SSID, if it carries it.
+    pub fn ssid(&self, name: &str) -> Option<&ProfileSsid> {
+        self.ssid_details
+            .iter()
+            .find(|ssid| ssid.name.as_deref().is_some_and(|ssid_name| ssid_name.eq_ignore_ascii_case(name)))
+    }
+}
+
+pub async fn get_wireless_profiles(config: &Config, token: &Token) -> Result<Vec<ProfileDetails>> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/wireless/profile", config.dnac_url);
+
+    let resp = client
+        .get(&url)
+        .header("X-Auth-Token", &token.value)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!("Failed to retrieve wireless profiles: {}", resp.status()));
+    }
+
+    // Some releases return a bare list, others wrap it in `response`
+    let body = resp.json::<Value>().await?;
+    let list = match body {
+        Value::Object(mut wrapper) => wrapper.remove("response").unwrap_or(Value::Array(Vec::new())),
+        list => list,
+    };
+    let profiles: Vec<WirelessProfile> = serde_json::from_value(list)?;
+
+    Ok(profiles.into_iter().map(|profile| profile.profile_details).collect())
+}
@@ -2,3 +2,8 @@
 
 pub mod accesspointconfig;
 pub mod rfprofile;
+pub mod getssids;
+pub mod updatessid;
+pub mod getwirelessprofiles;
+pub mod updatewirelessprofile;
+pub mod provisionwlc;
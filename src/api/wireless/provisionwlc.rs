@@ -0,0 +1,41 @@
+// src/api/wireless/provisionwlc.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{TaskRef, TaskRefResponse};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProvisionRequest {
+    skip_ap_provision: bool,
+}
+
+/// Push the wireless design (SSIDs and profiles) for its site to a WLC.
+/// Access points are not reprovisioned.
+pub async fn provision_wlc(config: &Config, token: &Token, device_id: &str) -> Result<TaskRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/wirelessControllers/{}/provision", config.dnac_url, device_id);
+
+    let resp = client
+        .post(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&ProvisionRequest { skip_ap_provision: true })
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to provision controller: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<TaskRefResponse>().await?.response)
+}
@@ -0,0 +1,43 @@
+// src/api/wireless/updatessid.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::getexecution::ExecutionRef;
+use crate::api::wireless::getssids::SsidDetails;
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+
+// Reported by the GET but rejected by the update
+const READ_ONLY_FIELDS: [&str; 3] = ["wlanType", "isEnabled", "isFabric"];
+
+/// Replace an enterprise SSID's settings with `ssid`.
+pub async fn update_enterprise_ssid(config: &Config, token: &Token, ssid: &SsidDetails) -> Result<ExecutionRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/enterprise-ssid", config.dnac_url);
+    let mut body = serde_json::to_value(ssid)?;
+    if let Some(fields) = body.as_object_mut() {
+        for field in READ_ONLY_FIELDS {
+            fields.remove(field);
+        }
+    }
+
+    let resp = client
+        .put(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&body)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to update SSID: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<ExecutionRef>().await?)
+}
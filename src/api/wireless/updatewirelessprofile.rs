@@ -0,0 +1,37 @@
+// src/api/wireless/updatewirelessprofile.rs
+
+use crate::app::config::Config;
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::getexecution::ExecutionRef;
+use crate::api::wireless::getwirelessprofiles::{ProfileDetails, WirelessProfile};
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+
+/// Replace a wireless profile's sites and SSIDs with those in `profile`.
+pub async fn update_wireless_profile(config: &Config, token: &Token, profile: &ProfileDetails) -> Result<ExecutionRef> {
+    let client = Client::builder()
+        .danger_accept_invalid_certs(!config.verify_ssl)
+        .build()?;
+
+    let url = format!("{}/dna/intent/api/v1/wireless/profile", config.dnac_url);
+    let request = WirelessProfile {
+        profile_details: profile.clone(),
+    };
+
+    let resp = client
+        .put(&url)
+        .header("X-Auth-Token", &token.value)
+        .json(&request)
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "Failed to update wireless profile: {} - {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    Ok(resp.json::<ExecutionRef>().await?)
+}
@@ -0,0 +1,345 @@
+use crate::api::authentication::auth;
+use crate::api::clients::getclientdetail::{self, ClientDetailResponse};
+use crate::api::devices::getdevicelist::{self, AllDevices};
+use crate::api::sites::getsitelist::{self, Site};
+use crate::app::config::{self, Config};
+use crate::helpers::utils;
+use anyhow::{anyhow, Context, Result};
+use chrono::DateTime;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+pub const DEVICES: &str = "devices";
+pub const SITES: &str = "sites";
+pub const CLIENTS: &str = "clients";
+
+const DEFAULT_TTL_SECS: u64 = 5 * 60;
+
+/// Set while `watch` re-runs a command, so each poll sees current data.
+static LIVE: AtomicBool = AtomicBool::new(false);
+
+/// How a command should use the local inventory cache.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CacheMode {
+    /// Use cached data while it is within the TTL, otherwise fetch
+    Default,
+    /// Only answer from the cache, never contact Catalyst Center
+    Cached,
+    /// Always fetch and update the cache
+    Refresh,
+}
+
+/// Summary of one kind of cached data.
+pub struct CacheStatus {
+    pub kind: String,
+    pub count: i64,
+    pub refreshed_at: u64,
+}
+
+/// Local SQLite cache of inventory data, stored next to the credentials database.
+pub struct InventoryCache {
+    conn: Mutex<Connection>,
+}
+
+impl InventoryCache {
+    pub fn new<P: AsRef<Path>>(db_path: P) -> Result<Self> {
+        let conn = Connection::open(db_path).context("Failed to open cache database")?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS cache_entries (
+                kind TEXT NOT NULL,
+                key TEXT NOT NULL,
+                data TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY (kind, key)
+            );
+            CREATE TABLE IF NOT EXISTS cache_meta (
+                kind TEXT PRIMARY KEY,
+                refreshed_at INTEGER NOT NULL
+            );
+            -- Access points used to be cached separately; they are in devices
+            DELETE FROM cache_entries WHERE kind = 'aps';
+            DELETE FROM cache_meta WHERE kind = 'aps';",
+        )
+        .context("Failed to create cache tables")?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    pub fn open() -> Result<Self> {
+        Self::new(config::get_cache_db_path())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
+        self.conn
+            .lock()
+            .map_err(|e| anyhow!("Failed to acquire database lock: {}", e))
+    }
+
+    /// Replace every cached entry of `kind` with `items`.
+    pub fn store_all<T: Serialize>(&self, kind: &str, items: &[(String, &T)]) -> Result<()> {
+        let now = utils::current_timestamp();
+        let mut conn = self.lock()?;
+        let tx = conn.transaction()?;
+
+        tx.execute("DELETE FROM cache_entries WHERE kind = ?1", params![kind])?;
+        for (key, item) in items {
+            tx.execute(
+                "INSERT OR REPLACE INTO cache_entries (kind, key, data, fetched_at) VALUES (?1, ?2, ?3, ?4)",
+                params![kind, key, serde_json::to_string(item)?, now],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO cache_meta (kind, refreshed_at) VALUES (?1, ?2)",
+            params![kind, now],
+        )?;
+
+        tx.commit().context("Failed to update cache")?;
+        Ok(())
+    }
+
+    /// Add or replace a single cached entry.
+    pub fn store_one<T: Serialize>(&self, kind: &str, key: &str, item: &T) -> Result<()> {
+        let now = utils::current_timestamp();
+        let conn = self.lock()?;
+        conn.execute(
+            "INSERT OR REPLACE INTO cache_entries (kind, key, data, fetched_at) VALUES (?1, ?2, ?3, ?4)",
+            params![kind, key, serde_json::to_string(item)?, now],
+        )?;
+        conn.execute(
+            "INSERT OR REPLACE INTO cache_meta (kind, refreshed_at) VALUES (?1, ?2)",
+            params![kind, now],
+        )?;
+        Ok(())
+    }
+
+    /// All entries of `kind` and when they were last refreshed, if any were stored.
+    pub fn load_all<T: DeserializeOwned>(&self, kind: &str) -> Result<Option<(Vec<T>, u64)>> {
+        let conn = self.lock()?;
+        let refreshed_at: Option<u64> = conn
+            .query_row(
+                "SELECT refreshed_at FROM cache_meta WHERE kind = ?1",
+                params![kind],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        let refreshed_at = match refreshed_at {
+            Some(refreshed_at) => refreshed_at,
+            None => return Ok(None),
+        };
+
+        let mut stmt = conn.prepare("SELECT data FROM cache_entries WHERE kind = ?1 ORDER BY key")?;
+        let items = stmt
+            .query_map(params![kind], |row| row.get::<_, String>(0))?
+            .map(|data| Ok(serde_json::from_str(&data?)?))
+            .collect::<Result<Vec<T>>>()
+            .context("Failed to read cached entries")?;
+
+        Ok(Some((items, refreshed_at)))
+    }
+
+    /// A single entry and when it was fetched.
+    pub fn load_one<T: DeserializeOwned>(&self, kind: &str, key: &str) -> Result<Option<(T, u64)>> {
+        let conn = self.lock()?;
+        let row: Option<(String, u64)> = conn
+            .query_row(
+                "SELECT data, fetched_at FROM cache_entries WHERE kind = ?1 AND key = ?2",
+                params![kind, key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        match row {
+            Some((data, fetched_at)) => Ok(Some((serde_json::from_str(&data)?, fetched_at))),
+            None => Ok(None),
+        }
+    }
+
+    pub fn status(&self) -> Result<Vec<CacheStatus>> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(
+            "SELECT m.kind, m.refreshed_at, COUNT(e.key)
+             FROM cache_meta m LEFT JOIN cache_entries e ON e.kind = m.kind
+             GROUP BY m.kind ORDER BY m.kind",
+        )?;
+        let status = stmt
+            .query_map([], |row| {
+                Ok(CacheStatus {
+                    kind: row.get(0)?,
+                    refreshed_at: row.get(1)?,
+                    count: row.get(2)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(status)
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        let conn = self.lock()?;
+        conn.execute_batch("DELETE FROM cache_entries; DELETE FROM cache_meta;")
+            .context("Failed to clear cache")?;
+        Ok(())
+    }
+}
+
+/// Make `CacheMode::Default` always fetch while `live` is set. `--cached`
+/// still answers from the cache.
+pub fn set_live(live: bool) {
+    LIVE.store(live, Ordering::SeqCst);
+}
+
+fn effective(mode: CacheMode) -> CacheMode {
+    if mode == CacheMode::Default && LIVE.load(Ordering::SeqCst) {
+        CacheMode::Refresh
+    } else {
+        mode
+    }
+}
+
+fn is_fresh(config: &Config, fetched_at: u64) -> bool {
+    let ttl_ms = config.cache_ttl_secs.unwrap_or(DEFAULT_TTL_SECS) * 1000;
+    utils::current_timestamp().saturating_sub(fetched_at) < ttl_ms
+}
+
+pub fn format_age(timestamp: u64) -> String {
+    let time = DateTime::from_timestamp_millis(timestamp as i64)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let age_secs = utils::current_timestamp().saturating_sub(timestamp) / 1000;
+    let age = match age_secs {
+        0..=59 => format!("{}s", age_secs),
+        60..=3599 => format!("{}m", age_secs / 60),
+        3600..=86399 => format!("{}h", age_secs / 3600),
+        _ => format!("{}d", age_secs / 86400),
+    };
+    format!("{} ({} ago)", time, age)
+}
+
+/// Fetch devices from Catalyst Center and store them.
+pub async fn refresh_devices(config: &Config) -> Result<Vec<AllDevices>> {
+    let token = auth::authenticate(config).await?;
+    let devices = getdevicelist::get_all_devices(config, &token).await?;
+
+    let entries: Vec<(String, &AllDevices)> = devices
+        .iter()
+        .map(|device| (device_key(device), device))
+        .collect();
+    InventoryCache::open()?.store_all(DEVICES, &entries)?;
+
+    Ok(devices)
+}
+
+pub async fn refresh_sites(config: &Config) -> Result<Vec<Site>> {
+    let token = auth::authenticate(config).await?;
+    let sites = getsitelist::get_all_sites(config, &token).await?;
+
+    let entries: Vec<(String, &Site)> = sites
+        .iter()
+        .map(|site| (site.id.clone().unwrap_or_default(), site))
+        .collect();
+    InventoryCache::open()?.store_all(SITES, &entries)?;
+
+    Ok(sites)
+}
+
+/// Whether `e` comes from failing to reach Catalyst Center at all, as opposed
+/// to an error response or a body that could not be parsed.
+fn is_unreachable(e: &anyhow::Error) -> bool {
+    e.chain()
+        .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+        .any(|e| e.is_connect() || e.is_timeout())
+}
+
+/// Devices from the cache or Catalyst Center depending on `mode`. If the
+/// controller cannot be reached, cached devices are used instead.
+pub async fn get_devices(config: &Config, mode: CacheMode) -> Result<Vec<AllDevices>> {
+    let mode = effective(mode);
+    let cached = InventoryCache::open()?.load_all::<AllDevices>(DEVICES)?;
+
+    match (mode, cached) {
+        (CacheMode::Cached, Some((devices, refreshed_at))) => {
+            eprintln!("Using cached devices from {}", format_age(refreshed_at));
+            Ok(devices)
+        }
+        (CacheMode::Cached, None) => Err(anyhow!(
+            "No cached devices. Run 'app cache refresh' while Catalyst Center is reachable."
+        )),
+        (CacheMode::Default, Some((devices, refreshed_at))) if is_fresh(config, refreshed_at) => {
+            Ok(devices)
+        }
+        (_, cached) => match refresh_devices(config).await {
+            Ok(devices) => Ok(devices),
+            Err(e) => match cached {
+                Some((devices, refreshed_at)) if mode == CacheMode::Default && is_unreachable(&e) => {
+                    eprintln!(
+                        "Catalyst Center unreachable ({}). Using cached devices from {}",
+                        e,
+                        format_age(refreshed_at)
+                    );
+                    Ok(devices)
+                }
+                _ => Err(e),
+            },
+        },
+    }
+}
+
+/// Client detail from the cache or Catalyst Center depending on `mode`.
+pub async fn get_client_detail(
+    config: &Config,
+    mac_address: &str,
+    mode: CacheMode,
+) -> Result<ClientDetailResponse> {
+    let mode = effective(mode);
+    let cache = InventoryCache::open()?;
+    let key = mac_address.to_lowercase();
+    let cached = cache.load_one::<ClientDetailResponse>(CLIENTS, &key)?;
+
+    match (mode, cached) {
+        (CacheMode::Cached, Some((client, fetched_at))) => {
+            eprintln!("Using cached client detail from {}", format_age(fetched_at));
+            Ok(client)
+        }
+        (CacheMode::Cached, None) => Err(anyhow!("No cached detail for client {}", mac_address)),
+        (CacheMode::Default, Some((client, fetched_at))) if is_fresh(config, fetched_at) => {
+            Ok(client)
+        }
+        (_, cached) => {
+            let fetched = match auth::authenticate(config).await {
+                Ok(token) => getclientdetail::get_client_detail(config, &token, mac_address).await,
+                Err(e) => Err(e),
+            };
+            match (fetched, cached) {
+                (Ok(client), _) => {
+                    cache.store_one(CLIENTS, &key, &client)?;
+                    Ok(client)
+                }
+                (Err(e), Some((client, fetched_at))) if mode == CacheMode::Default && is_unreachable(&e) => {
+                    eprintln!(
+                        "Catalyst Center unreachable ({}). Using cached client detail from {}",
+                        e,
+                        format_age(fetched_at)
+                    );
+                    Ok(client)
+                }
+                (Err(e), _) => Err(e),
+            }
+        }
+    }
+}
+
+fn device_key(device: &AllDevices) -> String {
+    device
+        .id
+        .clone()
+        .or_else(|| device.serial_number.clone())
+        .or_else(|| device.hostname.clone())
+        .unwrap_or_default()
+}
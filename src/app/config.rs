@@ -12,6 +12,10 @@ pub struct Config {
     pub dnac_url: String,
     pub username: String,
     pub verify_ssl: bool,
+    #[serde(default)]
+    pub captures_dir: Option<String>,
+    #[serde(default)]
+    pub cache_ttl_secs: Option<u64>,
 }
 
 impl Config {
@@ -20,6 +24,8 @@ impl Config {
             dnac_url,
             username,
             verify_ssl,
+            captures_dir: None,
+            cache_ttl_secs: None,
         }
     }
 }
@@ -39,6 +45,31 @@ pub fn get_credentials_db_path() -> PathBuf {
     db_path
 }
 
+pub fn get_cache_db_path() -> PathBuf {
+    let mut db_path = config_dir().unwrap();
+    db_path.push("catalysh");
+    db_path.push("cache.db");
+    db_path
+}
+
+/// Directory that relative output redirection paths are resolved under
+pub fn get_captures_dir() -> PathBuf {
+    let configured = fs::read_to_string(get_config_path())
+        .ok()
+        .and_then(|contents| serde_yaml::from_str::<Config>(&contents).ok())
+        .and_then(|config| config.captures_dir);
+
+    match configured {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let mut captures_path = config_dir().unwrap();
+            captures_path.push("catalysh");
+            captures_path.push("captures");
+            captures_path
+        }
+    }
+}
+
 /// Load configuration and trigger setup if necessary
 pub fn load_config() -> Result<Config> {
     let config_path = get_config_path();
@@ -58,11 +89,16 @@ pub fn load_config() -> Result<Config> {
 pub fn reset_config() -> Result<()> {
     let config_path = get_config_path();
     let credentials_db_path = get_credentials_db_path();
+    let cache_db_path = get_cache_db_path();
 
     if config_path.exists() {
         fs::remove_file(config_path)?;
     }
 
+    if cache_db_path.exists() {
+        fs::remove_file(cache_db_path)?;
+    }
+
     if credentials_db_path.exists() {
         fs::remove_file(credentials_db_path)?;
     }
@@ -89,6 +125,24 @@ pub fn update_verify_ssl(verify: bool) -> Result<()> {
     Ok(())
 }
 
+/// Update the directory used for saved command output
+pub fn update_captures_dir(dir: String) -> Result<()> {
+    let mut config = load_config()?;
+    config.captures_dir = Some(dir);
+    save_config(&config)?;
+    println!("Captures directory updated successfully.");
+    Ok(())
+}
+
+/// Update how long cached inventory is used before it is re-fetched
+pub fn update_cache_ttl(seconds: u64) -> Result<()> {
+    let mut config = load_config()?;
+    config.cache_ttl_secs = Some(seconds);
+    save_config(&config)?;
+    println!("Cache TTL updated successfully.");
+    Ok(())
+}
+
 /// Reset only the stored credentials while keeping other settings
 pub fn reset_credentials() -> Result<()> {
     let credentials_db_path = get_credentials_db_path();
@@ -1,3 +1,5 @@
 pub mod config;
 pub mod update;
 pub mod auth_storage;
+pub mod cache;
+pub mod snapshot;
@@ -0,0 +1,293 @@
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::app::config;
+use crate::helpers::utils;
+use anyhow::{anyhow, Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// A stored copy of the device inventory at a point in time.
+pub struct Snapshot {
+    pub id: i64,
+    pub label: Option<String>,
+    pub created_at: u64,
+    pub devices: Vec<AllDevices>,
+}
+
+/// Summary of a stored snapshot, without its devices.
+pub struct SnapshotInfo {
+    pub id: i64,
+    pub label: Option<String>,
+    pub created_at: u64,
+    pub device_count: i64,
+}
+
+/// Inventory snapshots, kept in the same database as the inventory cache.
+pub struct SnapshotStore {
+    conn: Mutex<Connection>,
+}
+
+impl SnapshotStore {
+    pub fn new<P: AsRef<Path>>(db_path: P) -> Result<Self> {
+        let conn = Connection::open(db_path).context("Failed to open cache database")?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                label TEXT,
+                created_at INTEGER NOT NULL,
+                device_count INTEGER NOT NULL,
+                data TEXT NOT NULL
+            )",
+            [],
+        )
+        .context("Failed to create snapshots table")?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    pub fn open() -> Result<Self> {
+        Self::new(config::get_cache_db_path())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
+        self.conn
+            .lock()
+            .map_err(|e| anyhow!("Failed to acquire database lock: {}", e))
+    }
+
+    /// Store `devices` as a new snapshot and return its id.
+    pub fn save(&self, label: Option<&str>, devices: &[AllDevices]) -> Result<i64> {
+        let conn = self.lock()?;
+        conn.execute(
+            "INSERT INTO snapshots (label, created_at, device_count, data) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                label,
+                utils::current_timestamp(),
+                devices.len() as i64,
+                serde_json::to_string(devices)?
+            ],
+        )
+        .context("Failed to store snapshot")?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    pub fn list(&self) -> Result<Vec<SnapshotInfo>> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, label, created_at, device_count FROM snapshots ORDER BY id",
+        )?;
+        let snapshots = stmt
+            .query_map([], |row| {
+                Ok(SnapshotInfo {
+                    id: row.get(0)?,
+                    label: row.get(1)?,
+                    created_at: row.get(2)?,
+                    device_count: row.get(3)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(snapshots)
+    }
+
+    /// Look up a snapshot by id, or by label (the most recent one with that label).
+    pub fn find(&self, reference: &str) -> Result<Snapshot> {
+        let conn = self.lock()?;
+        let row: Option<(i64, Option<String>, u64, String)> = conn
+            .query_row(
+                "SELECT id, label, created_at, data FROM snapshots
+                 WHERE CAST(id AS TEXT) = ?1 OR label = ?1
+                 ORDER BY (CAST(id AS TEXT) = ?1) DESC, id DESC LIMIT 1",
+                params![reference],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .optional()?;
+
+        let (id, label, created_at, data) =
+            row.ok_or_else(|| anyhow!("No snapshot with id or label '{}'", reference))?;
+        let devices = serde_json::from_str(&data)
+            .with_context(|| format!("Failed to read snapshot {}", id))?;
+
+        Ok(Snapshot {
+            id,
+            label,
+            created_at,
+            devices,
+        })
+    }
+}
+
+/// One difference found between two snapshots.
+pub struct DeviceChange {
+    pub hostname: String,
+    pub change: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// Compare two inventories, matching devices by serial number.
+pub fn diff(before: &[AllDevices], after: &[AllDevices]) -> Vec<DeviceChange> {
+    let before = by_key(before);
+    let after = by_key(after);
+    let mut changes = Vec::new();
+
+    for (key, old) in &before {
+        let new = match after.get(key) {
+            Some(new) => new,
+            None => {
+                changes.push(DeviceChange {
+                    hostname: hostname(old),
+                    change: "Removed",
+                    before: value(&old.management_ip_address),
+                    after: String::new(),
+                });
+                continue;
+            }
+        };
+
+        let compared = [
+            ("Re-IP'd", &old.management_ip_address, &new.management_ip_address),
+            ("Re-roled", &old.role, &new.role),
+            ("Version changed", &old.software_version, &new.software_version),
+        ];
+        for (change, old_value, new_value) in compared {
+            if old_value != new_value {
+                changes.push(DeviceChange {
+                    hostname: hostname(new),
+                    change,
+                    before: value(old_value),
+                    after: value(new_value),
+                });
+            }
+        }
+
+        if old.reachability_status != new.reachability_status {
+            // Statuses such as "Ping Reachable" are neither fully up nor down
+            let reachable = |status: &Option<String>| status.as_deref() == Some("Reachable");
+            let change = match (reachable(&old.reachability_status), reachable(&new.reachability_status)) {
+                (false, true) => "Became reachable",
+                (true, false) => "Became unreachable",
+                _ => "Reachability changed",
+            };
+            changes.push(DeviceChange {
+                hostname: hostname(new),
+                change,
+                before: value(&old.reachability_status),
+                after: value(&new.reachability_status),
+            });
+        }
+    }
+
+    for (key, new) in &after {
+        if !before.contains_key(key) {
+            changes.push(DeviceChange {
+                hostname: hostname(new),
+                change: "Added",
+                before: String::new(),
+                after: value(&new.management_ip_address),
+            });
+        }
+    }
+
+    changes.sort_by(|a, b| a.hostname.cmp(&b.hostname));
+    changes
+}
+
+// Serial numbers survive re-IPs and renames, so prefer them as the identity.
+fn by_key(devices: &[AllDevices]) -> BTreeMap<String, &AllDevices> {
+    devices
+        .iter()
+        .map(|device| {
+            let key = device
+                .serial_number
+                .clone()
+                .or_else(|| device.id.clone())
+                .or_else(|| device.hostname.clone())
+                .unwrap_or_default();
+            (key, device)
+        })
+        .collect()
+}
+
+fn hostname(device: &AllDevices) -> String {
+    device
+        .hostname
+        .clone()
+        .or_else(|| device.serial_number.clone())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+fn value(field: &Option<String>) -> String {
+    field.clone().unwrap_or_else(|| "N/A".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn device(serial: &str, hostname: &str, ip: &str, reachability: &str) -> AllDevices {
+        serde_json::from_value(json!({
+            "serialNumber": serial,
+            "hostname": hostname,
+            "managementIpAddress": ip,
+            "role": "ACCESS",
+            "softwareVersion": "17.9.4",
+            "reachabilityStatus": reachability,
+        }))
+        .unwrap()
+    }
+
+    fn summary(changes: &[DeviceChange]) -> Vec<(String, &'static str)> {
+        changes
+            .iter()
+            .map(|change| (change.hostname.clone(), change.change))
+            .collect()
+    }
+
+    #[test]
+    fn identical_inventories_have_no_changes() {
+        let devices = [device("S1", "edge-1", "10.0.0.1", "Reachable")];
+        assert!(diff(&devices, &devices).is_empty());
+    }
+
+    #[test]
+    fn reports_added_and_removed_devices() {
+        let before = [device("S1", "edge-1", "10.0.0.1", "Reachable")];
+        let after = [device("S2", "edge-2", "10.0.0.2", "Reachable")];
+        assert_eq!(
+            summary(&diff(&before, &after)),
+            [("edge-1".to_string(), "Removed"), ("edge-2".to_string(), "Added")]
+        );
+    }
+
+    #[test]
+    fn matches_devices_by_serial_across_renames() {
+        let before = [device("S1", "edge-1", "10.0.0.1", "Reachable")];
+        let after = [device("S1", "edge-1a", "10.0.0.9", "Reachable")];
+        let changes = diff(&before, &after);
+        assert_eq!(summary(&changes), [("edge-1a".to_string(), "Re-IP'd")]);
+        assert_eq!(changes[0].before, "10.0.0.1");
+        assert_eq!(changes[0].after, "10.0.0.9");
+    }
+
+    #[test]
+    fn labels_reachability_changes() {
+        let cases = [
+            ("Reachable", "Unreachable", "Became unreachable"),
+            ("Unreachable", "Reachable", "Became reachable"),
+            ("Reachable", "Ping Reachable", "Became unreachable"),
+            ("Ping Reachable", "Unreachable", "Reachability changed"),
+        ];
+        for (old, new, label) in cases {
+            let changes = diff(
+                &[device("S1", "edge-1", "10.0.0.1", old)],
+                &[device("S1", "edge-1", "10.0.0.1", new)],
+            );
+            assert_eq!(summary(&changes), [("edge-1".to_string(), label)], "{} -> {}", old, new);
+        }
+    }
+}
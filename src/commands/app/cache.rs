@@ -0,0 +1,11 @@
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum AppCacheCommands {
+    /// Show what is stored in the local inventory cache
+    Status,
+    /// Re-fetch devices, APs and sites into the cache
+    Refresh,
+    /// Delete all cached inventory data
+    Clear,
+}
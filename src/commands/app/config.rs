@@ -18,6 +18,16 @@ pub enum AppConfigCommands {
         action: SetVerifySslAction,
     },
     ResetCredentials,
+    /// Set how many seconds cached inventory is used before it is re-fetched
+    SetCacheTtl {
+        #[arg(help = "Cache lifetime in seconds")]
+        seconds: u64,
+    },
+    /// Set the directory relative output redirection paths are saved under
+    SetCapturesDir {
+        #[arg(help = "Directory for saved command output")]
+        path: String,
+    },
 }
 
 #[derive(Debug, Subcommand)]
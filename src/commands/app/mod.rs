@@ -1,5 +1,6 @@
 pub mod config;
 pub mod update; // Added this line
+pub mod cache;
 
 use clap::Subcommand;
 
@@ -10,6 +11,11 @@ pub enum AppCommands {
         #[command(subcommand)]
         subcommand: config::AppConfigCommands,
     },
+    /// Local inventory cache commands
+    Cache {
+        #[command(subcommand)]
+        subcommand: cache::AppCacheCommands,
+    },
     /// Update the program to the latest release available (Program restart needed for changes to take effect)
     Update,
 }
@@ -0,0 +1,25 @@
+// src/commands/compliance.rs
+
+use crate::commands::show::compliance::ComplianceType;
+use crate::commands::watch;
+use clap::Subcommand;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ComplianceCommands {
+    /// Re-check compliance of matching devices and wait for the result
+    Run {
+        /// Hostname or hostname pattern such as 'edge-*'
+        #[arg(required_unless_present = "site")]
+        device: Option<String>,
+        /// Only devices at this site (and its child sites)
+        #[arg(long)]
+        site: Option<String>,
+        /// Only check these compliance types (comma-separated); all by default
+        #[arg(long = "type", value_enum, ignore_case = true, value_delimiter = ',')]
+        compliance_types: Vec<ComplianceType>,
+        /// How long to wait for the check to finish (e.g. 5m)
+        #[arg(long, default_value = "10m", value_parser = watch::parse_interval)]
+        timeout: Duration,
+    },
+}
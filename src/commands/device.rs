@@ -0,0 +1,75 @@
+// src/commands/device.rs
+
+use crate::commands::watch;
+use clap::{Args, Subcommand};
+use std::net::IpAddr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum DeviceCommands {
+    /// Add devices by discovering their IPs with a global credential profile
+    Add {
+        /// Management IPs to add, separated by commas
+        #[arg(long, required = true, value_delimiter = ',')]
+        ip: Vec<IpAddr>,
+        /// Global credentials whose description is this profile name
+        #[arg(long)]
+        credentials: String,
+        #[command(flatten)]
+        action: DeviceActionArgs,
+    },
+    /// Resynchronize matching devices' inventory
+    Resync {
+        #[command(flatten)]
+        select: DeviceSelectArgs,
+        #[command(flatten)]
+        action: DeviceActionArgs,
+    },
+    /// Remove matching devices from inventory
+    Delete {
+        #[command(flatten)]
+        select: DeviceSelectArgs,
+        /// Remove the configuration Catalyst Center pushed to each device first
+        #[arg(long)]
+        clean_config: bool,
+        #[command(flatten)]
+        action: DeviceActionArgs,
+    },
+    /// Assign matching devices to a building or floor
+    AssignSite {
+        /// Hostname or hostname pattern such as 'edge-*'
+        device: String,
+        /// Site hierarchy to assign to, e.g. Global/US/Austin/Building-1
+        #[arg(long)]
+        site: String,
+        #[command(flatten)]
+        action: DeviceActionArgs,
+    },
+}
+
+/// Which inventory devices an operation applies to.
+#[derive(Debug, Clone, Args)]
+pub struct DeviceSelectArgs {
+    /// Hostname or hostname pattern such as 'edge-*'
+    #[arg(required_unless_present_any = ["site", "unreachable"])]
+    pub device: Option<String>,
+    /// Only devices at this site (and its child sites)
+    #[arg(long)]
+    pub site: Option<String>,
+    /// Only devices whose reachability status is not Reachable
+    #[arg(long)]
+    pub unreachable: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct DeviceActionArgs {
+    /// Show what would be done without starting anything
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Do not ask for confirmation
+    #[arg(long, short)]
+    pub yes: bool,
+    /// How long to track the tasks (e.g. 5m)
+    #[arg(long, default_value = "10m", value_parser = watch::parse_interval)]
+    pub timeout: Duration,
+}
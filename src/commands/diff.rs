@@ -0,0 +1,26 @@
+// src/commands/diff.rs
+
+use clap::Subcommand;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum DiffCommands {
+    /// Compare device data
+    Device {
+        #[command(subcommand)]
+        subcommand: DiffDeviceCommands,
+    },
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum DiffDeviceCommands {
+    /// Unified diff of two running configs, e.g. `diff device config core-1 core-2`
+    Config {
+        /// First config: a device, or <device>@<version> for an archived version
+        a: String,
+        /// Second config: a device, or <device>@<version> for an archived version
+        b: String,
+        /// Unchanged lines to show around each change
+        #[arg(long, short = 'U', default_value_t = 3)]
+        context: usize,
+    },
+}
@@ -0,0 +1,95 @@
+// src/commands/discovery.rs
+
+use crate::commands::watch;
+use clap::Subcommand;
+use std::net::Ipv4Addr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum DiscoveryCommands {
+    /// Discover devices in IP ranges and follow its progress
+    Start {
+        /// Ranges to discover (comma-separated), as CIDR such as 10.1.0.0/24,
+        /// a span such as 10.1.0.10-10.1.0.50, or a single address
+        #[arg(long, required = true, value_delimiter = ',', value_parser = parse_range)]
+        range: Vec<String>,
+        /// Global credential profiles to try, by description (comma-separated)
+        #[arg(long, required = true, value_delimiter = ',')]
+        credentials: Vec<String>,
+        /// Name of the discovery job; defaults to one with the current time
+        #[arg(long)]
+        name: Option<String>,
+        /// How long to follow the discovery (e.g. 30m)
+        #[arg(long, default_value = "1h", value_parser = watch::parse_interval)]
+        timeout: Duration,
+    },
+}
+
+/// Parse a CIDR block, span or address into the `first-last` form the
+/// discovery API takes. CIDR blocks exclude the network and broadcast addresses.
+pub fn parse_range(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    let address = |text: &str| {
+        text.trim()
+            .parse::<Ipv4Addr>()
+            .map_err(|_| format!("invalid IPv4 address '{}'", text.trim()))
+    };
+
+    let (first, last) = if let Some((network, prefix)) = input.split_once('/') {
+        let prefix: u32 = prefix
+            .parse()
+            .ok()
+            .filter(|prefix| *prefix <= 32)
+            .ok_or_else(|| format!("invalid prefix length in '{}'", input))?;
+        let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+        let base = u32::from(address(network)?) & mask;
+        let broadcast = base | !mask;
+        if prefix >= 31 {
+            (base, broadcast)
+        } else {
+            (base + 1, broadcast - 1)
+        }
+    } else if let Some((first, last)) = input.split_once('-') {
+        (u32::from(address(first)?), u32::from(address(last)?))
+    } else {
+        let single = u32::from(address(input)?);
+        (single, single)
+    };
+
+    if first > last {
+        return Err(format!("range '{}' ends before it starts", input));
+    }
+    Ok(format!("{}-{}", Ipv4Addr::from(first), Ipv4Addr::from(last)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cidr_excludes_network_and_broadcast() {
+        assert_eq!(parse_range("10.1.1.0/24"), Ok("10.1.1.1-10.1.1.254".to_string()));
+        assert_eq!(parse_range("10.1.1.77/30"), Ok("10.1.1.77-10.1.1.78".to_string()));
+    }
+
+    #[test]
+    fn small_prefixes_keep_every_address() {
+        assert_eq!(parse_range("10.1.1.4/31"), Ok("10.1.1.4-10.1.1.5".to_string()));
+        assert_eq!(parse_range("10.1.1.4/32"), Ok("10.1.1.4-10.1.1.4".to_string()));
+        assert_eq!(parse_range("0.0.0.0/0"), Ok("0.0.0.1-255.255.255.254".to_string()));
+    }
+
+    #[test]
+    fn spans_and_single_addresses() {
+        assert_eq!(parse_range("10.0.0.5 - 10.0.0.9"), Ok("10.0.0.5-10.0.0.9".to_string()));
+        assert_eq!(parse_range("10.0.0.5"), Ok("10.0.0.5-10.0.0.5".to_string()));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(parse_range("10.0.0.0/33").is_err());
+        assert!(parse_range("10.0.0.0/x").is_err());
+        assert!(parse_range("10.0.0.256").is_err());
+        assert!(parse_range("10.0.0.9-10.0.0.5").is_err());
+    }
+}
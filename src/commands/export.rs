@@ -0,0 +1,36 @@
+// src/commands/export.rs
+
+use crate::commands::show::cache::CacheArgs;
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Debug, Subcommand)]
+pub enum ExportCommands {
+    /// Export the device inventory for use by other automation tools
+    Inventory {
+        /// Target format
+        #[arg(long, short, value_enum)]
+        format: ExportFormat,
+        /// Group hosts by these attributes (Ansible and Nornir only)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        group_by: Vec<GroupBy>,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// Ansible dynamic inventory JSON
+    Ansible,
+    /// Nornir SimpleInventory hosts.yaml
+    Nornir,
+    /// NetBox device bulk import CSV
+    NetboxCsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum GroupBy {
+    Site,
+    Role,
+    Family,
+}
@@ -0,0 +1,47 @@
+// src/commands/image.rs
+
+use crate::commands::watch;
+use clap::{Args, Subcommand};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ImageCommands {
+    /// Copy the golden (or given) image to matching devices
+    Distribute {
+        #[command(flatten)]
+        args: ImageActionArgs,
+    },
+    /// Activate the golden (or given) image on matching devices; devices reload
+    Activate {
+        #[command(flatten)]
+        args: ImageActionArgs,
+    },
+}
+
+/// Which devices and image an upgrade step applies to. Devices failing the
+/// readiness pre-checks or already on the target version are skipped.
+#[derive(Debug, Clone, Args)]
+pub struct ImageActionArgs {
+    /// Hostname or hostname pattern such as 'edge-*'
+    #[arg(required_unless_present = "site")]
+    pub device: Option<String>,
+    /// Only devices at this site (and its child sites)
+    #[arg(long)]
+    pub site: Option<String>,
+    /// Image name or id to use instead of each device's golden image
+    #[arg(long)]
+    pub image: Option<String>,
+    /// Show what would be done without starting anything
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Start at most this many devices at a time, waiting for each wave to
+    /// finish before the next; all at once by default
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub batch: Option<u16>,
+    /// Do not ask for confirmation
+    #[arg(long, short)]
+    pub yes: bool,
+    /// How long to track the tasks (e.g. 30m, 2h)
+    #[arg(long, default_value = "2h", value_parser = watch::parse_interval)]
+    pub timeout: Duration,
+}
@@ -0,0 +1,22 @@
+// src/commands/inventory.rs
+
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum InventoryCommands {
+    /// Save the current device inventory as a snapshot
+    Snapshot {
+        /// Label to refer to the snapshot by (e.g. pre-change)
+        #[arg(long)]
+        label: Option<String>,
+    },
+    /// List stored snapshots
+    List,
+    /// Show devices added, removed, re-IP'd, re-roled, upgraded or unreachable between two snapshots
+    Diff {
+        /// Snapshot id or label to compare from
+        from: String,
+        /// Snapshot id or label to compare to
+        to: String,
+    },
+}
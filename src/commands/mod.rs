@@ -1,10 +1,25 @@
 pub mod show;
 pub mod config;
 pub mod app;
+pub mod watch;
+pub mod terminal;
+pub mod inventory;
+pub mod export;
+pub mod trace;
+pub mod diff;
+pub mod compliance;
+pub mod image;
+pub mod template;
+pub mod pnp;
+pub mod device;
+pub mod discovery;
+pub mod tag;
 
 use clap::{Parser, Subcommand};
+use std::net::SocketAddr;
+use std::time::Duration;
 use log::error;
-use crate::handlers::{handle_show_command, handle_config_command, handle_app_command, clear_screen};
+use crate::handlers::{handle_show_command, handle_config_command, handle_app_command, handle_watch_command, handle_terminal_command, handle_inventory_command, handle_export_command, handle_exporter_command, handle_trace_command, handle_diff_command, handle_compliance_command, handle_image_command, handle_template_command, handle_pnp_command, handle_device_command, handle_discovery_command, handle_tag_command, clear_screen};
 
 #[derive(Debug, Parser)]
 #[command(name = "catalysh", about = "A command line interface for Cisco Catalyst Center")]
@@ -27,17 +42,123 @@ pub enum Commands {
         #[command(subcommand)]
         subcommand: app::AppCommands,
     },
+    /// Re-run a show command on an interval and highlight changes
+    Watch {
+        /// Refresh interval (e.g. 30s, 5m, 1h)
+        #[arg(long, short, default_value = "30s", value_parser = watch::parse_interval)]
+        interval: Duration,
+        #[command(subcommand)]
+        command: watch::WatchCommands,
+    },
+    /// Terminal settings for this session
+    Terminal {
+        #[command(subcommand)]
+        subcommand: terminal::TerminalCommands,
+    },
+    /// Inventory snapshots and diffs
+    Inventory {
+        #[command(subcommand)]
+        subcommand: inventory::InventoryCommands,
+    },
+    /// Export data for other tools
+    Export {
+        #[command(subcommand)]
+        subcommand: export::ExportCommands,
+    },
+    /// Trace the path of a flow between two endpoints
+    Trace {
+        #[command(flatten)]
+        args: trace::TraceArgs,
+    },
+    /// Compare configurations
+    Diff {
+        #[command(subcommand)]
+        subcommand: diff::DiffCommands,
+    },
+    /// Run compliance checks
+    Compliance {
+        #[command(subcommand)]
+        subcommand: compliance::ComplianceCommands,
+    },
+    /// Distribute and activate software images
+    Image {
+        #[command(subcommand)]
+        subcommand: image::ImageCommands,
+    },
+    /// Render and deploy configuration templates
+    Template {
+        #[command(subcommand)]
+        subcommand: template::TemplateCommands,
+    },
+    /// Claim and reset Plug and Play devices
+    Pnp {
+        #[command(subcommand)]
+        subcommand: pnp::PnpCommands,
+    },
+    /// Add, resync, delete and assign inventory devices
+    Device {
+        #[command(subcommand)]
+        subcommand: device::DeviceCommands,
+    },
+    /// Discover devices in IP ranges
+    Discovery {
+        #[command(subcommand)]
+        subcommand: discovery::DiscoveryCommands,
+    },
+    /// Create tags and tag or untag devices and interfaces
+    Tag {
+        #[command(subcommand)]
+        subcommand: tag::TagCommands,
+    },
+    /// Serve Catalyst Center health metrics for Prometheus until stopped
+    Exporter {
+        /// Address to serve /metrics on
+        #[arg(long, default_value = "127.0.0.1:9800")]
+        listen: SocketAddr,
+        /// How often to poll Catalyst Center (e.g. 30s, 5m)
+        #[arg(long, short, default_value = "60s", value_parser = watch::parse_interval)]
+        interval: Duration,
+    },
     /// Clear the screen
     Clear,
     /// Exit the program
     Exit,
 }
 
+impl Commands {
+    /// Commands whose output is collected before printing so it can be paged.
+    /// Interactive commands such as `watch` and `config` print directly.
+    pub fn is_pageable(&self) -> bool {
+        matches!(
+            self,
+            Commands::Show { .. }
+                | Commands::App { .. }
+                | Commands::Inventory { .. }
+                | Commands::Export { .. }
+                | Commands::Diff { .. }
+        )
+    }
+}
+
 pub fn route_command(command: Commands) {
     match command {
         Commands::Show { subcommand } => handle_show_command(subcommand),
         Commands::Config => handle_config_command(),
         Commands::App { subcommand } => handle_app_command(subcommand),
+        Commands::Watch { interval, command } => handle_watch_command(interval, command),
+        Commands::Terminal { subcommand } => handle_terminal_command(subcommand),
+        Commands::Inventory { subcommand } => handle_inventory_command(subcommand),
+        Commands::Export { subcommand } => handle_export_command(subcommand),
+        Commands::Trace { args } => handle_trace_command(args),
+        Commands::Diff { subcommand } => handle_diff_command(subcommand),
+        Commands::Compliance { subcommand } => handle_compliance_command(subcommand),
+        Commands::Image { subcommand } => handle_image_command(subcommand),
+        Commands::Template { subcommand } => handle_template_command(subcommand),
+        Commands::Pnp { subcommand } => handle_pnp_command(subcommand),
+        Commands::Device { subcommand } => handle_device_command(subcommand),
+        Commands::Discovery { subcommand } => handle_discovery_command(subcommand),
+        Commands::Tag { subcommand } => handle_tag_command(subcommand),
+        Commands::Exporter { listen, interval } => handle_exporter_command(listen, interval),
         Commands::Clear => {
             if let Err(e) = clear_screen() {
                 error!("Failed to clear screen: {}", e);
@@ -0,0 +1,38 @@
+// src/commands/pnp.rs
+
+use clap::Subcommand;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum PnpCommands {
+    /// Claim unclaimed PnP devices to a site, with an optional template and image
+    Claim {
+        /// Serial numbers of the devices to claim
+        #[arg(required = true)]
+        serials: Vec<String>,
+        /// Site hierarchy, e.g. Global/US/Austin/Building-1
+        #[arg(long)]
+        site: String,
+        /// Onboarding template, optionally as <project>/<name>
+        #[arg(long)]
+        template: Option<String>,
+        /// Image to install: a name from the repository, or 'golden' for each device's golden image
+        #[arg(long)]
+        image: Option<String>,
+        /// YAML file of template variables, with per-serial overrides under 'devices'
+        #[arg(long, requires = "template")]
+        vars: Option<PathBuf>,
+        /// Do not ask for confirmation
+        #[arg(long, short)]
+        yes: bool,
+    },
+    /// Reset PnP devices in the Error state so they onboard again
+    Reset {
+        /// Serial numbers of the devices to reset
+        #[arg(required = true)]
+        serials: Vec<String>,
+        /// Do not ask for confirmation
+        #[arg(long, short)]
+        yes: bool,
+    },
+}
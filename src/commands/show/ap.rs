@@ -2,13 +2,16 @@
 
 use clap::Subcommand;
 
-#[derive(Debug, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 pub enum ApCommands {
     /// Show AP configuration by MAC address
     Config {
         /// MAC address of the AP
         mac_address: String,
     },
-    /// Show all RF profiles
-    RfProfile,
+    /// Show all RF profiles, or a single profile by name
+    RfProfile {
+        /// Optional RF profile name
+        name: Option<String>,
+    },
 }
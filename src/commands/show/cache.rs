@@ -0,0 +1,27 @@
+// src/commands/show/cache.rs
+
+use crate::app::cache::CacheMode;
+use clap::Args;
+
+/// Options controlling use of the local inventory cache.
+#[derive(Debug, Clone, Default, Args)]
+pub struct CacheArgs {
+    /// Answer from the local inventory cache without contacting Catalyst Center
+    #[arg(long, global = true, conflicts_with = "refresh")]
+    pub cached: bool,
+    /// Ignore the local inventory cache and fetch fresh data
+    #[arg(long, global = true)]
+    pub refresh: bool,
+}
+
+impl CacheArgs {
+    pub fn mode(&self) -> CacheMode {
+        if self.cached {
+            CacheMode::Cached
+        } else if self.refresh {
+            CacheMode::Refresh
+        } else {
+            CacheMode::Default
+        }
+    }
+}
@@ -1,13 +1,19 @@
 // src/commands/show/client.rs
 
+use crate::commands::show::cache::CacheArgs;
+use crate::commands::show::table::TableArgs;
 use clap::Subcommand;
 
-#[derive(Debug, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 pub enum ClientCommands {
     /// Show client details by MAC address
     Detail {
         /// MAC address of the client
         mac_address: String,
+        #[command(flatten)]
+        table: TableArgs,
+        #[command(flatten)]
+        cache: CacheArgs,
     },
     /// Show client enrichment by network user ID or MAC address
     Enrichment {
@@ -0,0 +1,52 @@
+// src/commands/show/compliance.rs
+
+use crate::commands::show::cache::CacheArgs;
+use clap::{Args, Subcommand, ValueEnum};
+
+/// `show compliance` summarizes compliance across devices; `show compliance
+/// detail <device>` shows what one device is out of compliance with.
+#[derive(Debug, Clone, Args)]
+pub struct ComplianceArgs {
+    #[command(subcommand)]
+    pub subcommand: Option<ComplianceCommands>,
+    /// Only devices at this site (and its child sites)
+    #[arg(long)]
+    pub site: Option<String>,
+    /// Only this compliance type
+    #[arg(long = "type", value_enum, ignore_case = true)]
+    pub compliance_type: Option<ComplianceType>,
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ComplianceCommands {
+    /// Show each compliance type of a device, with the differences found
+    Detail {
+        /// Device hostname, management IP or serial number
+        device: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+#[value(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComplianceType {
+    RunningConfig,
+    Image,
+    Psirt,
+    Eox,
+    NetworkSettings,
+    NetworkProfile,
+    ApplicationVisibility,
+    Fabric,
+    Workflow,
+}
+
+impl ComplianceType {
+    /// Name used by the compliance APIs, e.g. `RUNNING_CONFIG`.
+    pub fn api_name(&self) -> String {
+        self.to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default()
+    }
+}
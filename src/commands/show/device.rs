@@ -1,16 +1,26 @@
 // src/commands/show/device.rs
 
-use clap::Subcommand;
+use crate::commands::show::cache::CacheArgs;
+use crate::commands::show::table::TableArgs;
+use clap::{Args, Subcommand};
+use std::path::PathBuf;
 
-#[derive(Debug, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 pub enum DeviceCommands {
     /// List devices
     List {
+        #[command(flatten)]
+        cache: CacheArgs,
+        /// Only devices carrying this tag
+        #[arg(long, global = true)]
+        tag: Option<String>,
         #[command(subcommand)]
         filter: DeviceListFilter,
     },
     /// Show device details
     Detail {
+        #[command(flatten)]
+        cache: CacheArgs,
         #[command(subcommand)]
         filter: DeviceDetailFilter,
     },
@@ -19,30 +29,79 @@ pub enum DeviceCommands {
         #[command(subcommand)]
         filter: DeviceEnrichmentFilter,
     },
+    /// Show a device's running config, or back up configs with --save
+    Config {
+        #[command(flatten)]
+        args: DeviceConfigArgs,
+    },
+}
+
+/// `show device config <device>` prints one config; with `--save <dir>` the
+/// device may be a hostname pattern such as `edge-*` and every match is saved.
+#[derive(Debug, Clone, Args)]
+pub struct DeviceConfigArgs {
+    /// Device hostname, management IP or serial number (a hostname pattern with --save)
+    #[arg(required_unless_present = "site")]
+    pub device: Option<String>,
+    /// Show this archived version instead of the current running config
+    #[arg(long, conflicts_with = "versions")]
+    pub version: Option<String>,
+    /// List the device's archived config versions
+    #[arg(long, conflicts_with = "save")]
+    pub versions: bool,
+    /// Only save devices at this site (and its child sites)
+    #[arg(long, requires = "save")]
+    pub site: Option<String>,
+    /// Write each matching device's config to <dir>/<hostname>.cfg
+    #[arg(long, value_name = "DIR")]
+    pub save: Option<PathBuf>,
+    #[command(flatten)]
+    pub cache: CacheArgs,
 }
 
-#[derive(Debug, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 pub enum DeviceListFilter {
     /// List all devices
-    All,
+    All {
+        #[command(flatten)]
+        table: TableArgs,
+    },
     /// List devices filtered by hostname
     Hostname {
         /// Optional partial hostname to filter by
         partial_hostname: Option<String>,
+        #[command(flatten)]
+        table: TableArgs,
     },
     /// List devices filtered by IP address
     Ip {
         /// Optional partial IP address to filter by
         partial_ip: Option<String>,
+        #[command(flatten)]
+        table: TableArgs,
     },
     /// List devices filtered by WLC IP address
     Wlc {
         /// Optional partial WLC ip to filter by
         partial_wlc: Option<String>,
+        #[command(flatten)]
+        table: TableArgs,
     },
 }
 
-#[derive(Debug, Subcommand)]
+impl DeviceListFilter {
+    /// Column and sort options given after the filter.
+    pub fn table(&self) -> &TableArgs {
+        match self {
+            DeviceListFilter::All { table }
+            | DeviceListFilter::Hostname { table, .. }
+            | DeviceListFilter::Ip { table, .. }
+            | DeviceListFilter::Wlc { table, .. } => table,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Subcommand)]
 pub enum DeviceDetailFilter {
     /// Show device detail by hostname
     Hostname {
@@ -61,7 +120,7 @@ pub enum DeviceDetailFilter {
     },
 }
 
-#[derive(Debug, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 pub enum DeviceEnrichmentFilter {
     /// Enrichment by MAC address
     Mac {
@@ -0,0 +1,14 @@
+// src/commands/show/discovery.rs
+
+use clap::Subcommand;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum DiscoveryCommands {
+    /// List discovery jobs
+    List,
+    /// Show a discovery job and the devices it found
+    Detail {
+        /// Discovery id or name
+        discovery: String,
+    },
+}
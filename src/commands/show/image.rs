@@ -0,0 +1,28 @@
+// src/commands/show/image.rs
+
+use crate::commands::show::cache::CacheArgs;
+use clap::Subcommand;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ImageCommands {
+    /// List the images tagged golden, by device family
+    Golden,
+    /// Show whether matching devices are ready to upgrade to their golden image
+    Readiness {
+        /// Hostname or hostname pattern such as 'edge-*'; all devices by default
+        device: Option<String>,
+        /// Only devices at this site (and its child sites)
+        #[arg(long)]
+        site: Option<String>,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Group devices by platform and version against each golden image
+    Summary {
+        /// Only devices at this site (and its child sites)
+        #[arg(long)]
+        site: Option<String>,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+}
@@ -0,0 +1,56 @@
+// src/commands/show/interface.rs
+
+use crate::commands::show::cache::CacheArgs;
+use crate::commands::show::table::TableArgs;
+use clap::{Args, Subcommand, ValueEnum};
+
+/// `show interface <device>` lists a device's interfaces; with `--site` and no
+/// device it searches every switch at the site.
+#[derive(Debug, Clone, Args)]
+pub struct InterfaceArgs {
+    #[command(subcommand)]
+    pub subcommand: Option<InterfaceCommands>,
+    /// Device hostname, management IP or serial number
+    #[arg(required_unless_present = "site")]
+    pub device: Option<String>,
+    /// List interfaces of every device at this site (and its child sites)
+    #[arg(long)]
+    pub site: Option<String>,
+    /// Only show interfaces with this operational status
+    #[arg(long, value_enum)]
+    pub status: Option<PortStatus>,
+    /// Only show interfaces in this VLAN
+    #[arg(long)]
+    pub vlan: Option<String>,
+    /// Only show interfaces in this port mode
+    #[arg(long, value_enum)]
+    pub mode: Option<PortMode>,
+    #[command(flatten)]
+    pub table: TableArgs,
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum InterfaceCommands {
+    /// Show everything known about one interface, including errors and PoE
+    Detail {
+        /// Device hostname, management IP or serial number
+        device: String,
+        /// Interface name (e.g. GigabitEthernet1/0/1)
+        ifname: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PortStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PortMode {
+    Access,
+    Trunk,
+    Routed,
+}
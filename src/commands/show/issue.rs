@@ -2,8 +2,9 @@
 
 #[allow(unused_imports)]
 use clap::{Parser, Subcommand, ValueEnum};
+use crate::commands::show::table::TableArgs;
 
-#[derive(Debug, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 #[allow(unused_imports)]
 pub enum IssueCommands {
     /// List issues based on search criteria
@@ -13,6 +14,13 @@ pub enum IssueCommands {
         search_option: Option<SearchOption>,
         /// Search input corresponding to the search option
         search_input: Option<String>,
+        #[command(flatten)]
+        table: TableArgs,
+    },
+    /// Show one issue by its ID
+    Detail {
+        /// Issue ID from `show issue list`
+        issue_id: String,
     },
 }
 
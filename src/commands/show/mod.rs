@@ -2,10 +2,22 @@ pub mod device;
 pub mod client;
 pub mod issue;
 pub mod ap;
+pub mod table;
+pub mod cache;
+pub mod topology;
+pub mod interface;
+pub mod compliance;
+pub mod image;
+pub mod template;
+pub mod pnp;
+pub mod discovery;
+pub mod tag;
+pub mod ssid;
+pub mod wlc;
 
 use clap::Subcommand;
 
-#[derive(Debug, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 pub enum ShowCommands {
     /// Show device information
     Device {
@@ -27,5 +39,56 @@ pub enum ShowCommands {
         #[command(subcommand)]
         subcommand: ap::ApCommands,
     },
+    /// Show device interfaces and their status
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+    Interface {
+        #[command(flatten)]
+        args: interface::InterfaceArgs,
+    },
+    /// Show physical, layer 2 or layer 3 topology
+    Topology {
+        #[command(subcommand)]
+        subcommand: topology::TopologyCommands,
+    },
+    /// Show compliance status across devices, or for one device
+    #[command(args_conflicts_with_subcommands = true)]
+    Compliance {
+        #[command(flatten)]
+        args: compliance::ComplianceArgs,
+    },
+    /// Show golden images and upgrade readiness
+    Image {
+        #[command(subcommand)]
+        subcommand: image::ImageCommands,
+    },
+    /// Show configuration templates
+    Template {
+        #[command(subcommand)]
+        subcommand: template::TemplateCommands,
+    },
+    /// Show devices in the Plug and Play queue
+    Pnp {
+        #[command(subcommand)]
+        subcommand: pnp::PnpCommands,
+    },
+    /// Show discovery jobs and the devices they found
+    Discovery {
+        #[command(subcommand)]
+        subcommand: discovery::DiscoveryCommands,
+    },
+    /// Show device and interface tags
+    Tag {
+        #[command(subcommand)]
+        subcommand: tag::TagCommands,
+    },
+    /// Show wireless SSIDs
+    Ssid {
+        #[command(subcommand)]
+        subcommand: ssid::SsidCommands,
+    },
+    /// Show wireless LAN controllers and their access points
+    Wlc {
+        #[command(subcommand)]
+        subcommand: wlc::WlcCommands,
+    },
 }
-
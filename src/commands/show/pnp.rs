@@ -0,0 +1,35 @@
+// src/commands/show/pnp.rs
+
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum PnpCommands {
+    /// List devices in the Plug and Play queue
+    Devices {
+        /// Only devices in this onboarding state
+        #[arg(long, value_enum, ignore_case = true)]
+        state: Option<PnpState>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum PnpState {
+    Unclaimed,
+    Planned,
+    Onboarding,
+    Provisioned,
+    Error,
+}
+
+impl PnpState {
+    /// Name used by the PnP APIs, e.g. `Unclaimed`.
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            PnpState::Unclaimed => "Unclaimed",
+            PnpState::Planned => "Planned",
+            PnpState::Onboarding => "Onboarding",
+            PnpState::Provisioned => "Provisioned",
+            PnpState::Error => "Error",
+        }
+    }
+}
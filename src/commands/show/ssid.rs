@@ -0,0 +1,18 @@
+// src/commands/show/ssid.rs
+
+use clap::Subcommand;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SsidCommands {
+    /// List SSIDs with their security and the profiles carrying them
+    List {
+        /// Only SSIDs broadcast at this site (or its parent or child sites)
+        #[arg(long)]
+        site: Option<String>,
+    },
+    /// Show an SSID's settings and where it is broadcast
+    Detail {
+        /// SSID name
+        name: String,
+    },
+}
@@ -0,0 +1,24 @@
+// src/commands/show/table.rs
+
+use clap::Args;
+
+/// Options controlling which fields a table shows and in what order.
+#[derive(Debug, Clone, Default, Args)]
+pub struct TableArgs {
+    /// Comma-separated columns to display (e.g. hostname,ip,uptime,reachability)
+    #[arg(long, value_delimiter = ',')]
+    pub columns: Option<Vec<String>>,
+    /// Sort rows by a field, optionally descending (e.g. hostname or uptime_seconds:desc)
+    #[arg(long)]
+    pub sort_by: Option<String>,
+    /// Show every known field
+    #[arg(long)]
+    pub wide: bool,
+}
+
+impl TableArgs {
+    /// True when no option was given and the default layout should be used.
+    pub fn is_default(&self) -> bool {
+        self.columns.is_none() && self.sort_by.is_none() && !self.wide
+    }
+}
@@ -0,0 +1,9 @@
+// src/commands/show/tag.rs
+
+use clap::Subcommand;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum TagCommands {
+    /// List tags with how many devices and interfaces carry each
+    List,
+}
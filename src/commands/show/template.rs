@@ -0,0 +1,18 @@
+// src/commands/show/template.rs
+
+use clap::Subcommand;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum TemplateCommands {
+    /// List templates by project
+    List {
+        /// Only templates in this project
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Show a template's parameters and content
+    Detail {
+        /// Template name, optionally as <project>/<name>
+        name: String,
+    },
+}
@@ -0,0 +1,54 @@
+// src/commands/show/topology.rs
+
+use clap::{Args, Subcommand, ValueEnum};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum TopologyCommands {
+    /// Physical topology learned through CDP/LLDP
+    Physical {
+        #[command(flatten)]
+        view: TopologyArgs,
+    },
+    /// Layer 2 topology of a VLAN
+    L2 {
+        /// VLAN number or name (e.g. 100 or Vlan100)
+        vlan: String,
+        #[command(flatten)]
+        view: TopologyArgs,
+    },
+    /// Layer 3 topology of a routing protocol
+    L3 {
+        #[arg(value_enum)]
+        protocol: L3Protocol,
+        #[command(flatten)]
+        view: TopologyArgs,
+    },
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct TopologyArgs {
+    /// Only include devices in this site (and its child sites)
+    #[arg(long)]
+    pub site: Option<String>,
+    /// Output format
+    #[arg(long, short, value_enum, default_value = "ascii")]
+    pub format: TopologyFormat,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum L3Protocol {
+    Ospf,
+    Eigrp,
+    Isis,
+    Static,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum TopologyFormat {
+    /// Adjacency listing per device
+    Ascii,
+    /// Graphviz DOT
+    Dot,
+    /// Raw nodes and links as JSON
+    Json,
+}
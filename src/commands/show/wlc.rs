@@ -0,0 +1,23 @@
+// src/commands/show/wlc.rs
+
+use crate::commands::show::cache::CacheArgs;
+use clap::Subcommand;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum WlcCommands {
+    /// List wireless controllers with their APs, clients, HA state and sites
+    List {
+        /// Only controllers with APs at this site (and its child sites)
+        #[arg(long)]
+        site: Option<String>,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Show one controller and the APs joined to it
+    Detail {
+        /// Controller hostname, IP address or serial number
+        name: String,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+}
@@ -0,0 +1,57 @@
+// src/commands/tag.rs
+
+use crate::commands::watch;
+use clap::{Args, Subcommand};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum TagCommands {
+    /// Create a static tag
+    Create {
+        /// Tag name
+        name: String,
+        /// Tag description
+        #[arg(long)]
+        description: Option<String>,
+    },
+    /// Tag matching devices, or interfaces of one device
+    Add {
+        /// Tag name
+        tag: String,
+        #[command(flatten)]
+        members: TagMemberArgs,
+    },
+    /// Untag matching devices, or interfaces of one device
+    Remove {
+        /// Tag name
+        tag: String,
+        #[command(flatten)]
+        members: TagMemberArgs,
+    },
+}
+
+/// Which devices or interfaces a tag change applies to.
+#[derive(Debug, Clone, Args)]
+pub struct TagMemberArgs {
+    /// Hostname or hostname pattern such as 'edge-*'
+    #[arg(long, required_unless_present_any = ["site", "interfaces"], conflicts_with = "interfaces")]
+    pub devices: Option<String>,
+    /// Only devices at this site (and its child sites)
+    #[arg(long, conflicts_with = "interfaces")]
+    pub site: Option<String>,
+    /// Interfaces to tag (comma-separated), e.g. Gi1/0/1,Gi1/0/2
+    #[arg(long, value_delimiter = ',', requires = "device")]
+    pub interfaces: Vec<String>,
+    /// Device whose interfaces are tagged
+    #[arg(long, requires = "interfaces")]
+    pub device: Option<String>,
+    /// Show what would change without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Do not ask for confirmation
+    #[arg(long, short)]
+    pub yes: bool,
+    /// How long to track the tasks (e.g. 5m)
+    #[arg(long, default_value = "5m", value_parser = watch::parse_interval)]
+    pub timeout: Duration,
+}
@@ -0,0 +1,47 @@
+// src/commands/template.rs
+
+use crate::commands::watch;
+use clap::Subcommand;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum TemplateCommands {
+    /// Render a template locally with variables from a YAML file
+    Render {
+        /// Template name, optionally as <project>/<name>
+        name: String,
+        /// YAML file of template variables
+        #[arg(long)]
+        vars: Option<PathBuf>,
+        /// Apply this device's overrides from the vars file
+        #[arg(long)]
+        device: Option<String>,
+        /// Have Catalyst Center render it instead, evaluating loops and conditionals
+        #[arg(long)]
+        remote: bool,
+    },
+    /// Deploy a template to matching devices and track each deployment
+    Deploy {
+        /// Template name, optionally as <project>/<name>
+        name: String,
+        /// Hostname or hostname pattern such as 'edge-*'
+        #[arg(long, required_unless_present = "site")]
+        devices: Option<String>,
+        /// Only devices at this site (and its child sites)
+        #[arg(long)]
+        site: Option<String>,
+        /// YAML file of template variables
+        #[arg(long)]
+        vars: Option<PathBuf>,
+        /// Show what would be deployed without starting anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Do not ask for confirmation
+        #[arg(long, short)]
+        yes: bool,
+        /// How long to track the deployments (e.g. 10m)
+        #[arg(long, default_value = "15m", value_parser = watch::parse_interval)]
+        timeout: Duration,
+    },
+}
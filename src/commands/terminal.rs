@@ -0,0 +1,42 @@
+// src/commands/terminal.rs
+
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum TerminalCommands {
+    /// Set the number of lines per page for this session (0 disables paging)
+    Length {
+        /// Lines per page, or "auto" to follow the terminal height
+        #[arg(value_parser = parse_length)]
+        lines: TerminalLength,
+    },
+    /// Enable or disable the pager for this session
+    Pager {
+        #[command(subcommand)]
+        action: PagerAction,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum TerminalLength {
+    Auto,
+    Lines(u16),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PagerAction {
+    /// Page output that does not fit on one screen
+    Enable,
+    /// Print all output directly
+    Disable,
+}
+
+fn parse_length(input: &str) -> Result<TerminalLength, String> {
+    if input.eq_ignore_ascii_case("auto") {
+        return Ok(TerminalLength::Auto);
+    }
+    input
+        .parse()
+        .map(TerminalLength::Lines)
+        .map_err(|_| format!("invalid length '{}', expected a number or 'auto'", input))
+}
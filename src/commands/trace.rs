@@ -0,0 +1,32 @@
+// src/commands/trace.rs
+
+use crate::commands::watch;
+use clap::{Args, ValueEnum};
+use std::net::IpAddr;
+use std::time::Duration;
+
+#[derive(Debug, Args)]
+pub struct TraceArgs {
+    /// Source IP address
+    pub source: IpAddr,
+    /// Destination IP address
+    pub destination: IpAddr,
+    /// Protocol of the flow
+    #[arg(long, value_enum)]
+    pub protocol: Option<TraceProtocol>,
+    /// Source port of the flow
+    #[arg(long, requires = "protocol")]
+    pub src_port: Option<u16>,
+    /// Destination port of the flow
+    #[arg(long, requires = "protocol")]
+    pub dst_port: Option<u16>,
+    /// How long to wait for the trace to complete (e.g. 90s, 5m)
+    #[arg(long, default_value = "2m", value_parser = watch::parse_interval)]
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum TraceProtocol {
+    Tcp,
+    Udp,
+}
@@ -0,0 +1,60 @@
+// src/commands/watch.rs
+
+use crate::commands::show::ShowCommands;
+use clap::Subcommand;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum WatchCommands {
+    /// Show command to re-run
+    Show {
+        #[command(subcommand)]
+        subcommand: ShowCommands,
+    },
+}
+
+/// Parse an interval such as `30`, `30s`, `5m` or `1h`.
+pub fn parse_interval(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let (number, multiplier) = match input.char_indices().last() {
+        Some((idx, 's')) => (&input[..idx], 1),
+        Some((idx, 'm')) => (&input[..idx], 60),
+        Some((idx, 'h')) => (&input[..idx], 60 * 60),
+        _ => (input, 1),
+    };
+
+    let seconds: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid interval '{}', expected e.g. 30s, 5m or 1h", input))?;
+
+    if seconds == 0 {
+        return Err("interval must be at least 1s".to_string());
+    }
+
+    Ok(Duration::from_secs(seconds * multiplier))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_units() {
+        assert_eq!(parse_interval("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_interval("5m"), Ok(Duration::from_secs(300)));
+        assert_eq!(parse_interval("1h"), Ok(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn bare_number_is_seconds() {
+        assert_eq!(parse_interval(" 45 "), Ok(Duration::from_secs(45)));
+    }
+
+    #[test]
+    fn rejects_zero_and_garbage() {
+        assert!(parse_interval("0s").is_err());
+        assert!(parse_interval("").is_err());
+        assert!(parse_interval("5x").is_err());
+        assert!(parse_interval("-5s").is_err());
+    }
+}
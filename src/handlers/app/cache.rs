@@ -0,0 +1,66 @@
+use log::error;
+use prettytable::{row, Table};
+use crate::app::cache::{self, InventoryCache};
+use crate::app::config;
+use crate::commands::app::cache::AppCacheCommands;
+use crate::helpers::{completion, output};
+
+pub fn handle_app_cache_command(subcommand: AppCacheCommands) {
+    match subcommand {
+        AppCacheCommands::Status => {
+            let status = match InventoryCache::open().and_then(|cache| cache.status()) {
+                Ok(status) => status,
+                Err(e) => {
+                    error!("Failed to read cache: {}", e);
+                    return;
+                }
+            };
+
+            if status.is_empty() {
+                outln!("The inventory cache is empty.");
+                return;
+            }
+
+            let mut table = Table::new();
+            table.add_row(row!["Data", "Entries", "Last Refreshed"]);
+            for entry in status {
+                table.add_row(row![entry.kind, entry.count, cache::format_age(entry.refreshed_at)]);
+            }
+            output::print_table(&table);
+            outln!("Cache database: {}", config::get_cache_db_path().display());
+        }
+        AppCacheCommands::Refresh => {
+            let config = match config::load_config() {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    error!("Failed to load configuration: {}", e);
+                    return;
+                }
+            };
+
+            let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+            runtime.block_on(async {
+                match cache::refresh_devices(&config).await {
+                    Ok(devices) => {
+                        completion::record_devices(&devices);
+                        println!("Cached {} devices.", devices.len());
+                    }
+                    Err(e) => error!("Failed to refresh devices: {}", e),
+                }
+                match cache::refresh_sites(&config).await {
+                    Ok(sites) => {
+                        completion::record_sites(&sites);
+                        println!("Cached {} sites.", sites.len());
+                    }
+                    Err(e) => error!("Failed to refresh sites: {}", e),
+                }
+            });
+        }
+        AppCacheCommands::Clear => {
+            match InventoryCache::open().and_then(|cache| cache.clear()) {
+                Ok(()) => println!("Inventory cache cleared."),
+                Err(e) => error!("Failed to clear cache: {}", e),
+            }
+        }
+    }
+}
@@ -14,12 +14,14 @@ pub fn handle_app_config_command(subcommand: AppConfigCommands) {
         AppConfigCommands::Show => {
             match config::load_config() {
                 Ok(config) => {
-                    println!("Current Configuration:");
-                    println!("---------------------");
-                    println!("DNA Center URL: {}", config.dnac_url);
-                    println!("Username: {}", config.username);
-                    println!("Password: [hidden]");
-                    println!("Verify SSL: {}", config.verify_ssl);
+                    outln!("Current Configuration:");
+                    outln!("---------------------");
+                    outln!("DNA Center URL: {}", config.dnac_url);
+                    outln!("Username: {}", config.username);
+                    outln!("Password: [hidden]");
+                    outln!("Verify SSL: {}", config.verify_ssl);
+                    outln!("Captures Directory: {}", config::get_captures_dir().display());
+                    outln!("Cache TTL: {}s", config.cache_ttl_secs.unwrap_or(300));
                 }
                 Err(e) => {
                     error!("Failed to read configuration: {}", e);
@@ -42,5 +44,15 @@ pub fn handle_app_config_command(subcommand: AppConfigCommands) {
                 error!("Failed to reset credentials: {}", e);
             }
         }
+        AppConfigCommands::SetCacheTtl { seconds } => {
+            if let Err(e) = config::update_cache_ttl(seconds) {
+                error!("Failed to update cache TTL: {}", e);
+            }
+        }
+        AppConfigCommands::SetCapturesDir { path } => {
+            if let Err(e) = config::update_captures_dir(path) {
+                error!("Failed to update captures directory: {}", e);
+            }
+        }
     }
 }
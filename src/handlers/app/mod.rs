@@ -1,11 +1,13 @@
 pub mod config;
 pub mod update;
+pub mod cache;
 
 use crate::commands::app::AppCommands;
 
 pub fn handle_app_command(subcommand: AppCommands) {
     match subcommand {
         AppCommands::Config { subcommand } => config::handle_app_config_command(subcommand),
+        AppCommands::Cache { subcommand } => cache::handle_app_cache_command(subcommand),
         AppCommands::Update => update::handle_update_command(),
     }
 }
@@ -0,0 +1,94 @@
+// src/handlers/compliance.rs
+
+use crate::api::authentication::auth;
+use crate::api::compliance::runcompliance;
+use crate::api::devices::getdevicehealth;
+use crate::api::tasks::gettask;
+use crate::app::cache::{self, CacheMode};
+use crate::app::config;
+use crate::commands::compliance::ComplianceCommands;
+use crate::helpers::{completion, devicefilter, interrupt};
+use log::error;
+
+pub fn handle_compliance_command(subcommand: ComplianceCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            ComplianceCommands::Run { device, site, compliance_types, timeout } => {
+                let devices = match cache::get_devices(&config, CacheMode::Default).await {
+                    Ok(devices) => devices,
+                    Err(e) => {
+                        error!("Failed to fetch devices: {}", e);
+                        return;
+                    }
+                };
+                completion::record_devices(&devices);
+
+                let token = match auth::authenticate(&config).await {
+                    Ok(t) => t,
+                    Err(e) => {
+                        error!("Authentication failed: {}", e);
+                        return;
+                    }
+                };
+
+                let pattern = device.as_deref().unwrap_or("*");
+                let mut selected = devicefilter::select_devices(&devices, pattern);
+                if let Some(site) = &site {
+                    match getdevicehealth::get_device_sites(&config, &token).await {
+                        Ok(sites) => devicefilter::retain_site(&mut selected, &sites, site),
+                        Err(e) => {
+                            error!("Failed to retrieve device sites: {}", e);
+                            return;
+                        }
+                    }
+                }
+                if selected.is_empty() {
+                    println!("No devices match '{}'.", pattern);
+                    return;
+                }
+
+                let device_ids: Vec<String> = selected.iter().filter_map(|device| device.id.clone()).collect();
+                let categories: Vec<String> = compliance_types
+                    .iter()
+                    .map(|compliance_type| compliance_type.api_name())
+                    .collect();
+
+                let task = match runcompliance::run_compliance(&config, &token, &device_ids, &categories).await {
+                    Ok(task) => task,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                println!(
+                    "Checking compliance of {} devices (task {}), Ctrl-C to stop waiting...",
+                    device_ids.len(),
+                    task.task_id
+                );
+
+                let wait = gettask::wait_for_task(&config, &token, &task.task_id, timeout);
+                let result = match interrupt::until_ctrl_c(wait).await {
+                    Some(result) => result,
+                    None => {
+                        println!("Stopped waiting. The check continues to run in Catalyst Center.");
+                        return;
+                    }
+                };
+
+                match result {
+                    Ok(_) => println!("Compliance check complete. Run 'show compliance' to see the results."),
+                    Err(e) => error!("Compliance check failed: {}", e),
+                }
+            }
+        }
+    });
+}
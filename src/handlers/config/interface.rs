@@ -0,0 +1,334 @@
+// src/handlers/config/interface.rs
+
+use crate::api::authentication::auth;
+use crate::api::interfaces::getinterfaces::{self, Interface};
+use crate::api::interfaces::updateinterface::{self, InterfaceUpdate};
+use crate::api::tasks::gettask;
+use crate::app::cache::{self, CacheMode};
+use crate::app::config::{self, Config};
+use crate::helpers::{devicefilter, output};
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
+use clap_repl::reedline::{DefaultPrompt, DefaultPromptSegment, FileBackedHistory};
+use clap_repl::{ClapEditor, ReadCommandOutput};
+use log::error;
+use prettytable::{row, Table};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+const TASK_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Parser)]
+#[command(name = "", about = "Interface Configuration Mode REPL")]
+struct InterfaceCli {
+    #[command(subcommand)]
+    command: InterfaceConfigCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum InterfaceConfigCommands {
+    /// Set the interface description
+    Description {
+        #[arg(required = true, trailing_var_arg = true)]
+        text: Vec<String>,
+    },
+    /// Administratively disable the interface
+    Shutdown,
+    /// Negate a setting
+    No {
+        #[command(subcommand)]
+        setting: NoCommands,
+    },
+    /// Set the access or voice VLAN
+    Switchport {
+        #[command(subcommand)]
+        setting: SwitchportCommands,
+    },
+    /// Show current and pending settings
+    Show,
+    /// Preview the CLI Catalyst Center would push for the pending changes
+    Preview,
+    /// Push the pending changes to the device
+    Commit,
+    /// Discard the pending changes
+    Abort,
+    /// Return to configuration mode
+    Exit,
+    /// Leave configuration mode
+    End,
+}
+
+#[derive(Debug, Subcommand)]
+enum NoCommands {
+    /// Administratively enable the interface
+    Shutdown,
+    /// Clear the interface description
+    Description,
+}
+
+#[derive(Debug, Subcommand)]
+enum SwitchportCommands {
+    /// Set the access VLAN
+    Access {
+        #[command(subcommand)]
+        vlan: VlanSetting,
+    },
+    /// Set the voice VLAN
+    Voice {
+        #[command(subcommand)]
+        vlan: VlanSetting,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum VlanSetting {
+    Vlan {
+        /// VLAN ID (1-4094)
+        #[arg(value_parser = clap::value_parser!(u16).range(1..=4094))]
+        id: u16,
+    },
+}
+
+/// How the interface context was left.
+pub enum InterfaceExit {
+    /// `exit`: back to configuration mode
+    Config,
+    /// `end`: leave configuration mode too
+    End,
+}
+
+struct InterfaceContext {
+    config: Config,
+    hostname: String,
+    interface: Interface,
+    pending: InterfaceUpdate,
+}
+
+/// Configure one interface, staging changes until `commit`.
+pub fn start_interface_repl(device: &str, ifname: &str) -> InterfaceExit {
+    let runtime = Runtime::new().expect("Failed to create Tokio runtime");
+    let mut context = match runtime.block_on(load_context(device, ifname)) {
+        Ok(context) => context,
+        Err(e) => {
+            error!("{}", e);
+            return InterfaceExit::Config;
+        }
+    };
+
+    let port = context
+        .interface
+        .port_name
+        .clone()
+        .unwrap_or_else(|| ifname.to_string());
+    println!(
+        "Configuring {} on {}. Changes are staged until 'commit'.",
+        port, context.hostname
+    );
+
+    let prompt = DefaultPrompt {
+        left_prompt: DefaultPromptSegment::Basic("catalysh(config-if)#".to_owned()),
+        ..DefaultPrompt::default()
+    };
+    let mut rl = ClapEditor::<InterfaceCli>::builder()
+        .with_prompt(Box::new(prompt))
+        .with_editor_hook(|reed| {
+            reed.with_history(Box::new(
+                FileBackedHistory::with_file(10000, "/tmp/catalysh-config-cli-history".into())
+                    .unwrap(),
+            ))
+        })
+        .build();
+
+    loop {
+        let command = match rl.read_command() {
+            ReadCommandOutput::Command(cli) => cli.command,
+            ReadCommandOutput::ClapError(e) => {
+                let _ = e.print();
+                continue;
+            }
+            ReadCommandOutput::ShlexError => {
+                println!("Error: input was not valid and could not be processed");
+                continue;
+            }
+            ReadCommandOutput::EmptyLine | ReadCommandOutput::CtrlC => continue,
+            ReadCommandOutput::CtrlD => return leave(&context, InterfaceExit::Config),
+            ReadCommandOutput::ReedlineError(e) => {
+                error!("Failed to read input: {}", e);
+                return leave(&context, InterfaceExit::Config);
+            }
+        };
+
+        match command {
+            InterfaceConfigCommands::Description { text } => {
+                context.pending.description = Some(text.join(" "))
+            }
+            InterfaceConfigCommands::Shutdown => {
+                context.pending.admin_status = Some("DOWN".to_string())
+            }
+            InterfaceConfigCommands::No { setting } => match setting {
+                NoCommands::Shutdown => context.pending.admin_status = Some("UP".to_string()),
+                NoCommands::Description => context.pending.description = Some(String::new()),
+            },
+            InterfaceConfigCommands::Switchport { setting } => match setting {
+                SwitchportCommands::Access {
+                    vlan: VlanSetting::Vlan { id },
+                } => context.pending.vlan_id = Some(id),
+                SwitchportCommands::Voice {
+                    vlan: VlanSetting::Vlan { id },
+                } => context.pending.voice_vlan_id = Some(id),
+            },
+            InterfaceConfigCommands::Show => print_settings(&context),
+            InterfaceConfigCommands::Preview => {
+                if let Err(e) = runtime.block_on(preview(&context)) {
+                    error!("Preview failed: {}", e);
+                }
+            }
+            InterfaceConfigCommands::Commit => match runtime.block_on(commit(&context)) {
+                Ok(()) => {
+                    apply_pending(&mut context);
+                    println!("Changes applied to {} on {}.", port, context.hostname);
+                }
+                Err(e) => error!("Commit failed: {}", e),
+            },
+            InterfaceConfigCommands::Abort => {
+                context.pending = InterfaceUpdate::default();
+                println!("Pending changes discarded.");
+            }
+            InterfaceConfigCommands::Exit => return leave(&context, InterfaceExit::Config),
+            InterfaceConfigCommands::End => return leave(&context, InterfaceExit::End),
+        }
+    }
+}
+
+fn leave(context: &InterfaceContext, exit: InterfaceExit) -> InterfaceExit {
+    if !context.pending.is_empty() {
+        println!("Uncommitted changes were discarded.");
+    }
+    exit
+}
+
+async fn load_context(device: &str, ifname: &str) -> Result<InterfaceContext> {
+    let config = config::load_config()?;
+    let devices = cache::get_devices(&config, CacheMode::Default).await?;
+    let device = devicefilter::find_device(&devices, device)?;
+    let device_id = device
+        .id
+        .clone()
+        .ok_or_else(|| anyhow!("Device has no id"))?;
+
+    let token = auth::authenticate(&config).await?;
+    let interface = getinterfaces::get_device_interfaces(&config, &token, &device_id)
+        .await?
+        .into_iter()
+        .find(|interface| {
+            interface
+                .port_name
+                .as_deref()
+                .is_some_and(|port| devicefilter::interface_matches(port, ifname))
+        })
+        .ok_or_else(|| anyhow!("No interface {} found on {}", ifname, device_id))?;
+
+    Ok(InterfaceContext {
+        hostname: device.hostname.clone().unwrap_or(device_id),
+        config,
+        interface,
+        pending: InterfaceUpdate::default(),
+    })
+}
+
+fn print_settings(context: &InterfaceContext) {
+    let current = &context.interface;
+    let pending = &context.pending;
+    let show = |value: Option<String>| value.unwrap_or_default();
+
+    let mut table = Table::new();
+    table.add_row(row!["Setting", "Current", "Pending"]);
+    table.add_row(row![
+        "Description",
+        show(current.description.clone()),
+        show(pending.description.clone())
+    ]);
+    table.add_row(row![
+        "Admin Status",
+        show(current.admin_status.clone()),
+        show(pending.admin_status.clone())
+    ]);
+    table.add_row(row![
+        "Access VLAN",
+        show(current.vlan_id.clone()),
+        show(pending.vlan_id.map(|id| id.to_string()))
+    ]);
+    table.add_row(row![
+        "Voice VLAN",
+        show(current.voice_vlan.clone()),
+        show(pending.voice_vlan_id.map(|id| id.to_string()))
+    ]);
+    output::print_table(&table);
+}
+
+fn interface_id(context: &InterfaceContext) -> Result<&str> {
+    context
+        .interface
+        .id
+        .as_deref()
+        .ok_or_else(|| anyhow!("Interface has no id"))
+}
+
+async fn preview(context: &InterfaceContext) -> Result<()> {
+    if context.pending.is_empty() {
+        println!("No pending changes.");
+        return Ok(());
+    }
+
+    let token = auth::authenticate(&context.config).await?;
+    let task = updateinterface::update_interface(
+        &context.config,
+        &token,
+        interface_id(context)?,
+        &context.pending,
+        true,
+    )
+    .await?;
+    let task = gettask::wait_for_task(&context.config, &token, &task.task_id, TASK_TIMEOUT).await?;
+
+    println!("Configuration to be pushed:");
+    println!("{}", task.data.or(task.progress).unwrap_or_default());
+    Ok(())
+}
+
+async fn commit(context: &InterfaceContext) -> Result<()> {
+    if context.pending.is_empty() {
+        return Err(anyhow!("No pending changes"));
+    }
+
+    let token = auth::authenticate(&context.config).await?;
+    let task = updateinterface::update_interface(
+        &context.config,
+        &token,
+        interface_id(context)?,
+        &context.pending,
+        false,
+    )
+    .await?;
+    println!("Deploying (task {})...", task.task_id);
+    gettask::wait_for_task(&context.config, &token, &task.task_id, TASK_TIMEOUT).await?;
+    Ok(())
+}
+
+/// Record committed changes as the interface's current settings.
+fn apply_pending(context: &mut InterfaceContext) {
+    let pending = std::mem::take(&mut context.pending);
+    let interface = &mut context.interface;
+    if let Some(description) = pending.description {
+        interface.description = Some(description);
+    }
+    if let Some(admin_status) = pending.admin_status {
+        interface.admin_status = Some(admin_status);
+    }
+    if let Some(vlan) = pending.vlan_id {
+        interface.vlan_id = Some(vlan.to_string());
+    }
+    if let Some(vlan) = pending.voice_vlan_id {
+        interface.voice_vlan = Some(vlan.to_string());
+    }
+}
@@ -1,4 +1,6 @@
+pub mod interface;
 pub mod repl;
+pub mod ssid;
 
 pub fn handle_config_command() {
     repl::start_config_repl();
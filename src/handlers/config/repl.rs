@@ -1,3 +1,5 @@
+use super::interface::{self, InterfaceExit};
+use super::ssid::{self, SsidExit};
 use clap::{Parser, Subcommand};
 use clap_repl::reedline::{DefaultPrompt, DefaultPromptSegment, FileBackedHistory};
 use clap_repl::ClapEditor;
@@ -17,6 +19,18 @@ enum ConfigCommands {
     Dummy,
     /// Placeholder for future ap config commands
     Ap,
+    /// Configure an interface on a device
+    Interface {
+        /// Device hostname, IP address or serial number
+        device: String,
+        /// Interface name, e.g. Gi1/0/1
+        ifname: String,
+    },
+    /// Configure a wireless SSID
+    Ssid {
+        /// SSID name
+        name: String,
+    },
     /// Exit configuration mode
     Exit,
     /// End configuration mode
@@ -29,7 +43,7 @@ struct ExitRepl;
 pub fn start_config_repl() {
     println!("Entering configuration mode. Type 'exit' or 'end' to leave.");
     let prompt = DefaultPrompt {
-        left_prompt: DefaultPromptSegment::Basic("catlysh(config)#".to_owned()),
+        left_prompt: DefaultPromptSegment::Basic("catalysh(config)#".to_owned()),
         ..DefaultPrompt::default()
     };
 
@@ -63,6 +77,16 @@ pub fn start_config_repl() {
             ConfigCommands::Ap => {
                 println!("ap command executed");
             }
+            ConfigCommands::Interface { device, ifname } => {
+                if let InterfaceExit::End = interface::start_interface_repl(&device, &ifname) {
+                    panic_any(ExitRepl);
+                }
+            }
+            ConfigCommands::Ssid { name } => {
+                if let SsidExit::End = ssid::start_ssid_repl(&name) {
+                    panic_any(ExitRepl);
+                }
+            }
             ConfigCommands::Exit | ConfigCommands::End => {
                 panic_any(ExitRepl);
             }
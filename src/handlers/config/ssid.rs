@@ -0,0 +1,556 @@
+// src/handlers/config/ssid.rs
+
+use crate::api::authentication::auth;
+use crate::api::devices::getdevicehealth;
+use crate::api::tasks::getexecution;
+use crate::api::wireless::getssids::{self, SsidDetails, BAND_SELECT, DUAL_BAND};
+use crate::api::wireless::getwirelessprofiles::{self, FlexConnect, ProfileDetails};
+use crate::api::wireless::{provisionwlc, updatessid, updatewirelessprofile};
+use crate::app::cache::{self, CacheMode};
+use crate::app::config::{self, Config};
+use crate::handlers::show::ssid::yes_no;
+use crate::helpers::tasktracker::{self, TrackedTask};
+use crate::helpers::{output, utils, wireless};
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use clap_repl::reedline::{DefaultPrompt, DefaultPromptSegment, FileBackedHistory};
+use clap_repl::{ClapEditor, ReadCommandOutput};
+use log::error;
+use prettytable::{row, Table};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+const EXECUTION_TIMEOUT: Duration = Duration::from_secs(120);
+const PROVISION_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Parser)]
+#[command(name = "", about = "SSID Configuration Mode REPL")]
+struct SsidCli {
+    #[command(subcommand)]
+    command: SsidConfigCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum SsidConfigCommands {
+    /// Set the security type
+    Security {
+        #[arg(value_enum, ignore_case = true)]
+        security: SecurityType,
+    },
+    /// Set the pre-shared key for personal security types (prompted, never echoed)
+    Passphrase,
+    /// Centrally switch the SSID on this WLC interface in every profile carrying it
+    Interface { name: String },
+    /// Locally switch the SSID to this VLAN at FlexConnect APs
+    Vlan {
+        /// VLAN ID (1-4094)
+        #[arg(value_parser = clap::value_parser!(u16).range(1..=4094))]
+        id: u16,
+    },
+    /// Steer dual-band clients to 5 GHz
+    BandSelect,
+    /// Set 802.11r fast transition
+    FastTransition {
+        #[arg(value_enum, ignore_case = true)]
+        mode: FastTransitionMode,
+    },
+    /// Broadcast the SSID in beacons
+    Broadcast,
+    /// Negate a setting
+    No {
+        #[command(subcommand)]
+        setting: NoCommands,
+    },
+    /// Show current and pending settings
+    Show,
+    /// Save the pending changes to the wireless design
+    Commit,
+    /// Push the wireless design to the controllers serving this SSID
+    Provision,
+    /// Discard the pending changes
+    Abort,
+    /// Return to configuration mode
+    Exit,
+    /// Leave configuration mode
+    End,
+}
+
+#[derive(Debug, Subcommand)]
+enum NoCommands {
+    /// Stop FlexConnect local switching
+    Vlan,
+    /// Stop steering clients to 5 GHz
+    BandSelect,
+    /// Disable 802.11r fast transition
+    FastTransition,
+    /// Hide the SSID from beacons
+    Broadcast,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum SecurityType {
+    Open,
+    Wpa2Personal,
+    Wpa2Enterprise,
+    Wpa3Personal,
+    Wpa3Enterprise,
+    Wpa2Wpa3Personal,
+    Wpa2Wpa3Enterprise,
+}
+
+impl SecurityType {
+    /// Name used by the SSID APIs, e.g. `WPA2_ENTERPRISE`.
+    fn api_name(&self) -> String {
+        self.to_possible_value()
+            .map(|value| value.get_name().replace('-', "_").to_uppercase())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum FastTransitionMode {
+    Adaptive,
+    Enable,
+    Disable,
+}
+
+impl FastTransitionMode {
+    /// Name used by the SSID APIs, e.g. `Adaptive`.
+    fn api_name(&self) -> &'static str {
+        match self {
+            FastTransitionMode::Adaptive => "Adaptive",
+            FastTransitionMode::Enable => "Enable",
+            FastTransitionMode::Disable => "Disable",
+        }
+    }
+}
+
+/// Staged changes. SSID settings and per-profile switching are saved by
+/// different APIs, so they are kept apart.
+#[derive(Debug, Default, PartialEq)]
+struct SsidChanges {
+    security: Option<String>,
+    passphrase: Option<String>,
+    band_select: Option<bool>,
+    fast_transition: Option<String>,
+    broadcast: Option<bool>,
+    interface: Option<String>,
+    /// `Some(None)` turns FlexConnect local switching off
+    vlan: Option<Option<u16>>,
+}
+
+impl SsidChanges {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn changes_ssid(&self) -> bool {
+        self.security.is_some()
+            || self.passphrase.is_some()
+            || self.band_select.is_some()
+            || self.fast_transition.is_some()
+            || self.broadcast.is_some()
+    }
+
+    fn changes_profiles(&self) -> bool {
+        self.interface.is_some() || self.vlan.is_some()
+    }
+}
+
+/// How the SSID context was left.
+pub enum SsidExit {
+    /// `exit`: back to configuration mode
+    Config,
+    /// `end`: leave configuration mode too
+    End,
+}
+
+struct SsidContext {
+    config: Config,
+    ssid: SsidDetails,
+    /// Wireless profiles carrying the SSID
+    profiles: Vec<ProfileDetails>,
+    pending: SsidChanges,
+}
+
+impl SsidContext {
+    fn name(&self) -> &str {
+        self.ssid.name.as_deref().unwrap_or_default()
+    }
+}
+
+/// Edit one SSID, staging changes until `commit`.
+pub fn start_ssid_repl(name: &str) -> SsidExit {
+    let runtime = Runtime::new().expect("Failed to create Tokio runtime");
+    let mut context = match runtime.block_on(load_context(name)) {
+        Ok(context) => context,
+        Err(e) => {
+            error!("{}", e);
+            return SsidExit::Config;
+        }
+    };
+
+    println!(
+        "Configuring SSID {} ({} wireless profiles). Changes are staged until 'commit'.",
+        context.name(),
+        context.profiles.len()
+    );
+
+    let prompt = DefaultPrompt {
+        left_prompt: DefaultPromptSegment::Basic("catalysh(config-ssid)#".to_owned()),
+        ..DefaultPrompt::default()
+    };
+    let mut rl = ClapEditor::<SsidCli>::builder()
+        .with_prompt(Box::new(prompt))
+        .with_editor_hook(|reed| {
+            reed.with_history(Box::new(
+                FileBackedHistory::with_file(10000, "/tmp/catalysh-config-cli-history".into())
+                    .unwrap(),
+            ))
+        })
+        .build();
+
+    loop {
+        let command = match rl.read_command() {
+            ReadCommandOutput::Command(cli) => cli.command,
+            ReadCommandOutput::ClapError(e) => {
+                let _ = e.print();
+                continue;
+            }
+            ReadCommandOutput::ShlexError => {
+                println!("Error: input was not valid and could not be processed");
+                continue;
+            }
+            ReadCommandOutput::EmptyLine | ReadCommandOutput::CtrlC => continue,
+            ReadCommandOutput::CtrlD => return leave(&context, SsidExit::Config),
+            ReadCommandOutput::ReedlineError(e) => {
+                error!("Failed to read input: {}", e);
+                return leave(&context, SsidExit::Config);
+            }
+        };
+
+        let pending = &mut context.pending;
+        match command {
+            SsidConfigCommands::Security { security } => pending.security = Some(security.api_name()),
+            SsidConfigCommands::Passphrase => match read_passphrase() {
+                Ok(passphrase) => pending.passphrase = Some(passphrase),
+                Err(e) => error!("{}", e),
+            },
+            SsidConfigCommands::Interface { name } => pending.interface = Some(name),
+            SsidConfigCommands::Vlan { id } => pending.vlan = Some(Some(id)),
+            SsidConfigCommands::BandSelect => pending.band_select = Some(true),
+            SsidConfigCommands::FastTransition { mode } => {
+                pending.fast_transition = Some(mode.api_name().to_string())
+            }
+            SsidConfigCommands::Broadcast => pending.broadcast = Some(true),
+            SsidConfigCommands::No { setting } => match setting {
+                NoCommands::Vlan => pending.vlan = Some(None),
+                NoCommands::BandSelect => pending.band_select = Some(false),
+                NoCommands::FastTransition => {
+                    pending.fast_transition = Some(FastTransitionMode::Disable.api_name().to_string())
+                }
+                NoCommands::Broadcast => pending.broadcast = Some(false),
+            },
+            SsidConfigCommands::Show => print_settings(&context),
+            SsidConfigCommands::Commit => match runtime.block_on(commit(&mut context)) {
+                Ok(()) => println!(
+                    "Changes saved to the wireless design. Run 'provision' to push them to the controllers."
+                ),
+                Err(e) => error!("Commit failed: {}", e),
+            },
+            SsidConfigCommands::Provision => {
+                if !context.pending.is_empty() {
+                    println!("Pending changes are not committed and will not be provisioned.");
+                }
+                if let Err(e) = runtime.block_on(provision(&context)) {
+                    error!("Provisioning failed: {}", e);
+                }
+            }
+            SsidConfigCommands::Abort => {
+                context.pending = SsidChanges::default();
+                println!("Pending changes discarded.");
+            }
+            SsidConfigCommands::Exit => return leave(&context, SsidExit::Config),
+            SsidConfigCommands::End => return leave(&context, SsidExit::End),
+        }
+    }
+}
+
+/// Prompt for the pre-shared key without echo, so it never reaches the history file.
+fn read_passphrase() -> Result<String> {
+    let passphrase = rpassword::prompt_password("Passphrase: ")?;
+    if !(8..=63).contains(&passphrase.len()) {
+        return Err(anyhow!("Passphrase must be 8 to 63 characters"));
+    }
+    if rpassword::prompt_password("Confirm passphrase: ")? != passphrase {
+        return Err(anyhow!("Passphrases do not match"));
+    }
+    Ok(passphrase)
+}
+
+fn leave(context: &SsidContext, exit: SsidExit) -> SsidExit {
+    if !context.pending.is_empty() {
+        println!("Uncommitted changes were discarded.");
+    }
+    exit
+}
+
+async fn load_context(name: &str) -> Result<SsidContext> {
+    let config = config::load_config()?;
+    let token = auth::authenticate(&config).await?;
+    let (ssids, profiles) = tokio::try_join!(
+        getssids::get_enterprise_ssids(&config, &token),
+        getwirelessprofiles::get_wireless_profiles(&config, &token)
+    )?;
+
+    let ssid = wireless::find_ssid(&ssids, name)?.clone();
+    let profiles = wireless::profiles_with_ssid(&profiles, name)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    Ok(SsidContext {
+        config,
+        ssid,
+        profiles,
+        pending: SsidChanges::default(),
+    })
+}
+
+fn print_settings(context: &SsidContext) {
+    let current = &context.ssid;
+    let pending = &context.pending;
+    let show = |value: Option<&str>| value.unwrap_or_default().to_string();
+    let flag = |value: Option<bool>| value.map(|value| yes_no(Some(value))).unwrap_or_default();
+
+    let entries: Vec<_> = context
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.ssid(context.name()))
+        .collect();
+    let interfaces: Vec<&str> = entries
+        .iter()
+        .filter_map(|entry| entry.interface_name.as_deref())
+        .collect();
+    let vlans: Vec<String> = entries
+        .iter()
+        .filter_map(|entry| entry.flex_connect.as_ref())
+        .filter(|flex| flex.enable_flex_connect.unwrap_or(false))
+        .filter_map(|flex| flex.local_to_vlan)
+        .map(|vlan| vlan.to_string())
+        .collect();
+
+    let mut table = Table::new();
+    table.add_row(row!["Setting", "Current", "Pending"]);
+    table.add_row(row![
+        "Security",
+        show(current.security_level.as_deref()),
+        show(pending.security.as_deref())
+    ]);
+    table.add_row(row![
+        "Passphrase",
+        if current
+            .passphrase
+            .as_deref()
+            .is_some_and(|value| !value.is_empty())
+        {
+            "(set)"
+        } else {
+            ""
+        },
+        if pending.passphrase.is_some() {
+            "(changed)"
+        } else {
+            ""
+        }
+    ]);
+    table.add_row(row![
+        "Band Select",
+        yes_no(Some(wireless::band_select(current))),
+        flag(pending.band_select)
+    ]);
+    table.add_row(row![
+        "Fast Transition",
+        show(current.fast_transition.as_deref()),
+        show(pending.fast_transition.as_deref())
+    ]);
+    table.add_row(row![
+        "Broadcast",
+        yes_no(current.enable_broadcast_ssid),
+        flag(pending.broadcast)
+    ]);
+    table.add_row(row![
+        "Interface",
+        interfaces.join(", "),
+        show(pending.interface.as_deref())
+    ]);
+    table.add_row(row![
+        "FlexConnect VLAN",
+        vlans.join(", "),
+        match pending.vlan {
+            Some(Some(vlan)) => vlan.to_string(),
+            Some(None) => "(off)".to_string(),
+            None => String::new(),
+        }
+    ]);
+    output::print_table(&table);
+}
+
+async fn commit(context: &mut SsidContext) -> Result<()> {
+    let pending = &context.pending;
+    if pending.is_empty() {
+        return Err(anyhow!("No pending changes"));
+    }
+    if pending.changes_profiles() && context.profiles.is_empty() {
+        return Err(anyhow!(
+            "{} is not in any wireless profile, so it has no interface or VLAN to change",
+            context.name()
+        ));
+    }
+
+    let mut ssid = context.ssid.clone();
+    if let Some(security) = &pending.security {
+        ssid.security_level = Some(security.clone());
+    }
+    if let Some(passphrase) = &pending.passphrase {
+        ssid.passphrase = Some(passphrase.clone());
+    }
+    if let Some(fast_transition) = &pending.fast_transition {
+        ssid.fast_transition = Some(fast_transition.clone());
+    }
+    if let Some(broadcast) = pending.broadcast {
+        ssid.enable_broadcast_ssid = Some(broadcast);
+    }
+    match pending.band_select {
+        Some(true) => ssid.radio_policy = Some(BAND_SELECT.to_string()),
+        // Only undo band select; other radio policies are left alone
+        Some(false) if wireless::band_select(&ssid) => {
+            ssid.radio_policy = Some(DUAL_BAND.to_string())
+        }
+        _ => {}
+    }
+    let personal = ssid
+        .security_level
+        .as_deref()
+        .is_some_and(|level| level.ends_with("PERSONAL"));
+    if personal && ssid.passphrase.as_deref().is_none_or(str::is_empty) {
+        return Err(anyhow!(
+            "Personal security needs a passphrase; set one with 'passphrase'"
+        ));
+    }
+
+    let mut profiles = context.profiles.clone();
+    for profile in &mut profiles {
+        for entry in &mut profile.ssid_details {
+            if !entry
+                .name
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(context.name()))
+            {
+                continue;
+            }
+            if let Some(interface) = &pending.interface {
+                entry.interface_name = Some(interface.clone());
+            }
+            if let Some(vlan) = pending.vlan {
+                entry.flex_connect = Some(FlexConnect {
+                    enable_flex_connect: Some(vlan.is_some()),
+                    local_to_vlan: vlan,
+                });
+            }
+        }
+    }
+
+    let config = &context.config;
+    let token = auth::authenticate(config).await?;
+    if pending.changes_ssid() {
+        let execution = updatessid::update_enterprise_ssid(config, &token, &ssid).await?;
+        println!("Updating SSID {}...", context.name());
+        getexecution::wait_for_execution(
+            config,
+            &token,
+            &execution.execution_id,
+            EXECUTION_TIMEOUT,
+        )
+        .await?;
+        context.ssid = ssid;
+    }
+    if pending.changes_profiles() {
+        for profile in &profiles {
+            let execution =
+                updatewirelessprofile::update_wireless_profile(config, &token, profile).await?;
+            println!(
+                "Updating wireless profile {}...",
+                profile.name.as_deref().unwrap_or_default()
+            );
+            getexecution::wait_for_execution(
+                config,
+                &token,
+                &execution.execution_id,
+                EXECUTION_TIMEOUT,
+            )
+            .await?;
+        }
+        context.profiles = profiles;
+    }
+
+    context.pending = SsidChanges::default();
+    Ok(())
+}
+
+/// Provision every WLC at a site where the SSID is broadcast, tracking each task.
+async fn provision(context: &SsidContext) -> Result<()> {
+    let config = &context.config;
+    let token = auth::authenticate(config).await?;
+    let devices = cache::get_devices(config, CacheMode::Default).await?;
+    let sites = getdevicehealth::get_device_sites(config, &token).await?;
+
+    let controllers: Vec<_> = devices
+        .iter()
+        .filter(|device| wireless::is_wlc(device))
+        .filter(|device| {
+            device
+                .id
+                .as_ref()
+                .and_then(|id| sites.get(id))
+                .is_some_and(|site| wireless::ssid_at_site(&context.profiles, context.name(), site))
+        })
+        .collect();
+    if controllers.is_empty() {
+        println!(
+            "No controllers are assigned to sites where {} is broadcast.",
+            context.name()
+        );
+        return Ok(());
+    }
+
+    for controller in &controllers {
+        println!(
+            "  {} ({})",
+            controller.hostname.as_deref().unwrap_or("Unknown"),
+            controller.management_ip_address.as_deref().unwrap_or("N/A")
+        );
+    }
+    if !utils::confirm(&format!("Provision {} controllers?", controllers.len())) {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let mut tasks = Vec::new();
+    for controller in &controllers {
+        let device_id = controller.id.as_deref().unwrap_or_default();
+        let started = provisionwlc::provision_wlc(config, &token, device_id).await;
+        tasks.push(TrackedTask::new(
+            controller.hostname.as_deref().unwrap_or("Unknown"),
+            started,
+        ));
+    }
+
+    let title = format!(
+        "Provision {} controllers for {}",
+        tasks.len(),
+        context.name()
+    );
+    tasktracker::track(config, &token, &title, &mut tasks, PROVISION_TIMEOUT).await;
+    Ok(())
+}
@@ -0,0 +1,379 @@
+// src/handlers/device.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::devices::{assigndevicesite, deletedevice, getdevicehealth, syncdevice};
+use crate::api::discovery::{getcredentials, getdiscovery, startdiscovery};
+use crate::api::tasks::gettask;
+use crate::app::cache::{self, CacheMode};
+use crate::app::config::{self, Config};
+use crate::commands::device::{DeviceActionArgs, DeviceCommands};
+use crate::handlers::show::image::load_devices;
+use crate::helpers::tasktracker::{self, TrackedTask};
+use crate::helpers::{devicefilter, interrupt, output, utils};
+use anyhow::Result;
+use log::error;
+use prettytable::{format, row, Table};
+use std::time::{Duration, Instant};
+
+const DISCOVERY_START_TIMEOUT: Duration = Duration::from_secs(60);
+const DISCOVERY_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+pub fn handle_device_command(subcommand: DeviceCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        // Reachability and site membership must be current, not cached
+        let site = match &subcommand {
+            DeviceCommands::Resync { select, .. } | DeviceCommands::Delete { select, .. } => select.site.clone(),
+            _ => None,
+        };
+        let inventory = match load_devices(&config, &token, CacheMode::Refresh, site.as_deref()).await {
+            Ok(inventory) => inventory,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            DeviceCommands::Add { ip, credentials, action } => {
+                let global = match getcredentials::get_global_credentials(&config, &token).await {
+                    Ok(global) => global,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                let profile = global.profile(&credentials);
+                if !profile.iter().any(|(kind, _)| *kind == "CLI") {
+                    error!("No CLI credential with the description '{}'", credentials);
+                    return;
+                }
+                let credential_ids: Vec<String> = profile
+                    .iter()
+                    .filter_map(|(_, credential)| credential.id.clone())
+                    .collect();
+                let kinds: Vec<&str> = profile.iter().map(|(kind, _)| *kind).collect();
+                println!("Credentials '{}': {}", credentials, kinds.join(", "));
+
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["IP", "Action"]);
+                let mut targets = Vec::new();
+                for address in ip.iter().map(|address| address.to_string()) {
+                    let existing = inventory
+                        .iter()
+                        .find(|device| device.management_ip_address.as_deref() == Some(address.as_str()));
+                    match existing {
+                        Some(device) => table.add_row(row![
+                            address,
+                            format!("Skip: already in inventory as {}", device.hostname.as_deref().unwrap_or("Unknown"))
+                        ]),
+                        None => {
+                            targets.push(address.clone());
+                            table.add_row(row![address, "Add"])
+                        }
+                    };
+                }
+                output::print_table(&table);
+
+                if !proceed(&action, targets.len(), &format!("Add {} devices?", targets.len())) {
+                    return;
+                }
+
+                add_devices(&config, &token, &targets, &credential_ids, action.timeout).await;
+                if let Err(e) = cache::refresh_devices(&config).await {
+                    error!("Failed to refresh the device cache: {}", e);
+                }
+            }
+            DeviceCommands::Resync { select, action } => {
+                let pattern = select.device.as_deref().unwrap_or("*");
+                let mut selected = devicefilter::select_devices(&inventory, pattern);
+                if select.unreachable {
+                    selected.retain(|device| !is_reachable(device));
+                }
+                if !plan(&selected, pattern, "Resync") {
+                    return;
+                }
+                if !proceed(&action, selected.len(), &format!("Resync {} devices?", selected.len())) {
+                    return;
+                }
+
+                let mut tasks = Vec::new();
+                for device in &selected {
+                    let device_id = device.id.as_deref().unwrap_or_default();
+                    let started = syncdevice::sync_device(&config, &token, device_id).await;
+                    tasks.push(TrackedTask::new(hostname(device), started));
+                }
+
+                let title = format!("Resync of {} devices", tasks.len());
+                tasktracker::track(&config, &token, &title, &mut tasks, action.timeout).await;
+            }
+            DeviceCommands::Delete { select, clean_config, action } => {
+                let pattern = select.device.as_deref().unwrap_or("*");
+                let mut selected = devicefilter::select_devices(&inventory, pattern);
+                if select.unreachable {
+                    selected.retain(|device| !is_reachable(device));
+                }
+                let verb = if clean_config { "Delete and clean config" } else { "Delete" };
+                if !plan(&selected, pattern, verb) {
+                    return;
+                }
+                let question = if clean_config {
+                    format!("Delete {} devices and remove the configuration pushed to them?", selected.len())
+                } else {
+                    format!("Delete {} devices from inventory?", selected.len())
+                };
+                if !proceed(&action, selected.len(), &question) {
+                    return;
+                }
+
+                let mut tasks = Vec::new();
+                for device in &selected {
+                    let device_id = device.id.as_deref().unwrap_or_default();
+                    let started = deletedevice::delete_device(&config, &token, device_id, clean_config).await;
+                    tasks.push(TrackedTask::new(hostname(device), started));
+                }
+
+                let title = format!("Delete {} devices", tasks.len());
+                tasktracker::track(&config, &token, &title, &mut tasks, action.timeout).await;
+                // Drop the deleted devices from the cache
+                if let Err(e) = cache::refresh_devices(&config).await {
+                    error!("Failed to refresh the device cache: {}", e);
+                }
+            }
+            DeviceCommands::AssignSite { device, site, action } => {
+                let site_id = match cache::refresh_sites(&config).await {
+                    Ok(sites) => match devicefilter::find_site(&sites, &site) {
+                        Ok(found) => found.id.clone().unwrap_or_default(),
+                        Err(e) => {
+                            error!("{}", e);
+                            return;
+                        }
+                    },
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                let current = match getdevicehealth::get_device_sites(&config, &token).await {
+                    Ok(current) => current,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+
+                let selected = devicefilter::select_devices(&inventory, &device);
+                if selected.is_empty() {
+                    println!("No devices match '{}'.", device);
+                    return;
+                }
+
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["Device", "IP", "Current Site", "Action"]);
+                let mut targets = Vec::new();
+                for entry in selected {
+                    let current_site = entry.id.as_ref().and_then(|id| current.get(id));
+                    let assigned = current_site.is_some_and(|hierarchy| devicefilter::same_site(hierarchy, &site));
+                    table.add_row(row![
+                        hostname(entry),
+                        entry.management_ip_address.as_deref().unwrap_or("N/A"),
+                        current_site.map(String::as_str).unwrap_or("-"),
+                        if assigned { "Skip: already assigned" } else { "Assign" }
+                    ]);
+                    if !assigned {
+                        targets.push(entry);
+                    }
+                }
+                output::print_table(&table);
+
+                if !proceed(&action, targets.len(), &format!("Assign {} devices to {}?", targets.len(), site)) {
+                    return;
+                }
+
+                let mut tasks = Vec::new();
+                for entry in &targets {
+                    let device_id = entry.id.as_deref().unwrap_or_default();
+                    let started = assigndevicesite::assign_to_site(&config, &token, device_id, &site_id).await;
+                    tasks.push(TrackedTask::new(hostname(entry), started));
+                }
+
+                let title = format!("Assign {} devices to {}", tasks.len(), site);
+                tasktracker::track(&config, &token, &title, &mut tasks, action.timeout).await;
+            }
+        }
+    });
+}
+
+/// Outcome of adding one device by discovery.
+enum AddState {
+    Discovering(String),
+    Added(String),
+    Failed(String),
+}
+
+/// Run a single-IP discovery per address and follow each until its device is
+/// discovered, the discovery fails, `timeout` passes or Ctrl-C is pressed.
+async fn add_devices(config: &Config, token: &Token, addresses: &[String], credential_ids: &[String], timeout: Duration) {
+    let deadline = Instant::now() + timeout;
+    let mut states = Vec::new();
+    for address in addresses {
+        let name = format!("catalysh add {}", address);
+        let started = startdiscovery::start_discovery(config, token, &name, "Single", address, credential_ids).await;
+        // The task finishes once the job is created; its progress is the discovery id
+        let created = match started {
+            Ok(task) => gettask::wait_for_task(config, token, &task.task_id, DISCOVERY_START_TIMEOUT).await,
+            Err(e) => Err(e),
+        };
+        let state = match created.map(|task| task.progress.unwrap_or_default()) {
+            Ok(id) if !id.is_empty() => (Some(id), AddState::Discovering("Started".to_string())),
+            Ok(_) => (None, AddState::Failed("Catalyst Center did not return the discovery id".to_string())),
+            Err(e) => (None, AddState::Failed(format!("Failed to start discovery: {}", e))),
+        };
+        print_add_state(address, &state.1);
+        states.push(state);
+    }
+
+    while states.iter().any(|(_, state)| matches!(state, AddState::Discovering(_))) {
+        if Instant::now() >= deadline {
+            for (_, state) in states.iter_mut().filter(|(_, state)| matches!(state, AddState::Discovering(_))) {
+                *state = AddState::Failed(format!("Still discovering after {}s", timeout.as_secs()));
+            }
+            break;
+        }
+        if interrupt::until_ctrl_c(tokio::time::sleep(DISCOVERY_POLL_INTERVAL)).await.is_none() {
+            println!("Stopped waiting. The discoveries continue to run; see 'show discovery list'.");
+            return;
+        }
+
+        for (address, (discovery_id, state)) in addresses.iter().zip(states.iter_mut()) {
+            let discovery_id = match (&state, discovery_id) {
+                (AddState::Discovering(_), Some(discovery_id)) => discovery_id.as_str(),
+                _ => continue,
+            };
+            // A failed poll is retried on the next round
+            let next = match add_state(config, token, discovery_id, address).await {
+                Ok(next) => next,
+                Err(_) => continue,
+            };
+            let changed = match (&*state, &next) {
+                (AddState::Discovering(before), AddState::Discovering(after)) => before != after,
+                _ => true,
+            };
+            if changed {
+                print_add_state(address, &next);
+                *state = next;
+            }
+        }
+    }
+
+    let added = states.iter().filter(|(_, state)| matches!(state, AddState::Added(_))).count();
+    println!("{} added, {} failed", added, states.len() - added);
+}
+
+/// Where the discovery of `address` has got to.
+async fn add_state(config: &Config, token: &Token, discovery_id: &str, address: &str) -> Result<AddState> {
+    let discovery = getdiscovery::get_discovery(config, token, discovery_id).await?;
+    if !discovery.is_complete() {
+        return Ok(AddState::Discovering(
+            discovery.discovery_condition.unwrap_or_else(|| "In Progress".to_string()),
+        ));
+    }
+
+    let devices = getdiscovery::get_discovered_devices(config, token, discovery_id).await?;
+    let device = devices
+        .iter()
+        .find(|device| device.management_ip_address.as_deref() == Some(address));
+    Ok(match device {
+        Some(device) if device.reachability_status.as_deref() == Some("Success") => AddState::Added(format!(
+            "Discovered as {}; inventory {}",
+            device.hostname.as_deref().unwrap_or("Unknown"),
+            device.inventory_collection_status.as_deref().unwrap_or("pending")
+        )),
+        Some(device) => AddState::Failed(
+            device
+                .reachability_failure_reason
+                .clone()
+                .or_else(|| device.cli_status.as_ref().map(|status| format!("CLI {}", status)))
+                .unwrap_or_else(|| "Unreachable".to_string()),
+        ),
+        None => AddState::Failed("Discovery completed without finding a device".to_string()),
+    })
+}
+
+fn print_add_state(address: &str, state: &AddState) {
+    match state {
+        AddState::Discovering(detail) => println!("{}: Discovering {}", address, detail),
+        AddState::Added(detail) => println!("{}: Added. {}", address, detail),
+        AddState::Failed(detail) => println!("{}: Failed. {}", address, detail),
+    }
+}
+
+fn hostname(device: &AllDevices) -> &str {
+    device.hostname.as_deref().unwrap_or("Unknown")
+}
+
+fn is_reachable(device: &AllDevices) -> bool {
+    device
+        .reachability_status
+        .as_deref()
+        .is_some_and(|status| status.eq_ignore_ascii_case("Reachable"))
+}
+
+/// Print what `verb` would apply to. Returns false if nothing matched.
+fn plan(selected: &[&AllDevices], pattern: &str, verb: &str) -> bool {
+    if selected.is_empty() {
+        println!("No devices match '{}'.", pattern);
+        return false;
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Device", "IP", "Reachability", "Last Updated", "Action"]);
+    for device in selected {
+        table.add_row(row![
+            hostname(device),
+            device.management_ip_address.as_deref().unwrap_or("N/A"),
+            device.reachability_status.as_deref().unwrap_or("Unknown"),
+            device.last_updated.as_deref().unwrap_or("N/A"),
+            verb
+        ]);
+    }
+    output::print_table(&table);
+    true
+}
+
+/// Whether to go ahead with `count` operations, given `--dry-run` and `--yes`.
+fn proceed(action: &DeviceActionArgs, count: usize, question: &str) -> bool {
+    if count == 0 {
+        println!("Nothing to do.");
+        return false;
+    }
+    if action.dry_run {
+        println!("Dry run: nothing was started.");
+        return false;
+    }
+    if !action.yes && !utils::confirm(question) {
+        println!("Aborted.");
+        return false;
+    }
+    true
+}
@@ -0,0 +1,80 @@
+// src/handlers/diff.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::devices::getdeviceconfig;
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::app::cache::{self, CacheMode};
+use crate::app::config::{self, Config};
+use crate::commands::diff::{DiffCommands, DiffDeviceCommands};
+use crate::helpers::{completion, devicefilter, diff};
+use anyhow::{anyhow, Result};
+use log::error;
+
+pub fn handle_diff_command(subcommand: DiffCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            DiffCommands::Device {
+                subcommand: DiffDeviceCommands::Config { a, b, context },
+            } => {
+                let devices = match cache::get_devices(&config, CacheMode::Default).await {
+                    Ok(devices) => devices,
+                    Err(e) => {
+                        error!("Failed to fetch devices: {}", e);
+                        return;
+                    }
+                };
+                completion::record_devices(&devices);
+
+                let token = match auth::authenticate(&config).await {
+                    Ok(t) => t,
+                    Err(e) => {
+                        error!("Authentication failed: {}", e);
+                        return;
+                    }
+                };
+
+                let (old, new) = match tokio::try_join!(
+                    fetch(&config, &token, &devices, &a),
+                    fetch(&config, &token, &devices, &b)
+                ) {
+                    Ok(configs) => configs,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+
+                let lines = diff::unified(&old, &new, &a, &b, context);
+                if lines.is_empty() {
+                    outln!("No differences between {} and {}.", a, b);
+                }
+                for line in lines {
+                    outln!("{}", line);
+                }
+            }
+        }
+    });
+}
+
+/// Config named by `<device>` (current) or `<device>@<version>` (archived).
+async fn fetch(config: &Config, token: &Token, devices: &[AllDevices], spec: &str) -> Result<String> {
+    let (name, version) = match spec.split_once('@') {
+        Some((name, version)) => (name, Some(version)),
+        None => (spec, None),
+    };
+    let device = devicefilter::find_device(devices, name)?;
+    let id = device.id.as_deref().ok_or_else(|| anyhow!("Device {} has no id", name))?;
+
+    getdeviceconfig::fetch_config(config, token, id, version)
+        .await
+        .map_err(|e| anyhow!("{}: {}", spec, e))
+}
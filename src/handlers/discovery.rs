@@ -0,0 +1,139 @@
+// src/handlers/discovery.rs
+
+use crate::api::authentication::auth;
+use crate::api::discovery::{getcredentials, getdiscovery, startdiscovery};
+use crate::api::tasks::gettask;
+use crate::app::config;
+use crate::commands::discovery::DiscoveryCommands;
+use crate::handlers::show::discovery::print_discovery;
+use crate::helpers::interrupt;
+use log::error;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+pub fn handle_discovery_command(subcommand: DiscoveryCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            DiscoveryCommands::Start { range, credentials, name, timeout } => {
+                let global = match getcredentials::get_global_credentials(&config, &token).await {
+                    Ok(global) => global,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+
+                let mut credential_ids: Vec<String> = Vec::new();
+                for profile_name in &credentials {
+                    let profile = global.profile(profile_name);
+                    if profile.is_empty() {
+                        error!("No global credentials with the description '{}'", profile_name);
+                        return;
+                    }
+                    let kinds: Vec<&str> = profile.iter().map(|(kind, _)| *kind).collect();
+                    println!("Credentials '{}': {}", profile_name, kinds.join(", "));
+                    for id in profile.iter().filter_map(|(_, credential)| credential.id.clone()) {
+                        if !credential_ids.contains(&id) {
+                            credential_ids.push(id);
+                        }
+                    }
+                }
+
+                let name = name.unwrap_or_else(|| format!("catalysh {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S")));
+                let discovery_type = if range.len() == 1 { "Range" } else { "Multi Range" };
+                let ranges = range.join(",");
+
+                let task = match startdiscovery::start_discovery(&config, &token, &name, discovery_type, &ranges, &credential_ids).await {
+                    Ok(task) => task,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                println!("Starting discovery '{}' of {}, Ctrl-C to stop waiting...", name, ranges);
+
+                // The task finishes once the job is created; its progress is the discovery id
+                let deadline = Instant::now() + timeout;
+                let wait = gettask::wait_for_task(&config, &token, &task.task_id, timeout);
+                let created = match interrupt::until_ctrl_c(wait).await {
+                    Some(result) => result,
+                    None => {
+                        println!("Stopped waiting. The discovery continues to run in Catalyst Center.");
+                        return;
+                    }
+                };
+                let discovery_id = match created.map(|task| task.progress.unwrap_or_default()) {
+                    Ok(id) if !id.is_empty() => id,
+                    Ok(_) => {
+                        error!("Catalyst Center did not return the discovery id");
+                        return;
+                    }
+                    Err(e) => {
+                        error!("Failed to start discovery: {}", e);
+                        return;
+                    }
+                };
+
+                let mut last_progress = None;
+                let discovery = loop {
+                    let discovery = match getdiscovery::get_discovery(&config, &token, &discovery_id).await {
+                        Ok(discovery) => discovery,
+                        Err(e) => {
+                            error!("{}", e);
+                            return;
+                        }
+                    };
+
+                    let progress = (discovery.discovery_condition.clone(), discovery.num_devices);
+                    if last_progress.as_ref() != Some(&progress) {
+                        println!(
+                            "{}: {} devices found",
+                            progress.0.as_deref().unwrap_or("Unknown"),
+                            progress.1.unwrap_or(0)
+                        );
+                        last_progress = Some(progress);
+                    }
+                    if discovery.is_complete() {
+                        break discovery;
+                    }
+                    if Instant::now() >= deadline {
+                        println!(
+                            "Discovery still running after {}s. Check it with 'show discovery detail {}'.",
+                            timeout.as_secs(),
+                            discovery_id
+                        );
+                        return;
+                    }
+
+                    if interrupt::until_ctrl_c(tokio::time::sleep(POLL_INTERVAL)).await.is_none() {
+                        println!("Stopped waiting. Check the discovery with 'show discovery detail {}'.", discovery_id);
+                        return;
+                    }
+                };
+
+                match getdiscovery::get_discovered_devices(&config, &token, &discovery_id).await {
+                    Ok(devices) => print_discovery(&discovery, &devices),
+                    Err(e) => error!("{}", e),
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,79 @@
+// src/handlers/export.rs
+
+use crate::api::authentication::auth;
+use crate::api::devices::getdevicehealth;
+use crate::app::cache;
+use crate::app::config;
+use crate::commands::export::{ExportCommands, ExportFormat, GroupBy};
+use crate::helpers::completion;
+use crate::helpers::export::{self, ExportHost};
+use log::error;
+use std::collections::HashMap;
+
+pub fn handle_export_command(subcommand: ExportCommands) {
+    let config = match config::load_config() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            error!("Failed to load configuration: {}", e);
+            return;
+        }
+    };
+
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+
+    runtime.block_on(async {
+        match subcommand {
+            ExportCommands::Inventory { format, group_by, cache: cache_args } => {
+                let devices = match cache::get_devices(&config, cache_args.mode()).await {
+                    Ok(devices) => devices,
+                    Err(e) => {
+                        error!("Failed to fetch devices: {}", e);
+                        return;
+                    }
+                };
+                completion::record_devices(&devices);
+
+                // Site assignments only come from the device health API
+                let needs_sites = matches!(format, ExportFormat::NetboxCsv)
+                    || group_by.contains(&GroupBy::Site);
+                let sites = if needs_sites {
+                    let sites = match auth::authenticate(&config).await {
+                        Ok(token) => getdevicehealth::get_device_sites(&config, &token).await,
+                        Err(e) => Err(e),
+                    };
+                    match sites {
+                        Ok(sites) => sites,
+                        Err(e) => {
+                            error!("Failed to fetch device sites, exporting without them: {}", e);
+                            HashMap::new()
+                        }
+                    }
+                } else {
+                    HashMap::new()
+                };
+
+                let hosts = ExportHost::from_devices(&devices, &sites);
+                if hosts.len() < devices.len() {
+                    eprintln!(
+                        "Skipped {} devices with neither a hostname nor a management IP.",
+                        devices.len() - hosts.len()
+                    );
+                }
+                let text = match format {
+                    ExportFormat::Ansible => export::to_ansible(&hosts, &group_by),
+                    ExportFormat::Nornir => export::to_nornir(&hosts, &group_by),
+                    ExportFormat::NetboxCsv => Ok(export::to_netbox_csv(&hosts)),
+                };
+
+                match text {
+                    Ok(text) => {
+                        for line in text.lines() {
+                            outln!("{}", line);
+                        }
+                    }
+                    Err(e) => error!("Failed to export inventory: {}", e),
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,302 @@
+// src/handlers/exporter.rs
+
+use crate::api::authentication::auth;
+use crate::api::clients::getclienthealth;
+use crate::api::devices::{getdevicehealth, getdevicelist};
+use crate::api::issues::getissuelist;
+use crate::api::sites::getsitehealth;
+use crate::app::config::{self, Config};
+use crate::helpers::interrupt;
+use crate::helpers::metrics::{self, Gauge};
+use anyhow::Result;
+use log::{error, info};
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+pub fn handle_exporter_command(listen: SocketAddr, interval: Duration) {
+    let config = match config::load_config() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            error!("Failed to load configuration: {}", e);
+            return;
+        }
+    };
+
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+
+    runtime.block_on(async {
+        let listener = match TcpListener::bind(listen).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!("Failed to listen on {}: {}", listen, e);
+                return;
+            }
+        };
+        println!(
+            "Serving metrics on http://{}/metrics, polling every {}s (Ctrl-C to stop)",
+            listen,
+            interval.as_secs()
+        );
+
+        let latest = Arc::new(RwLock::new(String::new()));
+        let poller = tokio::spawn(poll(config, interval, Arc::clone(&latest)));
+
+        if interrupt::until_ctrl_c(serve(listener, latest)).await.is_none() {
+            println!("Exporter stopped.");
+        }
+        poller.abort();
+    });
+}
+
+async fn poll(config: Config, interval: Duration, latest: Arc<RwLock<String>>) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let text = collect(&config).await;
+        if let Ok(mut latest) = latest.write() {
+            *latest = text;
+        }
+    }
+}
+
+async fn serve(listener: TcpListener, latest: Arc<RwLock<String>>) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let latest = Arc::clone(&latest);
+                tokio::spawn(async move {
+                    if let Err(e) = respond(stream, latest).await {
+                        info!("Failed to answer metrics request: {}", e);
+                    }
+                });
+            }
+            Err(e) => error!("Failed to accept connection: {}", e),
+        }
+    }
+}
+
+/// Minimal HTTP/1.1 handling: only `GET /metrics` is served.
+async fn respond(mut stream: TcpStream, latest: Arc<RwLock<String>>) -> Result<()> {
+    let mut buf = [0u8; 4096];
+    let read = stream.read(&mut buf).await?;
+    let request = String::from_utf8_lossy(&buf[..read]);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+
+    let (status, content_type, body) = if path == "/metrics" {
+        let body = latest.read().map(|text| text.clone()).unwrap_or_default();
+        ("200 OK", "text/plain; version=0.0.4", body)
+    } else if path == "/" {
+        (
+            "200 OK",
+            "text/html",
+            "<html><body><a href=\"/metrics\">Metrics</a></body></html>".to_string(),
+        )
+    } else {
+        ("404 Not Found", "text/plain", "Not found\n".to_string())
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Poll every source once and render the results. A failing source is
+/// reported through `catalysh_scrape_success` rather than failing the scrape.
+async fn collect(config: &Config) -> String {
+    let started = Instant::now();
+    let mut success = Gauge::new(
+        "catalysh_scrape_success",
+        "Whether the last poll of each Catalyst Center API succeeded",
+    );
+    let mut gauges = Vec::new();
+
+    match auth::authenticate(config).await {
+        Ok(token) => {
+            let results = [
+                ("devices", device_metrics(config, &token).await),
+                ("device_health", device_health_metrics(config, &token).await),
+                ("issues", issue_metrics(config, &token).await),
+                ("client_health", client_health_metrics(config, &token).await),
+                ("site_health", site_health_metrics(config, &token).await),
+            ];
+            for (source, result) in results {
+                match result {
+                    Ok(source_gauges) => {
+                        success.add(vec![("source", source.to_string())], 1.0);
+                        gauges.extend(source_gauges);
+                    }
+                    Err(e) => {
+                        error!("Failed to poll {}: {}", source, e);
+                        success.add(vec![("source", source.to_string())], 0.0);
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            error!("Failed to authenticate: {}", e);
+            success.add(vec![("source", "auth".to_string())], 0.0);
+        }
+    }
+
+    let mut duration = Gauge::new(
+        "catalysh_poll_duration_seconds",
+        "Time taken to poll Catalyst Center",
+    );
+    duration.add(Vec::new(), started.elapsed().as_secs_f64());
+    gauges.push(success);
+    gauges.push(duration);
+
+    metrics::render(&gauges)
+}
+
+async fn device_metrics(config: &Config, token: &auth::Token) -> Result<Vec<Gauge>> {
+    let devices = getdevicelist::get_all_devices(config, token).await?;
+
+    let mut reachable = Gauge::new(
+        "catalysh_device_reachable",
+        "Whether Catalyst Center can reach the device (1) or not (0)",
+    );
+    let mut counts: BTreeMap<String, f64> = BTreeMap::new();
+    for device in &devices {
+        let status = device.reachability_status.clone().unwrap_or_else(|| "Unknown".to_string());
+        reachable.add(
+            vec![
+                ("hostname", device.hostname.clone().unwrap_or_default()),
+                ("ip", device.management_ip_address.clone().unwrap_or_default()),
+                ("family", device.family.clone().unwrap_or_default()),
+                ("role", device.role.clone().unwrap_or_default()),
+            ],
+            if status == "Reachable" { 1.0 } else { 0.0 },
+        );
+        *counts.entry(status).or_default() += 1.0;
+    }
+
+    let mut totals = Gauge::new("catalysh_devices", "Number of devices by reachability status");
+    for (status, count) in counts {
+        totals.add(vec![("reachability", status)], count);
+    }
+
+    Ok(vec![reachable, totals])
+}
+
+async fn device_health_metrics(config: &Config, token: &auth::Token) -> Result<Vec<Gauge>> {
+    let health = getdevicehealth::get_all_device_health(config, token).await?;
+
+    let mut score = Gauge::new("catalysh_device_health_score", "Overall device health score (0-10)");
+    for device in health {
+        if let Some(value) = device.overall_health.filter(|value| *value >= 0.0) {
+            score.add(
+                vec![
+                    ("hostname", device.name.unwrap_or_default()),
+                    ("ip", device.ip_address.unwrap_or_default()),
+                    ("site", device.location.unwrap_or_default()),
+                ],
+                value,
+            );
+        }
+    }
+
+    Ok(vec![score])
+}
+
+async fn issue_metrics(config: &Config, token: &auth::Token) -> Result<Vec<Gauge>> {
+    let open = HashMap::from([("issueStatus".to_string(), "ACTIVE".to_string())]);
+    let issues = getissuelist::get_issue_list(config, token, &open)
+        .await?
+        .response
+        .unwrap_or_default();
+
+    // Always report every priority so alerts can rely on the series existing
+    let mut counts: BTreeMap<String, f64> = ["P1", "P2", "P3", "P4"]
+        .iter()
+        .map(|priority| (priority.to_string(), 0.0))
+        .collect();
+    for issue in issues {
+        let priority = issue.priority.unwrap_or_else(|| "Unknown".to_string());
+        *counts.entry(priority).or_default() += 1.0;
+    }
+
+    let mut gauge = Gauge::new("catalysh_issues", "Number of open issues by priority");
+    for (priority, count) in counts {
+        gauge.add(vec![("priority", priority)], count);
+    }
+
+    Ok(vec![gauge])
+}
+
+async fn client_health_metrics(config: &Config, token: &auth::Token) -> Result<Vec<Gauge>> {
+    let health = getclienthealth::get_client_health(config, token).await?;
+
+    let mut score = Gauge::new(
+        "catalysh_client_health_score",
+        "Client health score by client type (0-100)",
+    );
+    let mut clients = Gauge::new("catalysh_clients", "Number of clients by client type");
+    for detail in health.into_iter().flat_map(|site| site.score_detail.unwrap_or_default()) {
+        let category = match detail.score_category {
+            Some(category) if category.score_category.as_deref() == Some("CLIENT_TYPE") => category,
+            _ => continue,
+        };
+        let client_type = category.value.unwrap_or_default().to_lowercase();
+
+        if let Some(value) = detail.score_value.filter(|value| *value >= 0.0) {
+            score.add(vec![("type", client_type.clone())], value);
+        }
+        if let Some(count) = detail.client_count {
+            clients.add(vec![("type", client_type)], count as f64);
+        }
+    }
+
+    Ok(vec![score, clients])
+}
+
+async fn site_health_metrics(config: &Config, token: &auth::Token) -> Result<Vec<Gauge>> {
+    let sites = getsitehealth::get_all_site_health(config, token).await?;
+
+    let mut network = Gauge::new("catalysh_site_network_health", "Average network health of the site (0-100)");
+    let mut devices = Gauge::new(
+        "catalysh_site_healthy_devices_percent",
+        "Percentage of healthy network devices at the site",
+    );
+    let mut clients = Gauge::new(
+        "catalysh_site_healthy_clients_percent",
+        "Percentage of healthy clients at the site",
+    );
+    let mut client_count = Gauge::new("catalysh_site_clients", "Number of clients at the site");
+
+    for site in sites {
+        let labels = vec![
+            ("site", site.site_name.unwrap_or_default()),
+            ("site_type", site.site_type.unwrap_or_default()),
+        ];
+        if let Some(value) = site.network_health_average {
+            network.add(labels.clone(), value);
+        }
+        if let Some(value) = site.healthy_network_device_percentage {
+            devices.add(labels.clone(), value);
+        }
+        if let Some(value) = site.healthy_clients_percentage {
+            clients.add(labels.clone(), value);
+        }
+        if let Some(value) = site.number_of_clients {
+            client_count.add(labels, value as f64);
+        }
+    }
+
+    Ok(vec![network, devices, clients, client_count])
+}
@@ -0,0 +1,190 @@
+// src/handlers/image.rs
+
+use crate::api::authentication::auth;
+use crate::api::images::getimages::{self, SoftwareImage};
+use crate::api::images::{activateimage, distributeimage};
+use crate::app::cache::CacheMode;
+use crate::app::config;
+use crate::commands::image::{ImageActionArgs, ImageCommands};
+use crate::handlers::show::image::load_devices;
+use crate::helpers::swim::{self, ReadinessStatus};
+use crate::helpers::tasktracker::{self, TrackedTask};
+use crate::helpers::{devicefilter, output, utils};
+use anyhow::{anyhow, Result};
+use log::error;
+use prettytable::{format, row, Table};
+
+#[derive(Clone, Copy)]
+enum Action {
+    Distribute,
+    Activate,
+}
+
+pub fn handle_image_command(subcommand: ImageCommands) {
+    let (action, args) = match subcommand {
+        ImageCommands::Distribute { args } => (Action::Distribute, args),
+        ImageCommands::Activate { args } => (Action::Activate, args),
+    };
+
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        // Pre-checks need current versions and reachability, not cached ones
+        let devices = match load_devices(&config, &token, CacheMode::Refresh, args.site.as_deref()).await {
+            Ok(devices) => devices,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+        let pattern = args.device.as_deref().unwrap_or("*");
+        let selected = devicefilter::select_devices(&devices, pattern);
+        if selected.is_empty() {
+            println!("No devices match '{}'.", pattern);
+            return;
+        }
+
+        let images = match target_images(&config, &token, &args).await {
+            Ok(images) => images,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        // Pre-checks: only reachable, managed devices below the target version go ahead
+        let readiness: Vec<swim::Readiness> = selected
+            .iter()
+            .map(|device| swim::check_readiness(device, &images))
+            .collect();
+        let verb = match action {
+            Action::Distribute => "Distribute",
+            Action::Activate => "Activate",
+        };
+
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+        table.add_row(row!["Device", "IP", "Current", "Target", "Action"]);
+        for entry in &readiness {
+            let plan = match entry.status {
+                ReadinessStatus::Ready => verb.to_string(),
+                ReadinessStatus::UpToDate => "Skip: up to date".to_string(),
+                ReadinessStatus::NotReady => format!("Skip: {}", entry.failed_checks.join(", ")),
+                ReadinessStatus::NoGolden if args.image.is_some() => {
+                    "Skip: image does not apply to this platform".to_string()
+                }
+                ReadinessStatus::NoGolden => "Skip: no golden image".to_string(),
+            };
+            table.add_row(row![
+                entry.hostname(),
+                entry.device.management_ip_address.as_deref().unwrap_or("N/A"),
+                entry.current_version(),
+                entry.golden.map(|image| image.version()).unwrap_or("-"),
+                plan
+            ]);
+        }
+        output::print_table(&table);
+
+        let targets: Vec<&swim::Readiness> = readiness
+            .iter()
+            .filter(|entry| entry.status == ReadinessStatus::Ready)
+            .collect();
+        if targets.is_empty() {
+            println!("Nothing to do.");
+            return;
+        }
+        if args.dry_run {
+            println!("Dry run: nothing was started.");
+            return;
+        }
+
+        let batch = args.batch.map_or(targets.len(), usize::from).min(targets.len());
+        let waves = targets.len().div_ceil(batch);
+        let pace = if waves == 1 {
+            "all at once".to_string()
+        } else {
+            format!("{} at a time in {} waves", batch, waves)
+        };
+        let question = match action {
+            Action::Distribute => format!("Distribute images to {} devices, {}?", targets.len(), pace),
+            Action::Activate => format!(
+                "Activate images on {} devices, {}? {} devices reload.",
+                targets.len(),
+                pace,
+                targets.len()
+            ),
+        };
+        if !args.yes && !utils::confirm(&question) {
+            println!("Aborted.");
+            return;
+        }
+
+        // Each wave must finish cleanly before the next one starts
+        for (wave, entries) in targets.chunks(batch).enumerate() {
+            let mut tasks = Vec::new();
+            for entry in entries {
+                let device_id = entry.device.id.as_deref().unwrap_or_default();
+                let image_id = entry
+                    .golden
+                    .and_then(|image| image.image_uuid.as_deref())
+                    .unwrap_or_default();
+                let started = match action {
+                    Action::Distribute => distributeimage::distribute_image(&config, &token, device_id, image_id).await,
+                    Action::Activate => activateimage::activate_image(&config, &token, device_id, image_id).await,
+                };
+                tasks.push(TrackedTask::new(entry.hostname(), started));
+            }
+
+            let title = if waves == 1 {
+                format!("{} of {} devices", verb, tasks.len())
+            } else {
+                format!("{} of {} devices, wave {}/{}", verb, tasks.len(), wave + 1, waves)
+            };
+            if !tasktracker::track(&config, &token, &title, &mut tasks, args.timeout).await {
+                return;
+            }
+            let remaining = waves - wave - 1;
+            if remaining > 0 && !tasks.iter().all(|task| task.succeeded()) {
+                println!("Stopping: a device in this wave did not succeed. {} waves were not started.", remaining);
+                return;
+            }
+        }
+    });
+}
+
+/// The golden images, or just the image named with `--image`.
+async fn target_images(
+    config: &config::Config,
+    token: &auth::Token,
+    args: &ImageActionArgs,
+) -> Result<Vec<SoftwareImage>> {
+    let wanted = match &args.image {
+        Some(wanted) => wanted,
+        None => return getimages::get_images(config, token, true).await,
+    };
+
+    getimages::get_images(config, token, false)
+        .await?
+        .into_iter()
+        .find(|image| {
+            image.image_uuid.as_deref() == Some(wanted.as_str())
+                || image.name.as_deref().is_some_and(|name| name.eq_ignore_ascii_case(wanted))
+        })
+        .map(|image| vec![image])
+        .ok_or_else(|| anyhow!("No image named '{}' in the repository", wanted))
+}
@@ -0,0 +1,109 @@
+// src/handlers/inventory.rs
+
+use crate::app::cache;
+use crate::app::config;
+use crate::app::snapshot::{self, Snapshot, SnapshotStore};
+use crate::commands::inventory::InventoryCommands;
+use crate::helpers::{completion, output};
+use log::error;
+use prettytable::{row, Table};
+
+pub fn handle_inventory_command(subcommand: InventoryCommands) {
+    match subcommand {
+        InventoryCommands::Snapshot { label } => {
+            let config = match config::load_config() {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    error!("Failed to load configuration: {}", e);
+                    return;
+                }
+            };
+
+            // Snapshots always come from Catalyst Center, never from the cache
+            let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+            let devices = match runtime.block_on(cache::refresh_devices(&config)) {
+                Ok(devices) => devices,
+                Err(e) => {
+                    error!("Failed to fetch devices: {}", e);
+                    return;
+                }
+            };
+            completion::record_devices(&devices);
+
+            match SnapshotStore::open().and_then(|store| store.save(label.as_deref(), &devices)) {
+                Ok(id) => outln!("Saved snapshot {} with {} devices.", id, devices.len()),
+                Err(e) => error!("Failed to save snapshot: {}", e),
+            }
+        }
+        InventoryCommands::List => {
+            let snapshots = match SnapshotStore::open().and_then(|store| store.list()) {
+                Ok(snapshots) => snapshots,
+                Err(e) => {
+                    error!("Failed to read snapshots: {}", e);
+                    return;
+                }
+            };
+
+            if snapshots.is_empty() {
+                outln!("No snapshots stored. Use 'inventory snapshot' to create one.");
+                return;
+            }
+
+            let mut table = Table::new();
+            table.add_row(row!["ID", "Label", "Taken", "Devices"]);
+            for snapshot in snapshots {
+                table.add_row(row![
+                    snapshot.id,
+                    snapshot.label.unwrap_or_default(),
+                    cache::format_age(snapshot.created_at),
+                    snapshot.device_count
+                ]);
+            }
+            output::print_table(&table);
+        }
+        InventoryCommands::Diff { from, to } => {
+            let store = match SnapshotStore::open() {
+                Ok(store) => store,
+                Err(e) => {
+                    error!("Failed to open snapshots: {}", e);
+                    return;
+                }
+            };
+            let (before, after) = match (store.find(&from), store.find(&to)) {
+                (Ok(before), Ok(after)) => (before, after),
+                (Err(e), _) | (_, Err(e)) => {
+                    error!("{}", e);
+                    return;
+                }
+            };
+
+            outln!("Comparing {} with {}", describe(&before), describe(&after));
+
+            let changes = snapshot::diff(&before.devices, &after.devices);
+            if changes.is_empty() {
+                outln!("No changes between the snapshots.");
+                return;
+            }
+
+            let mut table = Table::new();
+            table.add_row(row!["Hostname", "Change", "Before", "After"]);
+            for change in &changes {
+                table.add_row(row![change.hostname, change.change, change.before, change.after]);
+            }
+            output::print_table(&table);
+            outln!("{} changes", changes.len());
+        }
+    }
+}
+
+fn describe(snapshot: &Snapshot) -> String {
+    match &snapshot.label {
+        Some(label) => format!(
+            "snapshot {} '{}' ({})",
+            snapshot.id,
+            label,
+            cache::format_age(snapshot.created_at)
+        ),
+        None => format!("snapshot {} ({})", snapshot.id, cache::format_age(snapshot.created_at)),
+    }
+}
@@ -1,12 +1,40 @@
 pub mod show;
 pub mod config;
 pub mod app;
+pub mod watch;
+pub mod terminal;
+pub mod inventory;
+pub mod export;
+pub mod exporter;
+pub mod trace;
+pub mod diff;
+pub mod compliance;
+pub mod image;
+pub mod template;
+pub mod pnp;
+pub mod device;
+pub mod discovery;
+pub mod tag;
 
 use std::process::Command;
 
 pub use show::handle_show_command;
 pub use config::handle_config_command;
 pub use app::handle_app_command;
+pub use watch::handle_watch_command;
+pub use terminal::handle_terminal_command;
+pub use inventory::handle_inventory_command;
+pub use export::handle_export_command;
+pub use exporter::handle_exporter_command;
+pub use trace::handle_trace_command;
+pub use diff::handle_diff_command;
+pub use compliance::handle_compliance_command;
+pub use image::handle_image_command;
+pub use template::handle_template_command;
+pub use pnp::handle_pnp_command;
+pub use device::handle_device_command;
+pub use discovery::handle_discovery_command;
+pub use tag::handle_tag_command;
 
 pub fn clear_screen() -> std::io::Result<()> {
     if cfg!(target_os = "windows") {
@@ -0,0 +1,229 @@
+// src/handlers/pnp.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::images::getimages::{self, SoftwareImage};
+use crate::api::pnp::claimpnpdevice::{self, ClaimOptions};
+use crate::api::pnp::getpnpdevices::{self, PnpDevice};
+use crate::api::pnp::resetpnpdevice;
+use crate::app::cache;
+use crate::app::config::{self, Config};
+use crate::commands::pnp::PnpCommands;
+use crate::handlers::template::load_template;
+use crate::helpers::{devicefilter, output, swim, template, utils};
+use anyhow::{anyhow, Result};
+use log::error;
+use prettytable::{format, row, Table};
+
+pub fn handle_pnp_command(subcommand: PnpCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let queue = match getpnpdevices::get_pnp_devices(&config, &token, None).await {
+            Ok(queue) => queue,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            PnpCommands::Claim { serials, site, template, image, vars, yes } => {
+                let site_id = match find_site_id(&config, &site).await {
+                    Ok(site_id) => site_id,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                let images = match claim_images(&config, &token, image.as_deref()).await {
+                    Ok(images) => images,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                let onboarding = match &template {
+                    Some(name) => match load_template(&config, &token, name, vars.as_deref()).await {
+                        Ok(loaded) => Some(loaded),
+                        Err(e) => {
+                            error!("{}", e);
+                            return;
+                        }
+                    },
+                    None => None,
+                };
+                let template_id = onboarding.as_ref().map(|(version_id, _, _)| version_id.clone());
+
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["Serial", "PID", "Image", "Action"]);
+                let mut targets = Vec::new();
+                for serial in &serials {
+                    let device = match find_pnp_device(&queue, serial) {
+                        Some(device) => device,
+                        None => {
+                            table.add_row(row![serial, "-", "-", "Skip: not in the PnP queue"]);
+                            continue;
+                        }
+                    };
+                    let info = device.device_info.as_ref();
+                    let pid = info.and_then(|info| info.pid.as_deref()).unwrap_or_default();
+                    let state = info.and_then(|info| info.state.as_deref()).unwrap_or("Unknown");
+
+                    // A named image is used as-is; 'golden' is looked up per platform
+                    let chosen = match image.as_deref() {
+                        Some(wanted) if wanted.eq_ignore_ascii_case("golden") => swim::golden_for_pid(pid, &images),
+                        _ => images.first(),
+                    };
+                    let mut options = ClaimOptions {
+                        image_id: chosen.and_then(|image| image.image_uuid.clone()),
+                        template_id: template_id.clone(),
+                        template_params: Vec::new(),
+                    };
+
+                    let mut missing = Vec::new();
+                    if let Some((_, template, vars)) = &onboarding {
+                        let params = vars.for_device(Some(device.serial()));
+                        missing = template::missing_params(template, &params);
+                        options.template_params = params
+                            .iter()
+                            .map(|(key, value)| (key.clone(), template::value_text(value)))
+                            .collect();
+                    }
+
+                    let action = if !state.eq_ignore_ascii_case("Unclaimed") {
+                        format!("Skip: already {}", state)
+                    } else if image.is_some() && chosen.is_none() {
+                        "Skip: no golden image for this platform".to_string()
+                    } else if !missing.is_empty() {
+                        format!("Skip: missing {}", missing.join(", "))
+                    } else {
+                        "Claim".to_string()
+                    };
+                    table.add_row(row![
+                        device.serial(),
+                        if pid.is_empty() { "N/A" } else { pid },
+                        chosen.map(|image| image.version()).unwrap_or("-"),
+                        action
+                    ]);
+                    if action == "Claim" {
+                        targets.push((device, options));
+                    }
+                }
+                output::print_table(&table);
+
+                if targets.is_empty() {
+                    println!("Nothing to do.");
+                    return;
+                }
+                if !yes && !utils::confirm(&format!("Claim {} devices to {}?", targets.len(), site)) {
+                    println!("Aborted.");
+                    return;
+                }
+
+                let mut claimed = 0;
+                for (device, options) in &targets {
+                    let device_id = device.id.as_deref().unwrap_or_default();
+                    match claimpnpdevice::claim_device(&config, &token, device_id, &site_id, options).await {
+                        Ok(message) => {
+                            claimed += 1;
+                            println!("{}: {}", device.serial(), message);
+                        }
+                        Err(e) => println!("{}: {}", device.serial(), e),
+                    }
+                }
+                println!("{} claimed, {} failed", claimed, targets.len() - claimed);
+            }
+            PnpCommands::Reset { serials, yes } => {
+                let mut targets = Vec::new();
+                for serial in &serials {
+                    match find_pnp_device(&queue, serial) {
+                        None => println!("{}: not in the PnP queue", serial),
+                        Some(device) => {
+                            let state = device
+                                .device_info
+                                .as_ref()
+                                .and_then(|info| info.state.as_deref())
+                                .unwrap_or("Unknown");
+                            if state.eq_ignore_ascii_case("Error") {
+                                targets.push(device);
+                            } else {
+                                println!("{}: skipped, state is {} rather than Error", device.serial(), state);
+                            }
+                        }
+                    }
+                }
+
+                if targets.is_empty() {
+                    println!("Nothing to do.");
+                    return;
+                }
+                if !yes && !utils::confirm(&format!("Reset {} devices?", targets.len())) {
+                    println!("Aborted.");
+                    return;
+                }
+
+                let mut reset = 0;
+                for device in &targets {
+                    let device_id = device.id.as_deref().unwrap_or_default();
+                    match resetpnpdevice::reset_device(&config, &token, device_id).await {
+                        Ok(message) => {
+                            reset += 1;
+                            println!("{}: {}", device.serial(), message);
+                        }
+                        Err(e) => println!("{}: {}", device.serial(), e),
+                    }
+                }
+                println!("{} reset, {} failed", reset, targets.len() - reset);
+            }
+        }
+    });
+}
+
+fn find_pnp_device<'a>(queue: &'a [PnpDevice], serial: &str) -> Option<&'a PnpDevice> {
+    queue.iter().find(|device| device.serial().eq_ignore_ascii_case(serial))
+}
+
+async fn find_site_id(config: &Config, site: &str) -> Result<String> {
+    let sites = cache::refresh_sites(config).await?;
+    devicefilter::find_site(&sites, site)?
+        .id
+        .clone()
+        .ok_or_else(|| anyhow!("Site '{}' has no id", site))
+}
+
+/// The golden images for `--image golden`, or just the named image.
+async fn claim_images(config: &Config, token: &Token, wanted: Option<&str>) -> Result<Vec<SoftwareImage>> {
+    let wanted = match wanted {
+        Some(wanted) => wanted,
+        None => return Ok(Vec::new()),
+    };
+    if wanted.eq_ignore_ascii_case("golden") {
+        return getimages::get_images(config, token, true).await;
+    }
+
+    getimages::get_images(config, token, false)
+        .await?
+        .into_iter()
+        .find(|image| {
+            image.image_uuid.as_deref() == Some(wanted)
+                || image.name.as_deref().is_some_and(|name| name.eq_ignore_ascii_case(wanted))
+        })
+        .map(|image| vec![image])
+        .ok_or_else(|| anyhow!("No image named '{}' in the repository", wanted))
+}
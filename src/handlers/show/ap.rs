@@ -4,7 +4,7 @@ use crate::commands::show::ap::ApCommands;
 use crate::app::config;
 use crate::api::authentication::auth;
 use crate::api::wireless::{accesspointconfig, rfprofile};
-use crate::helpers::utils;
+use crate::helpers::{completion, output, utils};
 use log::error;
 use prettytable::{table, row};
 
@@ -42,11 +42,21 @@ pub fn handle_ap_command(subcommand: ApCommands) {
                     }
                 }
             }
-            ApCommands::RfProfile => {
+            ApCommands::RfProfile { name } => {
                 // Fetch RF profiles
                 match rfprofile::get_all_rf_profiles(&config, &token).await {
                     Ok(profiles) => {
-                        println!("\nRF Profiles Overview:");
+                        completion::record_rf_profiles(&profiles);
+                        let profiles: Vec<_> = profiles
+                            .into_iter()
+                            .filter(|profile| name.is_none() || profile.name == name)
+                            .collect();
+                        if profiles.is_empty() {
+                            outln!("No RF profile found matching the specified name.");
+                            return;
+                        }
+
+                        outln!("\nRF Profiles Overview:");
                         let mut overview_table = table!([FbFy => 
                             "Profile Name", "Default", "Channel Width", "Custom", "Brown Field",
                             "5GHz", "2.4GHz", "6GHz"
@@ -64,14 +74,14 @@ pub fn handle_ap_command(subcommand: ApCommands) {
                                 if profile.enable_radio_type_c.unwrap_or(false) { "✓" } else { "✗" }
                             ]);
                         }
-                        overview_table.printstd();
-                        overview_table.printstd();
+                        output::print_table(&overview_table);
+                        output::print_table(&overview_table);
 
                         for profile in &profiles {
-                            println!("\nProfile: {}", profile.name.as_deref().unwrap_or("N/A"));
+                            outln!("\nProfile: {}", profile.name.as_deref().unwrap_or("N/A"));
                             
                             if profile.enable_radio_type_a.unwrap_or(false) {
-                                println!("\n5 GHz Radio Properties:");
+                                outln!("\n5 GHz Radio Properties:");
                                 let mut radio_a_table = table!([FY => 
                                     "Parent Profile", "Channels", "Power Range", "Power Threshold",
                                     "RX SOP", "Data Rates", "Mandatory Rates"
@@ -90,11 +100,11 @@ pub fn handle_ap_command(subcommand: ApCommands) {
                                                                         props.mandatory_data_rates.as_deref().unwrap_or("N/A")
                                                                     ]);
                                                                 }
-                                radio_a_table.printstd();
+                                output::print_table(&radio_a_table);
                             }
 
                             if profile.enable_radio_type_b.unwrap_or(false) {
-                                println!("\n2.4 GHz Radio Properties:");
+                                outln!("\n2.4 GHz Radio Properties:");
                                 let mut radio_b_table = table!([FY => 
                                     "Parent Profile", "Channels", "Power Range", "Power Threshold",
                                     "RX SOP", "Data Rates", "Mandatory Rates"
@@ -114,11 +124,11 @@ pub fn handle_ap_command(subcommand: ApCommands) {
                                         props.mandatory_data_rates.as_deref().unwrap_or("N/A")
                                     ]);
                                 }
-                                radio_b_table.printstd();
+                                output::print_table(&radio_b_table);
                             }
 
                             if profile.enable_radio_type_c.unwrap_or(false) {
-                                println!("\n6 GHz Radio Properties:");
+                                outln!("\n6 GHz Radio Properties:");
                                 let mut radio_c_table = table!([FY => 
                                     "Parent Profile", "Channels", "Power Range", "Power Threshold",
                                     "RX SOP", "Data Rates", "Mandatory Rates"
@@ -138,9 +148,9 @@ pub fn handle_ap_command(subcommand: ApCommands) {
                                         props.mandatory_data_rates.as_deref().unwrap_or("N/A")
                                     ]);
                                 }
-                                radio_c_table.printstd();
+                                output::print_table(&radio_c_table);
                             }
-                            println!("\n");
+                            outln!("\n");
                         }
                     }
                     Err(e) => {
@@ -1,9 +1,9 @@
 // src/handlers/show/client.rs
 
 use crate::commands::show::client::ClientCommands;
-use crate::app::config;
+use crate::app::{cache, config};
 use crate::api::authentication::auth;
-use crate::api::clients::{getclientdetail, getclientenrichment};
+use crate::api::clients::getclientenrichment;
 use crate::helpers::utils;
 use log::error;
 
@@ -20,21 +20,12 @@ pub fn handle_client_command(subcommand: ClientCommands) {
             }
         };
 
-        // Authenticate and get token
-        let token = match auth::authenticate(&config).await {
-            Ok(t) => t,
-            Err(e) => {
-                error!("Authentication failed: {}", e);
-                return;
-            }
-        };
-
         match subcommand {
-            ClientCommands::Detail { mac_address } => {
-                // Fetch client details
-                match getclientdetail::get_client_detail(&config, &token, &mac_address).await {
+            ClientCommands::Detail { mac_address, table, cache: cache_args } => {
+                // Fetch client details, from the local cache where allowed
+                match cache::get_client_detail(&config, &mac_address, cache_args.mode()).await {
                     Ok(client_detail_response) => {
-                        utils::print_client_detail(client_detail_response);
+                        utils::print_client_detail(client_detail_response, &table);
                     }
                     Err(e) => {
                         error!("Failed to retrieve client details: {}", e);
@@ -46,6 +37,15 @@ pub fn handle_client_command(subcommand: ClientCommands) {
                 entity_value,
                 issue_category,
             } => {
+                // Authenticate and get token
+                let token = match auth::authenticate(&config).await {
+                    Ok(t) => t,
+                    Err(e) => {
+                        error!("Authentication failed: {}", e);
+                        return;
+                    }
+                };
+
                 // Fetch client enrichment details
                 match getclientenrichment::get_client_enrichment(
                     &config,
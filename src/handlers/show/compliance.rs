@@ -0,0 +1,238 @@
+// src/handlers/show/compliance.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::compliance::getcompliance::{self, ComplianceDetail};
+use crate::api::devices::getdevicehealth;
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::app::cache;
+use crate::app::config::{self, Config};
+use crate::commands::show::compliance::{ComplianceArgs, ComplianceCommands};
+use crate::helpers::{completion, devicefilter, output};
+use chrono::DateTime;
+use log::error;
+use prettytable::{format, row, Table};
+use std::collections::{BTreeMap, HashMap};
+
+pub fn handle_compliance_command(args: ComplianceArgs) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let devices = match cache::get_devices(&config, args.cache.mode()).await {
+            Ok(devices) => devices,
+            Err(e) => {
+                error!("Failed to fetch devices: {}", e);
+                return;
+            }
+        };
+        completion::record_devices(&devices);
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        match &args.subcommand {
+            Some(ComplianceCommands::Detail { device }) => {
+                show_detail(&config, &token, &devices, device).await
+            }
+            None => show_summary(&config, &token, &devices, &args).await,
+        }
+    });
+}
+
+/// Compliant and non-compliant counts per type, then the status of each device.
+async fn show_summary(config: &Config, token: &Token, devices: &[AllDevices], args: &ComplianceArgs) {
+    let compliance_type = args.compliance_type.map(|compliance_type| compliance_type.api_name());
+    let mut details = match getcompliance::get_all_compliance(config, token, compliance_type.as_deref()).await {
+        Ok(details) => details,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    if let Some(site) = &args.site {
+        match getdevicehealth::get_device_sites(config, token).await {
+            Ok(sites) => details.retain(|detail| {
+                let hierarchy = detail.device_uuid.as_ref().and_then(|id| sites.get(id));
+                hierarchy.is_some_and(|hierarchy| devicefilter::in_site(hierarchy, site))
+            }),
+            Err(e) => {
+                error!("Failed to retrieve device sites: {}", e);
+                return;
+            }
+        }
+    }
+
+    if details.is_empty() {
+        outln!("No compliance results found matching the specified criteria.");
+        return;
+    }
+
+    let mut by_type: BTreeMap<String, (usize, usize, usize)> = BTreeMap::new();
+    for detail in &details {
+        let counts = by_type
+            .entry(detail.compliance_type.clone().unwrap_or_else(|| "UNKNOWN".to_string()))
+            .or_default();
+        match detail.status.as_deref() {
+            Some("COMPLIANT") => counts.0 += 1,
+            Some("NON_COMPLIANT") => counts.1 += 1,
+            _ => counts.2 += 1,
+        }
+    }
+
+    let mut table = Table::new();
+    table.add_row(row!["Type", "Compliant", "Non-Compliant", "Other"]);
+    for (compliance_type, (compliant, non_compliant, other)) in by_type {
+        table.add_row(row![compliance_type, compliant, non_compliant, other]);
+    }
+    output::print_table(&table);
+
+    let by_id: HashMap<&str, &AllDevices> = devices
+        .iter()
+        .filter_map(|device| Some((device.id.as_deref()?, device)))
+        .collect();
+    let mut by_device: BTreeMap<String, (Option<&AllDevices>, Vec<&ComplianceDetail>)> = BTreeMap::new();
+    for detail in &details {
+        let device = detail.device_uuid.as_deref().and_then(|id| by_id.get(id).copied());
+        let name = device
+            .and_then(|device| device.hostname.clone())
+            .or_else(|| detail.device_uuid.clone())
+            .unwrap_or_else(|| "Unknown".to_string());
+        by_device.entry(name).or_insert((device, Vec::new())).1.push(detail);
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Device", "IP", "Status", "Non-Compliant", "Last Sync"]);
+    let mut non_compliant_devices = 0;
+    for (name, (device, details)) in &by_device {
+        let status = details
+            .iter()
+            .filter_map(|detail| detail.status.as_deref())
+            .max_by_key(|status| severity(status))
+            .unwrap_or("UNKNOWN");
+        if status == "NON_COMPLIANT" {
+            non_compliant_devices += 1;
+        }
+        let failing: Vec<&str> = details
+            .iter()
+            .filter(|detail| detail.status.as_deref() == Some("NON_COMPLIANT"))
+            .filter_map(|detail| detail.compliance_type.as_deref())
+            .collect();
+        let last_sync = details.iter().filter_map(|detail| detail.last_sync_time).max();
+
+        table.add_row(row![
+            name,
+            device
+                .and_then(|device| device.management_ip_address.as_deref())
+                .unwrap_or("N/A"),
+            status,
+            failing.join(", "),
+            format_time(last_sync)
+        ]);
+    }
+    outln!();
+    output::print_table(&table);
+    outln!("{} of {} devices non-compliant", non_compliant_devices, by_device.len());
+}
+
+async fn show_detail(config: &Config, token: &Token, devices: &[AllDevices], name: &str) {
+    let device_id = match devicefilter::find_device(devices, name) {
+        Ok(device) => device.id.clone().unwrap_or_default(),
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    let details = match getcompliance::get_device_compliance(config, token, &device_id).await {
+        Ok(details) => details,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+    if details.is_empty() {
+        outln!("No compliance results found for {}.", name);
+        return;
+    }
+
+    let mut table = Table::new();
+    table.add_row(row!["Type", "Status", "State", "Last Sync", "Remediation"]);
+    for detail in &details {
+        table.add_row(row![
+            detail.compliance_type.as_deref().unwrap_or("N/A"),
+            detail.status.as_deref().unwrap_or("N/A"),
+            detail.state.as_deref().unwrap_or("N/A"),
+            format_time(detail.last_sync_time),
+            match detail.remediation_supported {
+                Some(true) => "Supported",
+                _ => "-",
+            }
+        ]);
+    }
+    output::print_table(&table);
+
+    // What each failing type found, e.g. config lines that differ from intent
+    for detail in details
+        .iter()
+        .filter(|detail| detail.status.as_deref() == Some("NON_COMPLIANT"))
+    {
+        outln!(
+            "\n{}:",
+            detail
+                .display_name
+                .as_deref()
+                .or(detail.compliance_type.as_deref())
+                .unwrap_or("Unknown")
+        );
+        for source in &detail.source_info_list {
+            outln!(
+                "  {}",
+                source
+                    .display_name
+                    .as_deref()
+                    .or(source.name.as_deref())
+                    .unwrap_or("Unknown")
+            );
+            for diff in &source.diff_list {
+                outln!(
+                    "    {} {}: configured '{}', intended '{}'",
+                    diff.op.as_deref().unwrap_or("-"),
+                    diff.display_name.as_deref().or(diff.path.as_deref()).unwrap_or(""),
+                    diff.configured_value.as_deref().unwrap_or(""),
+                    diff.intended_value.as_deref().unwrap_or("")
+                );
+            }
+        }
+    }
+}
+
+/// Rank statuses so a device shows its worst one.
+fn severity(status: &str) -> u8 {
+    match status {
+        "NON_COMPLIANT" => 4,
+        "ERROR" => 3,
+        "IN_PROGRESS" => 2,
+        "COMPLIANT" => 0,
+        _ => 1,
+    }
+}
+
+fn format_time(millis: Option<i64>) -> String {
+    millis
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "N/A".to_string())
+}
@@ -1,13 +1,20 @@
 // src/handlers/show/device.rs
 
 use log::error;
-use crate::app::config;
-use crate::helpers::utils;
-use crate::api::authentication::auth;
-use crate::api::devices::{devicedetailenrichment, getdevicelist};
+use crate::app::{cache, config};
+use crate::helpers::concurrency::LimitedJoinSet;
+use crate::helpers::{completion, devicefilter, output, utils};
+use crate::api::authentication::auth::{self, Token};
+use crate::api::devices::{devicedetailenrichment, getdeviceconfig, getdevicehealth};
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::tags::gettags;
 use crate::commands::show::device::{
-    DeviceCommands, DeviceDetailFilter, DeviceEnrichmentFilter, DeviceListFilter,
+    DeviceCommands, DeviceConfigArgs, DeviceDetailFilter, DeviceEnrichmentFilter, DeviceListFilter,
 };
+use chrono::DateTime;
+use prettytable::{row, Table};
+use std::collections::HashSet;
+use std::path::Path;
 
 pub fn handle_device_command(subcommand: DeviceCommands) {
     // Create a Tokio runtime
@@ -21,23 +28,18 @@ pub fn handle_device_command(subcommand: DeviceCommands) {
             }
         };
 
-        let token = match auth::authenticate(&config).await {
-            Ok(t) => t,
-            Err(e) => {
-                error!("Authentication failed: {}", e);
-                return;
-            }
-        };
-
         match subcommand {
-            DeviceCommands::List { filter } => {
-                // Fetch all devices
-                match getdevicelist::get_all_devices(&config, &token).await {
+            DeviceCommands::List { cache: cache_args, tag, filter } => {
+                let table = filter.table().clone();
+                // Fetch all devices, from the local cache where allowed
+                match cache::get_devices(&config, cache_args.mode()).await {
                     Ok(devices) => {
+                        completion::record_devices(&devices);
+
                         // Apply filter if necessary
                         let filtered_devices = match filter {
-                            DeviceListFilter::All => devices,
-                            DeviceListFilter::Hostname { partial_hostname } => {
+                            DeviceListFilter::All { .. } => devices,
+                            DeviceListFilter::Hostname { partial_hostname, .. } => {
                                 devices
                                     .into_iter()
                                     .filter(|device| {
@@ -53,7 +55,7 @@ pub fn handle_device_command(subcommand: DeviceCommands) {
                                     })
                                     .collect()
                             }
-                            DeviceListFilter::Ip { partial_ip } => {
+                            DeviceListFilter::Ip { partial_ip, .. } => {
                                 devices
                                     .into_iter()
                                     .filter(|device| {
@@ -69,7 +71,7 @@ pub fn handle_device_command(subcommand: DeviceCommands) {
                                     })
                                     .collect()
                             }
-                            DeviceListFilter::Wlc { partial_wlc } => {
+                            DeviceListFilter::Wlc { partial_wlc, .. } => {
                                 devices
                                     .into_iter()
                                     .filter(|device| {
@@ -87,15 +89,28 @@ pub fn handle_device_command(subcommand: DeviceCommands) {
                             }
                         };
 
-                        utils::print_devices(filtered_devices);
+                        let filtered_devices = match tag {
+                            Some(tag) => match retain_tagged(&config, filtered_devices, &tag).await {
+                                Ok(devices) => devices,
+                                Err(e) => {
+                                    error!("{}", e);
+                                    return;
+                                }
+                            },
+                            None => filtered_devices,
+                        };
+
+                        utils::print_devices(filtered_devices, &table);
                     }
                     Err(e) => error!("Failed to retrieve devices: {}", e),
                 }
             }
-            DeviceCommands::Detail { filter } => {
-                // Fetch all devices
-                match getdevicelist::get_all_devices(&config, &token).await {
+            DeviceCommands::Detail { cache: cache_args, filter } => {
+                // Fetch all devices, from the local cache where allowed
+                match cache::get_devices(&config, cache_args.mode()).await {
                     Ok(devices) => {
+                        completion::record_devices(&devices);
+
                         // Find the device matching the filter
                         let device_option = match filter {
                             DeviceDetailFilter::Hostname { ref hostname } => devices
@@ -113,13 +128,21 @@ pub fn handle_device_command(subcommand: DeviceCommands) {
 
                         match device_option {
                             Some(device) => utils::print_device_detail(device),
-                            None => println!("No device found matching the specified criteria."),
+                            None => outln!("No device found matching the specified criteria."),
                         }
                     }
                     Err(e) => error!("Failed to retrieve devices: {}", e),
                 }
             }
             DeviceCommands::Enrichment { filter } => {
+                let token = match auth::authenticate(&config).await {
+                    Ok(t) => t,
+                    Err(e) => {
+                        error!("Authentication failed: {}", e);
+                        return;
+                    }
+                };
+
                 // Handle the Enrichment command
                 match filter {
                     DeviceEnrichmentFilter::Mac { mac_address } => {
@@ -154,6 +177,159 @@ pub fn handle_device_command(subcommand: DeviceCommands) {
                     }
                 }
             }
+            DeviceCommands::Config { args } => {
+                let devices = match cache::get_devices(&config, args.cache.mode()).await {
+                    Ok(devices) => devices,
+                    Err(e) => {
+                        error!("Failed to retrieve devices: {}", e);
+                        return;
+                    }
+                };
+                completion::record_devices(&devices);
+
+                let token = match auth::authenticate(&config).await {
+                    Ok(t) => t,
+                    Err(e) => {
+                        error!("Authentication failed: {}", e);
+                        return;
+                    }
+                };
+
+                match &args.save {
+                    Some(dir) => save_configs(&config, &token, &devices, &args, dir).await,
+                    None => show_config(&config, &token, &devices, &args).await,
+                }
+            }
         }
     });
 }
+
+async fn show_config(config: &config::Config, token: &Token, devices: &[AllDevices], args: &DeviceConfigArgs) {
+    let name = args.device.as_deref().unwrap_or_default();
+    let device_id = match devicefilter::find_device(devices, name) {
+        Ok(device) => device.id.clone().unwrap_or_default(),
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    if args.versions {
+        match getdeviceconfig::get_config_archive(config, token, &device_id).await {
+            Ok(versions) if versions.is_empty() => outln!("No archived configs found for {}.", name),
+            Ok(versions) => {
+                let mut table = Table::new();
+                table.add_row(row!["Version", "Created", "Created By", "Startup/Running"]);
+                for version in versions {
+                    let created = version
+                        .created_time
+                        .and_then(DateTime::from_timestamp_millis)
+                        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_else(|| "N/A".to_string());
+                    table.add_row(row![
+                        version.id,
+                        created,
+                        version.created_by.unwrap_or_else(|| "N/A".to_string()),
+                        version.startup_running_status.unwrap_or_else(|| "N/A".to_string())
+                    ]);
+                }
+                output::print_table(&table);
+            }
+            Err(e) => error!("{}", e),
+        }
+        return;
+    }
+
+    match getdeviceconfig::fetch_config(config, token, &device_id, args.version.as_deref()).await {
+        Ok(running_config) => {
+            for line in running_config.lines() {
+                outln!("{}", line);
+            }
+        }
+        Err(e) => error!("{}", e),
+    }
+}
+
+/// Back up the running config of every matching device to `<dir>/<hostname>.cfg`.
+async fn save_configs(
+    config: &config::Config,
+    token: &Token,
+    devices: &[AllDevices],
+    args: &DeviceConfigArgs,
+    dir: &Path,
+) {
+    let pattern = args.device.as_deref().unwrap_or("*");
+    let mut selected = devicefilter::select_devices(devices, pattern);
+
+    if let Some(site) = &args.site {
+        match getdevicehealth::get_device_sites(config, token).await {
+            Ok(sites) => devicefilter::retain_site(&mut selected, &sites, site),
+            Err(e) => {
+                error!("Failed to retrieve device sites: {}", e);
+                return;
+            }
+        }
+    }
+
+    if selected.is_empty() {
+        outln!("No devices match '{}'.", pattern);
+        return;
+    }
+    if let Err(e) = std::fs::create_dir_all(dir) {
+        error!("Failed to create {}: {}", dir.display(), e);
+        return;
+    }
+
+    let mut tasks = LimitedJoinSet::new();
+    for device in &selected {
+        let id = device.id.clone().unwrap_or_default();
+        let hostname = device.hostname.clone().unwrap_or_default();
+        let (config, token) = (config.clone(), token.clone());
+        tasks.spawn(async move {
+            let result = getdeviceconfig::get_running_config(&config, &token, &id).await;
+            (hostname, result)
+        });
+    }
+
+    let mut saved = 0;
+    while let Some(joined) = tasks.join_next().await {
+        let (hostname, result) = match joined {
+            Ok(joined) => joined,
+            Err(e) => {
+                error!("Config download failed: {}", e);
+                continue;
+            }
+        };
+        let running_config = match result {
+            Ok(running_config) => running_config,
+            Err(e) => {
+                error!("Failed to retrieve config of {}: {}", hostname, e);
+                continue;
+            }
+        };
+
+        let path = dir.join(format!("{}.cfg", hostname.replace(['/', '\\'], "_")));
+        match std::fs::write(&path, running_config) {
+            Ok(()) => {
+                saved += 1;
+                outln!("Saved {}", path.display());
+            }
+            Err(e) => error!("Failed to write {}: {}", path.display(), e),
+        }
+    }
+    outln!("Saved {} of {} configs to {}", saved, selected.len(), dir.display());
+}
+
+/// The devices carrying `tag`, whether added by hand or matched by its rules.
+async fn retain_tagged(config: &config::Config, mut devices: Vec<AllDevices>, tag: &str) -> anyhow::Result<Vec<AllDevices>> {
+    let token = auth::authenticate(config).await?;
+    let tag = gettags::get_tag_by_name(config, &token, tag).await?;
+    let tag_id = tag.id.as_deref().unwrap_or_default();
+    let members: HashSet<String> = gettags::get_tag_member_ids(config, &token, tag_id, gettags::DEVICE_MEMBER)
+        .await?
+        .into_iter()
+        .collect();
+
+    devices.retain(|device| device.id.as_ref().is_some_and(|id| members.contains(id)));
+    Ok(devices)
+}
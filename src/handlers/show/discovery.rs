@@ -0,0 +1,137 @@
+// src/handlers/show/discovery.rs
+
+use crate::api::authentication::auth;
+use crate::api::discovery::getdiscovery::{self, DiscoveredDevice, Discovery};
+use crate::app::config;
+use crate::commands::show::discovery::DiscoveryCommands;
+use crate::helpers::output;
+use anyhow::{anyhow, Result};
+use log::error;
+use prettytable::{format, row, Table};
+
+pub fn handle_discovery_command(subcommand: DiscoveryCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let discoveries = match getdiscovery::get_discoveries(&config, &token).await {
+            Ok(discoveries) => discoveries,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            DiscoveryCommands::List => {
+                if discoveries.is_empty() {
+                    outln!("No discoveries found.");
+                    return;
+                }
+
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["Id", "Name", "Type", "Ranges", "Condition", "Devices"]);
+                for discovery in &discoveries {
+                    table.add_row(row![
+                        discovery.id.as_deref().unwrap_or("N/A"),
+                        discovery.name.as_deref().unwrap_or("N/A"),
+                        discovery.discovery_type.as_deref().unwrap_or("N/A"),
+                        discovery.ip_address_list.as_deref().unwrap_or("N/A"),
+                        discovery.discovery_condition.as_deref().unwrap_or("N/A"),
+                        discovery.num_devices.unwrap_or(0)
+                    ]);
+                }
+                output::print_table(&table);
+                outln!("{} discoveries", discoveries.len());
+            }
+            DiscoveryCommands::Detail { discovery } => {
+                let found = match find_discovery(&discoveries, &discovery) {
+                    Ok(found) => found,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                let discovery_id = found.id.as_deref().unwrap_or_default();
+                match getdiscovery::get_discovered_devices(&config, &token, discovery_id).await {
+                    Ok(devices) => print_discovery(found, &devices),
+                    Err(e) => error!("{}", e),
+                }
+            }
+        }
+    });
+}
+
+/// Find a discovery by id or (case-insensitive) name.
+fn find_discovery<'a>(discoveries: &'a [Discovery], query: &str) -> Result<&'a Discovery> {
+    discoveries
+        .iter()
+        .find(|discovery| discovery.id.as_deref() == Some(query))
+        .or_else(|| {
+            discoveries.iter().find(|discovery| {
+                discovery
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(query))
+            })
+        })
+        .ok_or_else(|| anyhow!("No discovery with id or name '{}'", query))
+}
+
+/// A discovery's summary followed by each device it found.
+pub fn print_discovery(discovery: &Discovery, devices: &[DiscoveredDevice]) {
+    let mut table = Table::new();
+    table.add_row(row!["Id", discovery.id.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Name", discovery.name.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Type", discovery.discovery_type.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Ranges", discovery.ip_address_list.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Protocols", discovery.protocol_order.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Status", discovery.discovery_status.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Condition", discovery.discovery_condition.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Devices", discovery.num_devices.unwrap_or(0)]);
+    output::print_table(&table);
+
+    if devices.is_empty() {
+        outln!("\nNo devices found.");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Hostname", "IP", "Platform", "Reachability", "CLI", "SNMP", "NETCONF", "Inventory"]);
+    for device in devices {
+        table.add_row(row![
+            device.hostname.as_deref().unwrap_or("-"),
+            device.management_ip_address.as_deref().unwrap_or("N/A"),
+            device.platform_id.as_deref().unwrap_or("-"),
+            device.reachability_status.as_deref().unwrap_or("N/A"),
+            device.cli_status.as_deref().unwrap_or("-"),
+            device.snmp_status.as_deref().unwrap_or("-"),
+            device.netconf_status.as_deref().unwrap_or("-"),
+            device.inventory_collection_status.as_deref().unwrap_or("-")
+        ]);
+    }
+    outln!("\nDiscovered devices:");
+    output::print_table(&table);
+
+    for device in devices {
+        if let Some(reason) = device.reachability_failure_reason.as_deref().filter(|reason| !reason.is_empty()) {
+            outln!("{}: {}", device.management_ip_address.as_deref().unwrap_or("N/A"), reason);
+        }
+    }
+}
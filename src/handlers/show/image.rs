@@ -0,0 +1,204 @@
+// src/handlers/show/image.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::devices::getdevicehealth;
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::images::getimages::{self, SoftwareImage};
+use crate::app::cache::{self, CacheMode};
+use crate::app::config::{self, Config};
+use crate::commands::show::image::ImageCommands;
+use crate::helpers::swim::{self, ReadinessStatus};
+use crate::helpers::{completion, devicefilter, output};
+use anyhow::Result;
+use log::error;
+use prettytable::{format, row, Table};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+pub fn handle_image_command(subcommand: ImageCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let golden = match getimages::get_images(&config, &token, true).await {
+            Ok(images) => images,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            ImageCommands::Golden => print_golden(&golden),
+            ImageCommands::Readiness { device, site, cache: cache_args } => {
+                let pattern = device.as_deref().unwrap_or("*");
+                match load_devices(&config, &token, cache_args.mode(), site.as_deref()).await {
+                    Ok(devices) => {
+                        print_readiness(&devicefilter::select_devices(&devices, pattern), &golden)
+                    }
+                    Err(e) => error!("{}", e),
+                }
+            }
+            ImageCommands::Summary { site, cache: cache_args } => {
+                match load_devices(&config, &token, cache_args.mode(), site.as_deref()).await {
+                    Ok(devices) => print_summary(&devicefilter::select_devices(&devices, "*"), &golden),
+                    Err(e) => error!("{}", e),
+                }
+            }
+        }
+    });
+}
+
+/// Inventory, limited to `site` when given.
+pub async fn load_devices(
+    config: &Config,
+    token: &Token,
+    mode: CacheMode,
+    site: Option<&str>,
+) -> Result<Vec<AllDevices>> {
+    let mut devices = cache::get_devices(config, mode).await?;
+    completion::record_devices(&devices);
+
+    if let Some(site) = site {
+        let sites = getdevicehealth::get_device_sites(config, token).await?;
+        devices.retain(|device| {
+            let hierarchy = device.id.as_ref().and_then(|id| sites.get(id));
+            hierarchy.is_some_and(|hierarchy| devicefilter::in_site(hierarchy, site))
+        });
+    }
+    Ok(devices)
+}
+
+/// Leaf of a series path such as `Switches and Hubs/Cisco Catalyst 9300 Series Switches`.
+fn series_name(series: &str) -> &str {
+    series.rsplit('/').next().unwrap_or(series)
+}
+
+fn print_golden(golden: &[SoftwareImage]) {
+    if golden.is_empty() {
+        outln!("No images are tagged golden.");
+        return;
+    }
+
+    let mut images: Vec<&SoftwareImage> = golden.iter().collect();
+    images.sort_by(|a, b| (&a.family, &a.name).cmp(&(&b.family, &b.name)));
+
+    let mut table = Table::new();
+    table.add_row(row!["Family", "Image", "Version", "Platforms"]);
+    for image in images {
+        let platforms: Vec<&str> = image.image_series.iter().map(|series| series_name(series)).collect();
+        table.add_row(row![
+            image.family.as_deref().unwrap_or("N/A"),
+            image.name.as_deref().unwrap_or("N/A"),
+            image.version(),
+            platforms.join("\n")
+        ]);
+    }
+    output::print_table(&table);
+}
+
+fn print_readiness(devices: &[&AllDevices], golden: &[SoftwareImage]) {
+    if devices.is_empty() {
+        outln!("No devices found matching the specified criteria.");
+        return;
+    }
+
+    let mut readiness: Vec<swim::Readiness> = devices
+        .iter()
+        .map(|device| swim::check_readiness(device, golden))
+        .collect();
+    readiness.sort_by(|a, b| a.hostname().cmp(b.hostname()));
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Device", "IP", "Platform", "Current", "Golden", "Status", "Blocking Checks"]);
+    for entry in &readiness {
+        table.add_row(row![
+            entry.hostname(),
+            entry.device.management_ip_address.as_deref().unwrap_or("N/A"),
+            entry.device.platform_id.as_deref().unwrap_or("N/A"),
+            entry.current_version(),
+            entry.golden.map(|image| image.version()).unwrap_or("-"),
+            entry.status.as_str(),
+            entry.failed_checks.join(", ")
+        ]);
+    }
+    output::print_table(&table);
+
+    let count = |status: ReadinessStatus| readiness.iter().filter(|entry| entry.status == status).count();
+    outln!(
+        "{} ready, {} not ready, {} up to date, {} without a golden image",
+        count(ReadinessStatus::Ready),
+        count(ReadinessStatus::NotReady),
+        count(ReadinessStatus::UpToDate),
+        count(ReadinessStatus::NoGolden)
+    );
+}
+
+/// A platform and the software version its devices run, if reported.
+type PlatformVersion = (String, Option<String>);
+
+/// Device counts per platform and version, flagging versions below golden.
+fn print_summary(devices: &[&AllDevices], golden: &[SoftwareImage]) {
+    if devices.is_empty() {
+        outln!("No devices found matching the specified criteria.");
+        return;
+    }
+
+    let mut groups: BTreeMap<PlatformVersion, (Option<&SoftwareImage>, usize)> = BTreeMap::new();
+    for device in devices {
+        let platform = device
+            .series
+            .as_deref()
+            .or(device.platform_id.as_deref())
+            .unwrap_or("Unknown")
+            .to_string();
+        let entry = groups
+            .entry((platform, device.software_version.clone()))
+            .or_insert((swim::golden_for(device, golden), 0));
+        entry.1 += 1;
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Platform", "Version", "Golden", "Devices", "Status"]);
+    let mut below = 0;
+    for ((platform, version), (image, count)) in &groups {
+        // A device that reported no version can't be said to be below golden
+        let status = match (image, version) {
+            (None, _) => "No golden image",
+            (Some(_), None) => "Unknown version",
+            (Some(image), Some(version)) => match swim::compare_versions(version, image.version()) {
+                Ordering::Less => {
+                    below += count;
+                    "Below golden"
+                }
+                Ordering::Equal => "Golden",
+                Ordering::Greater => "Above golden",
+            },
+        };
+        table.add_row(row![
+            platform,
+            version.as_deref().unwrap_or("N/A"),
+            image.map(|image| image.version()).unwrap_or("-"),
+            count,
+            status
+        ]);
+    }
+    output::print_table(&table);
+    outln!("{} of {} devices below golden", below, devices.len());
+}
@@ -0,0 +1,268 @@
+// src/handlers/show/interface.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::devices::getdevicehealth;
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::interfaces::getinterfaces::{self, Interface};
+use crate::app::cache;
+use crate::app::config::{self, Config};
+use crate::commands::show::interface::{InterfaceArgs, InterfaceCommands, PortMode, PortStatus};
+use crate::commands::show::table::TableArgs;
+use crate::helpers::concurrency::LimitedJoinSet;
+use crate::helpers::{completion, devicefilter, fields, output};
+use anyhow::Result;
+use log::error;
+use prettytable::{format, row, Table};
+
+pub fn handle_interface_command(args: InterfaceArgs) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let devices = match cache::get_devices(&config, args.cache.mode()).await {
+            Ok(devices) => devices,
+            Err(e) => {
+                error!("Failed to fetch devices: {}", e);
+                return;
+            }
+        };
+        completion::record_devices(&devices);
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        match &args.subcommand {
+            Some(InterfaceCommands::Detail { device, ifname }) => {
+                show_detail(&config, &token, &devices, device, ifname).await
+            }
+            None => match (&args.device, &args.site) {
+                (Some(device), _) => show_device(&config, &token, &devices, device, &args).await,
+                (None, Some(site)) => show_site(&config, &token, &devices, site, &args).await,
+                (None, None) => error!("Specify a device or --site"),
+            },
+        }
+    });
+}
+
+async fn show_device(
+    config: &Config,
+    token: &Token,
+    devices: &[AllDevices],
+    name: &str,
+    args: &InterfaceArgs,
+) {
+    let device = match devicefilter::find_device(devices, name) {
+        Ok(device) => device,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    match fetch_interfaces(config, token, device).await {
+        Ok(interfaces) => print_interfaces(filter(interfaces, args), &args.table),
+        Err(e) => error!("Failed to retrieve interfaces: {}", e),
+    }
+}
+
+/// Interfaces of every non-AP device at a site, e.g. all down access ports.
+async fn show_site(
+    config: &Config,
+    token: &Token,
+    devices: &[AllDevices],
+    site: &str,
+    args: &InterfaceArgs,
+) {
+    let sites = match getdevicehealth::get_device_sites(config, token).await {
+        Ok(sites) => sites,
+        Err(e) => {
+            error!("Failed to retrieve device sites: {}", e);
+            return;
+        }
+    };
+
+    let at_site: Vec<&AllDevices> = devices
+        .iter()
+        .filter(|device| device.family.as_deref() != Some("Unified AP"))
+        .filter(|device| {
+            let hierarchy = device.id.as_ref().and_then(|id| sites.get(id));
+            hierarchy.is_some_and(|hierarchy| devicefilter::in_site(hierarchy, site))
+        })
+        .collect();
+
+    if at_site.is_empty() {
+        outln!("No devices found at site {}.", site);
+        return;
+    }
+
+    let mut tasks = LimitedJoinSet::new();
+    for device in at_site {
+        let (id, hostname) = device_ref(device);
+        let (config, token) = (config.clone(), token.clone());
+        tasks.spawn(async move {
+            let result = fetch_by_id(&config, &token, &id, &hostname).await;
+            (hostname, result)
+        });
+    }
+
+    let mut interfaces = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((_, Ok(device_interfaces))) => interfaces.extend(device_interfaces),
+            Ok((hostname, Err(e))) => error!("Failed to retrieve interfaces of {}: {}", hostname, e),
+            Err(e) => error!("Interface lookup failed: {}", e),
+        }
+    }
+    interfaces.sort_by(|a, b| (&a.device_name, &a.port_name).cmp(&(&b.device_name, &b.port_name)));
+
+    // Fleet-wide listings need to say which device each port is on
+    let mut view = args.table.clone();
+    if view.columns.is_none() && !view.wide {
+        view.columns = Some(
+            ["device", "port", "status", "admin", "vlan", "mode", "description"]
+                .map(String::from)
+                .to_vec(),
+        );
+    }
+    print_interfaces(filter(interfaces, args), &view);
+}
+
+async fn show_detail(config: &Config, token: &Token, devices: &[AllDevices], name: &str, ifname: &str) {
+    let device = match devicefilter::find_device(devices, name) {
+        Ok(device) => device,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    let interfaces = match fetch_interfaces(config, token, device).await {
+        Ok(interfaces) => interfaces,
+        Err(e) => {
+            error!("Failed to retrieve interfaces: {}", e);
+            return;
+        }
+    };
+    let interface = match interfaces.into_iter().find(|interface| {
+        interface
+            .port_name
+            .as_deref()
+            .is_some_and(|port| devicefilter::interface_matches(port, ifname))
+    }) {
+        Some(interface) => interface,
+        None => {
+            error!("No interface {} found on {}", ifname, name);
+            return;
+        }
+    };
+
+    let view = TableArgs {
+        wide: true,
+        ..TableArgs::default()
+    };
+    match fields::build_detail_table(&interface, &view) {
+        Ok(table) => output::print_table(&table),
+        Err(e) => error!("{}", e),
+    }
+
+    // Error counters come from Assurance and are not available on every release
+    let stats = match (&device.id, &interface.port_name) {
+        (Some(id), Some(port)) => getinterfaces::get_interface_stats(config, token, id, port).await,
+        _ => Ok(None),
+    };
+    match stats {
+        Ok(Some(stats)) => {
+            let count = |value: Option<i64>| value.map(|v| v.to_string()).unwrap_or_else(|| "N/A".to_string());
+            let mut table = Table::new();
+            table.add_row(row!["Counter", "Value"]);
+            table.add_row(row!["RX Errors", count(stats.rx_errors)]);
+            table.add_row(row!["TX Errors", count(stats.tx_errors)]);
+            table.add_row(row!["RX Discards", count(stats.rx_discards)]);
+            table.add_row(row!["TX Discards", count(stats.tx_discards)]);
+            table.add_row(row!["CRC Errors", count(stats.crc_errors)]);
+            outln!("\nError Counters:");
+            output::print_table(&table);
+        }
+        Ok(None) => outln!("\nNo error counters available for this interface."),
+        Err(e) => outln!("\nError counters unavailable: {}", e),
+    }
+}
+
+fn device_ref(device: &AllDevices) -> (String, String) {
+    (
+        device.id.clone().unwrap_or_default(),
+        device.hostname.clone().unwrap_or_else(|| "Unknown".to_string()),
+    )
+}
+
+async fn fetch_interfaces(config: &Config, token: &Token, device: &AllDevices) -> Result<Vec<Interface>> {
+    let (id, hostname) = device_ref(device);
+    fetch_by_id(config, token, &id, &hostname).await
+}
+
+/// Interfaces of a device with their PoE status attached where the device has PoE.
+async fn fetch_by_id(config: &Config, token: &Token, id: &str, hostname: &str) -> Result<Vec<Interface>> {
+    let mut interfaces = getinterfaces::get_device_interfaces(config, token, id).await?;
+    let poe = getinterfaces::get_device_poe(config, token, id).await.unwrap_or_default();
+
+    for interface in &mut interfaces {
+        interface.device_name = Some(hostname.to_string());
+        interface.poe = poe
+            .iter()
+            .find(|poe| poe.interface_name.is_some() && poe.interface_name == interface.port_name)
+            .cloned();
+    }
+    Ok(interfaces)
+}
+
+fn filter(interfaces: Vec<Interface>, args: &InterfaceArgs) -> Vec<Interface> {
+    let matches = |value: &Option<String>, wanted: &str| {
+        value.as_deref().is_some_and(|value| value.eq_ignore_ascii_case(wanted))
+    };
+
+    interfaces
+        .into_iter()
+        .filter(|interface| match args.status {
+            Some(PortStatus::Up) => matches(&interface.status, "up"),
+            Some(PortStatus::Down) => matches(&interface.status, "down"),
+            None => true,
+        })
+        .filter(|interface| match args.mode {
+            Some(PortMode::Access) => matches(&interface.port_mode, "access"),
+            Some(PortMode::Trunk) => matches(&interface.port_mode, "trunk"),
+            Some(PortMode::Routed) => matches(&interface.port_mode, "routed"),
+            None => true,
+        })
+        .filter(|interface| match &args.vlan {
+            Some(vlan) => matches(&interface.vlan_id, vlan),
+            None => true,
+        })
+        .collect()
+}
+
+fn print_interfaces(interfaces: Vec<Interface>, view: &TableArgs) {
+    if interfaces.is_empty() {
+        outln!("No interfaces found matching the specified criteria.");
+        return;
+    }
+
+    match fields::build_table(&interfaces, view) {
+        Ok(mut table) => {
+            table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+            output::print_table(&table);
+            outln!("{} interfaces", interfaces.len());
+        }
+        Err(e) => error!("{}", e),
+    }
+}
@@ -4,7 +4,7 @@ use crate::commands::show::issue::{IssueCommands, SearchOption};
 use crate::app::config;
 use crate::api::authentication::auth;
 use crate::api::issues::getissuelist;
-use crate::helpers::utils;
+use crate::helpers::{completion, utils};
 use log::error;
 use std::collections::HashMap;
 
@@ -31,7 +31,7 @@ pub fn handle_issue_command(subcommand: IssueCommands) {
         };
 
         match subcommand {
-            IssueCommands::List { search_option, search_input } => {
+            IssueCommands::List { search_option, search_input, table } => {
                 // Prepare search parameters
                 let mut search_params = HashMap::new();
 
@@ -72,13 +72,30 @@ pub fn handle_issue_command(subcommand: IssueCommands) {
                 // Fetch issue list
                 match getissuelist::get_issue_list(&config, &token, &search_params).await {
                     Ok(issue_list_response) => {
-                        utils::print_issue_list(issue_list_response);
+                        if let Some(issues) = &issue_list_response.response {
+                            completion::record_issues(issues);
+                        }
+                        utils::print_issue_list(issue_list_response, &table);
                     }
                     Err(e) => {
                         error!("Failed to retrieve issue list: {}", e);
                     }
                 }
             }
+            IssueCommands::Detail { issue_id } => {
+                let issues = match getissuelist::get_issue_list(&config, &token, &HashMap::new()).await {
+                    Ok(issue_list_response) => issue_list_response.response.unwrap_or_default(),
+                    Err(e) => {
+                        error!("Failed to retrieve issue list: {}", e);
+                        return;
+                    }
+                };
+                completion::record_issues(&issues);
+                match issues.into_iter().find(|issue| issue.issueId.as_deref() == Some(issue_id.as_str())) {
+                    Some(issue) => utils::print_issue_detail(issue),
+                    None => error!("No issue found with ID '{}'", issue_id),
+                }
+            }
         }
     });
 }
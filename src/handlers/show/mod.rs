@@ -2,6 +2,16 @@ pub mod device;
 pub mod client;
 pub mod issue;
 pub mod ap;
+pub mod topology;
+pub mod interface;
+pub mod compliance;
+pub mod image;
+pub mod template;
+pub mod pnp;
+pub mod discovery;
+pub mod tag;
+pub mod ssid;
+pub mod wlc;
 use crate::commands::show::ShowCommands;
 
 pub fn handle_show_command(subcommand: ShowCommands) {
@@ -13,6 +23,26 @@ pub fn handle_show_command(subcommand: ShowCommands) {
         ShowCommands::Issue { subcommand } => issue::handle_issue_command(subcommand),
 
         ShowCommands::Ap { subcommand } => ap::handle_ap_command(subcommand),
+
+        ShowCommands::Interface { args } => interface::handle_interface_command(args),
+
+        ShowCommands::Topology { subcommand } => topology::handle_topology_command(subcommand),
+
+        ShowCommands::Compliance { args } => compliance::handle_compliance_command(args),
+
+        ShowCommands::Image { subcommand } => image::handle_image_command(subcommand),
+
+        ShowCommands::Template { subcommand } => template::handle_template_command(subcommand),
+
+        ShowCommands::Pnp { subcommand } => pnp::handle_pnp_command(subcommand),
+
+        ShowCommands::Discovery { subcommand } => discovery::handle_discovery_command(subcommand),
+
+        ShowCommands::Tag { subcommand } => tag::handle_tag_command(subcommand),
+
+        ShowCommands::Ssid { subcommand } => ssid::handle_ssid_command(subcommand),
+
+        ShowCommands::Wlc { subcommand } => wlc::handle_wlc_command(subcommand),
     }
 }
 
@@ -0,0 +1,72 @@
+// src/handlers/show/pnp.rs
+
+use crate::api::authentication::auth;
+use crate::api::pnp::getpnpdevices;
+use crate::app::config;
+use crate::commands::show::pnp::PnpCommands;
+use crate::helpers::output;
+use chrono::DateTime;
+use log::error;
+use prettytable::{format, row, Table};
+
+pub fn handle_pnp_command(subcommand: PnpCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            PnpCommands::Devices { state } => {
+                let state = state.map(|state| state.api_name());
+                let mut devices = match getpnpdevices::get_pnp_devices(&config, &token, state).await {
+                    Ok(devices) => devices,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                if devices.is_empty() {
+                    outln!("No PnP devices found matching the specified criteria.");
+                    return;
+                }
+                devices.sort_by(|a, b| a.serial().cmp(b.serial()));
+
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["Serial", "Name", "PID", "State", "Onboarding", "Site", "Last Contact"]);
+                for device in &devices {
+                    let info = device.device_info.as_ref();
+                    let last_contact = info
+                        .and_then(|info| info.last_contact)
+                        .and_then(DateTime::from_timestamp_millis)
+                        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_else(|| "Never".to_string());
+                    table.add_row(row![
+                        device.serial(),
+                        info.and_then(|info| info.name.as_deref()).unwrap_or("N/A"),
+                        info.and_then(|info| info.pid.as_deref()).unwrap_or("N/A"),
+                        info.and_then(|info| info.state.as_deref()).unwrap_or("N/A"),
+                        info.and_then(|info| info.onb_state.as_deref()).unwrap_or("N/A"),
+                        info.and_then(|info| info.site_name.as_deref()).unwrap_or("-"),
+                        last_contact
+                    ]);
+                }
+                output::print_table(&table);
+                outln!("{} devices", devices.len());
+            }
+        }
+    });
+}
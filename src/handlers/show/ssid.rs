@@ -0,0 +1,133 @@
+// src/handlers/show/ssid.rs
+
+use crate::api::authentication::auth;
+use crate::api::wireless::getssids::{self, SsidDetails};
+use crate::api::wireless::getwirelessprofiles::{self, ProfileDetails};
+use crate::app::config;
+use crate::commands::show::ssid::SsidCommands;
+use crate::helpers::{output, wireless};
+use log::error;
+use prettytable::{format, row, Table};
+
+pub fn handle_ssid_command(subcommand: SsidCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let fetched = tokio::try_join!(
+            getssids::get_enterprise_ssids(&config, &token),
+            getwirelessprofiles::get_wireless_profiles(&config, &token)
+        );
+        let (mut ssids, profiles) = match fetched {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            SsidCommands::List { site } => {
+                if let Some(site) = &site {
+                    ssids.retain(|ssid| wireless::ssid_at_site(&profiles, ssid.name.as_deref().unwrap_or_default(), site));
+                }
+                if ssids.is_empty() {
+                    outln!("No SSIDs found matching the specified criteria.");
+                    return;
+                }
+                ssids.sort_by(|a, b| a.name.cmp(&b.name));
+
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["Name", "Security", "Broadcast", "Fast Transition", "Band Select", "Profiles"]);
+                for ssid in &ssids {
+                    let name = ssid.name.as_deref().unwrap_or_default();
+                    let profile_names: Vec<&str> = wireless::profiles_with_ssid(&profiles, name)
+                        .iter()
+                        .filter_map(|profile| profile.name.as_deref())
+                        .collect();
+                    table.add_row(row![
+                        name,
+                        ssid.security_level.as_deref().unwrap_or("N/A"),
+                        yes_no(ssid.enable_broadcast_ssid),
+                        ssid.fast_transition.as_deref().unwrap_or("N/A"),
+                        yes_no(Some(wireless::band_select(ssid))),
+                        profile_names.join(", ")
+                    ]);
+                }
+                output::print_table(&table);
+                outln!("{} SSIDs", ssids.len());
+            }
+            SsidCommands::Detail { name } => match wireless::find_ssid(&ssids, &name) {
+                Ok(ssid) => print_ssid(ssid, &profiles),
+                Err(e) => error!("{}", e),
+            },
+        }
+    });
+}
+
+pub fn yes_no(value: Option<bool>) -> &'static str {
+    match value {
+        Some(true) => "Yes",
+        Some(false) => "No",
+        None => "N/A",
+    }
+}
+
+fn print_ssid(ssid: &SsidDetails, profiles: &[ProfileDetails]) {
+    let name = ssid.name.as_deref().unwrap_or_default();
+    let mut table = Table::new();
+    table.add_row(row!["Name", name]);
+    table.add_row(row!["WLAN Type", ssid.wlan_type.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Security", ssid.security_level.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Auth Server", ssid.auth_server.as_deref().unwrap_or("-")]);
+    table.add_row(row!["Traffic Type", ssid.traffic_type.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Radio Policy", ssid.radio_policy.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Fast Transition", ssid.fast_transition.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Broadcast", yes_no(ssid.enable_broadcast_ssid)]);
+    table.add_row(row!["Enabled", yes_no(ssid.is_enabled)]);
+    table.add_row(row!["Fabric", yes_no(ssid.is_fabric)]);
+    output::print_table(&table);
+
+    let carrying = wireless::profiles_with_ssid(profiles, name);
+    if carrying.is_empty() {
+        outln!("\nNot in any wireless profile, so not broadcast anywhere.");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Profile", "Interface", "FlexConnect VLAN", "Policy Profile", "Sites"]);
+    for profile in carrying {
+        let entry = profile.ssid(name);
+        let flex_vlan = entry
+            .and_then(|entry| entry.flex_connect.as_ref())
+            .filter(|flex| flex.enable_flex_connect.unwrap_or(false))
+            .and_then(|flex| flex.local_to_vlan)
+            .map(|vlan| vlan.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        table.add_row(row![
+            profile.name.as_deref().unwrap_or("N/A"),
+            entry.and_then(|entry| entry.interface_name.as_deref()).unwrap_or("-"),
+            flex_vlan,
+            entry.and_then(|entry| entry.policy_profile_name.as_deref()).unwrap_or("-"),
+            profile.sites.join("\n")
+        ]);
+    }
+    outln!("\nWireless profiles:");
+    output::print_table(&table);
+}
@@ -0,0 +1,87 @@
+// src/handlers/show/tag.rs
+
+use crate::api::authentication::auth;
+use crate::api::tags::gettags::{self, DEVICE_MEMBER, INTERFACE_MEMBER};
+use crate::app::config;
+use crate::commands::show::tag::TagCommands;
+use crate::helpers::concurrency::LimitedJoinSet;
+use crate::helpers::output;
+use log::error;
+use prettytable::{format, row, Table};
+
+pub fn handle_tag_command(subcommand: TagCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            TagCommands::List => {
+                let mut tags = match gettags::get_tags(&config, &token).await {
+                    Ok(tags) => tags,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                if tags.is_empty() {
+                    outln!("No tags found.");
+                    return;
+                }
+                tags.sort_by(|a, b| a.name.cmp(&b.name));
+
+                // Member counts need two calls per tag, so fetch them concurrently
+                let mut counts = LimitedJoinSet::new();
+                for (idx, tag) in tags.iter().enumerate() {
+                    let (config, token) = (config.clone(), token.clone());
+                    let tag_id = tag.id.clone().unwrap_or_default();
+                    counts.spawn(async move {
+                        let devices = gettags::get_tag_member_count(&config, &token, &tag_id, DEVICE_MEMBER).await;
+                        let interfaces = gettags::get_tag_member_count(&config, &token, &tag_id, INTERFACE_MEMBER).await;
+                        (idx, devices.ok(), interfaces.ok())
+                    });
+                }
+                let mut member_counts = vec![(None, None); tags.len()];
+                while let Some(Ok((idx, devices, interfaces))) = counts.join_next().await {
+                    member_counts[idx] = (devices, interfaces);
+                }
+
+                let count_text = |count: Option<i64>| count.map(|count| count.to_string()).unwrap_or_else(|| "?".to_string());
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["Name", "Type", "Devices", "Interfaces", "Description"]);
+                for (tag, (devices, interfaces)) in tags.iter().zip(member_counts) {
+                    let kind = if tag.system_tag.unwrap_or(false) {
+                        "System"
+                    } else if tag.dynamic_rules.is_empty() {
+                        "Static"
+                    } else {
+                        "Dynamic"
+                    };
+                    table.add_row(row![
+                        tag.name.as_deref().unwrap_or("N/A"),
+                        kind,
+                        count_text(devices),
+                        count_text(interfaces),
+                        tag.description.as_deref().unwrap_or("")
+                    ]);
+                }
+                output::print_table(&table);
+                outln!("{} tags", tags.len());
+            }
+        }
+    });
+}
@@ -0,0 +1,139 @@
+// src/handlers/show/template.rs
+
+use crate::api::authentication::auth;
+use crate::api::templates::gettemplates::{self, TemplateSummary};
+use crate::app::config;
+use crate::commands::show::template::TemplateCommands;
+use crate::helpers::{output, template};
+use chrono::DateTime;
+use log::error;
+use prettytable::{format, row, Table};
+
+pub fn handle_template_command(subcommand: TemplateCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let templates = match gettemplates::get_templates(&config, &token).await {
+            Ok(templates) => templates,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            TemplateCommands::List { project } => print_templates(templates, project.as_deref()),
+            TemplateCommands::Detail { name } => {
+                let template_id = match template::find_template(&templates, &name) {
+                    Ok(summary) => summary.template_id.clone().unwrap_or_default(),
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+
+                let template = match gettemplates::get_template(&config, &token, &template_id).await {
+                    Ok(template) => template,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+
+                let device_types: Vec<String> = template
+                    .device_types
+                    .iter()
+                    .map(|device_type| {
+                        device_type
+                            .product_series
+                            .clone()
+                            .or_else(|| device_type.product_family.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect();
+                let mut table = Table::new();
+                table.add_row(row!["Name", template.name.as_deref().unwrap_or("N/A")]);
+                table.add_row(row!["Project", template.project_name.as_deref().unwrap_or("N/A")]);
+                table.add_row(row!["Language", template.language.as_deref().unwrap_or("N/A")]);
+                table.add_row(row!["Software Type", template.software_type.as_deref().unwrap_or("N/A")]);
+                table.add_row(row!["Device Types", device_types.join("\n")]);
+                table.add_row(row!["Version", template.version.as_deref().unwrap_or("N/A")]);
+                table.add_row(row!["Description", template.description.as_deref().unwrap_or("")]);
+                output::print_table(&table);
+
+                if !template.template_params.is_empty() {
+                    let mut table = Table::new();
+                    table.add_row(row!["Parameter", "Type", "Required", "Default", "Description"]);
+                    for param in &template.template_params {
+                        table.add_row(row![
+                            param.parameter_name.as_deref().unwrap_or("N/A"),
+                            param.data_type.as_deref().unwrap_or("N/A"),
+                            if param.required.unwrap_or(false) { "Yes" } else { "No" },
+                            param.default_value.as_deref().unwrap_or(""),
+                            param.description.as_deref().unwrap_or("")
+                        ]);
+                    }
+                    outln!("\nParameters:");
+                    output::print_table(&table);
+                }
+
+                outln!("\nContent:");
+                for line in template.template_content.as_deref().unwrap_or_default().lines() {
+                    outln!("{}", line);
+                }
+            }
+        }
+    });
+}
+
+fn print_templates(mut templates: Vec<TemplateSummary>, project: Option<&str>) {
+    if let Some(project) = project {
+        templates.retain(|template| {
+            template
+                .project_name
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(project))
+        });
+    }
+    if templates.is_empty() {
+        outln!("No templates found matching the specified criteria.");
+        return;
+    }
+    templates.sort_by(|a, b| (&a.project_name, &a.name).cmp(&(&b.project_name, &b.name)));
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Project", "Name", "Version", "Committed", "Composite"]);
+    for template in &templates {
+        let latest = template.latest_version();
+        let committed = latest
+            .and_then(|version| version.version_time)
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "Never".to_string());
+        table.add_row(row![
+            template.project_name.as_deref().unwrap_or("N/A"),
+            template.name.as_deref().unwrap_or("N/A"),
+            latest.and_then(|version| version.version.as_deref()).unwrap_or("-"),
+            committed,
+            if template.composite.unwrap_or(false) { "Yes" } else { "No" }
+        ]);
+    }
+    output::print_table(&table);
+    outln!("{} templates", templates.len());
+}
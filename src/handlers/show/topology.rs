@@ -0,0 +1,315 @@
+// src/handlers/show/topology.rs
+
+use crate::api::authentication::auth;
+use crate::api::devices::getdevicehealth;
+use crate::api::topology::gettopology::{self, NetworkNode, NetworkTopology};
+use crate::app::config;
+use crate::commands::show::topology::{L3Protocol, TopologyArgs, TopologyCommands, TopologyFormat};
+use crate::helpers::devicefilter;
+use log::error;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+pub fn handle_topology_command(subcommand: TopologyCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let (result, view) = match subcommand {
+            TopologyCommands::Physical { view } => {
+                (gettopology::get_physical_topology(&config, &token).await, view)
+            }
+            TopologyCommands::L2 { vlan, view } => {
+                // The API expects VLAN names such as Vlan100
+                let vlan = if vlan.chars().all(|c| c.is_ascii_digit()) {
+                    format!("Vlan{}", vlan)
+                } else {
+                    vlan
+                };
+                (gettopology::get_l2_topology(&config, &token, &vlan).await, view)
+            }
+            TopologyCommands::L3 { protocol, view } => {
+                let protocol = match protocol {
+                    L3Protocol::Ospf => "ospf",
+                    L3Protocol::Eigrp => "eigrp",
+                    L3Protocol::Isis => "isis",
+                    L3Protocol::Static => "static",
+                };
+                (gettopology::get_l3_topology(&config, &token, protocol).await, view)
+            }
+        };
+
+        let mut topology = match result {
+            Ok(topology) => topology,
+            Err(e) => {
+                error!("Failed to retrieve topology: {}", e);
+                return;
+            }
+        };
+
+        if let Some(site) = &view.site {
+            match getdevicehealth::get_device_sites(&config, &token).await {
+                Ok(sites) => filter_site(&mut topology, &sites, site),
+                Err(e) => {
+                    error!("Failed to retrieve device sites: {}", e);
+                    return;
+                }
+            }
+        }
+
+        if topology.nodes.is_empty() {
+            outln!("No devices found in this topology.");
+            return;
+        }
+
+        print_topology(&topology, &view);
+    });
+}
+
+/// Keep only the devices in `site` or below it, and the links between them.
+fn filter_site(topology: &mut NetworkTopology, sites: &HashMap<String, String>, site: &str) {
+    topology.nodes.retain(|node| {
+        node.id
+            .as_ref()
+            .and_then(|id| sites.get(id))
+            .is_some_and(|hierarchy| devicefilter::in_site(hierarchy, site))
+    });
+
+    let kept: HashSet<&str> = topology.nodes.iter().filter_map(|node| node.id.as_deref()).collect();
+    topology.links.retain(|link| {
+        link.source.as_deref().is_some_and(|id| kept.contains(id))
+            && link.target.as_deref().is_some_and(|id| kept.contains(id))
+    });
+}
+
+fn print_topology(topology: &NetworkTopology, view: &TopologyArgs) {
+    match view.format {
+        TopologyFormat::Ascii => print_adjacency(topology),
+        TopologyFormat::Dot => {
+            for line in to_dot(topology).lines() {
+                outln!("{}", line);
+            }
+        }
+        TopologyFormat::Json => match serde_json::to_string_pretty(topology) {
+            Ok(json) => {
+                for line in json.lines() {
+                    outln!("{}", line);
+                }
+            }
+            Err(e) => error!("Failed to serialize topology: {}", e),
+        },
+    }
+}
+
+fn node_name(node: &NetworkNode) -> String {
+    node.label
+        .clone()
+        .or_else(|| node.ip.clone())
+        .or_else(|| node.id.clone())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+fn port(name: &Option<String>, ip: &Option<String>) -> String {
+    match (name, ip) {
+        (Some(name), Some(ip)) => format!("{} ({})", name, ip),
+        (Some(name), None) => name.clone(),
+        (None, Some(ip)) => ip.clone(),
+        (None, None) => "-".to_string(),
+    }
+}
+
+/// Each device followed by its links, listed from that device's side.
+fn print_adjacency(topology: &NetworkTopology) {
+    let names: HashMap<&str, String> = topology
+        .nodes
+        .iter()
+        .filter_map(|node| Some((node.id.as_deref()?, node_name(node))))
+        .collect();
+    let name_of = |id: &Option<String>| {
+        id.as_deref()
+            .and_then(|id| names.get(id).cloned())
+            .unwrap_or_else(|| "Unknown".to_string())
+    };
+
+    let mut adjacency: HashMap<&str, Vec<String>> = HashMap::new();
+    for link in &topology.links {
+        let status = link.link_status.as_deref().unwrap_or("unknown");
+        if let Some(source) = link.source.as_deref() {
+            adjacency.entry(source).or_default().push(format!(
+                "{} -> {} {} [{}]",
+                port(&link.start_port_name, &link.start_port_ipv4_address),
+                name_of(&link.target),
+                port(&link.end_port_name, &link.end_port_ipv4_address),
+                status
+            ));
+        }
+        if let Some(target) = link.target.as_deref() {
+            adjacency.entry(target).or_default().push(format!(
+                "{} -> {} {} [{}]",
+                port(&link.end_port_name, &link.end_port_ipv4_address),
+                name_of(&link.source),
+                port(&link.start_port_name, &link.start_port_ipv4_address),
+                status
+            ));
+        }
+    }
+
+    // The id keeps nodes with the same label apart
+    let nodes: BTreeMap<(String, Option<&str>), &NetworkNode> = topology
+        .nodes
+        .iter()
+        .map(|node| ((node_name(node), node.id.as_deref()), node))
+        .collect();
+
+    for ((name, _), node) in nodes {
+        outln!(
+            "{} ({}) [{}]",
+            name,
+            node.ip.as_deref().unwrap_or("N/A"),
+            node.role.as_deref().or(node.device_type.as_deref()).unwrap_or("N/A")
+        );
+        match node.id.as_deref().and_then(|id| adjacency.get_mut(id)) {
+            Some(links) => {
+                links.sort();
+                for link in links.iter() {
+                    outln!("  {}", link);
+                }
+            }
+            None => outln!("  (no links)"),
+        }
+    }
+    outln!("{} devices, {} links", topology.nodes.len(), topology.links.len());
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Graphviz DOT, styled like `docs/command_flow.dot`. Down links are dashed red.
+fn to_dot(topology: &NetworkTopology) -> String {
+    let mut lines = vec![
+        "graph topology {".to_string(),
+        "    node [shape=box, style=rounded, fontname=\"Arial\", fontsize=12];".to_string(),
+        "    edge [fontname=\"Arial\", fontsize=10];".to_string(),
+        String::new(),
+    ];
+
+    for node in &topology.nodes {
+        let id = match node.id.as_deref() {
+            Some(id) => id,
+            None => continue,
+        };
+        // Escape each part on its own so the `\n` line break stays a DOT escape
+        let mut label = dot_escape(&node_name(node));
+        if let Some(ip) = &node.ip {
+            label.push_str(&format!("\\n{}", dot_escape(ip)));
+        }
+        lines.push(format!("    \"{}\" [label=\"{}\"];", dot_escape(id), label));
+    }
+    lines.push(String::new());
+
+    for link in &topology.links {
+        let (source, target) = match (link.source.as_deref(), link.target.as_deref()) {
+            (Some(source), Some(target)) => (source, target),
+            _ => continue,
+        };
+        let label = format!(
+            "{} - {}",
+            port(&link.start_port_name, &link.start_port_ipv4_address),
+            port(&link.end_port_name, &link.end_port_ipv4_address)
+        );
+        let style = if link.link_status.as_deref() == Some("down") {
+            ", style=dashed, color=red"
+        } else {
+            ""
+        };
+        lines.push(format!(
+            "    \"{}\" -- \"{}\" [label=\"{}\"{}];",
+            dot_escape(source),
+            dot_escape(target),
+            dot_escape(&label),
+            style
+        ));
+    }
+
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn topology() -> NetworkTopology {
+        serde_json::from_value(json!({
+            "nodes": [
+                { "id": "n1", "label": "core \"A\"", "ip": "10.0.0.1" },
+                { "id": "n2", "label": "edge-1" },
+                { "label": "no id" },
+            ],
+            "links": [
+                {
+                    "source": "n1",
+                    "target": "n2",
+                    "startPortName": "Gi1/0/1",
+                    "endPortName": "Gi1/0/48",
+                    "linkStatus": "down",
+                },
+                { "source": "n1" },
+            ],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn dot_keeps_label_line_break_and_escapes_quotes() {
+        let dot = to_dot(&topology());
+        assert!(dot.contains(r#"    "n1" [label="core \"A\"\n10.0.0.1"];"#));
+        assert!(dot.contains(r#"    "n2" [label="edge-1"];"#));
+        assert!(!dot.contains("\\\\n"));
+        assert!(!dot.contains("no id"));
+    }
+
+    #[test]
+    fn adjacency_lists_nodes_sharing_a_label() {
+        let topology: NetworkTopology = serde_json::from_value(json!({
+            "nodes": [
+                { "id": "n1", "label": "switch", "ip": "10.0.0.1" },
+                { "id": "n2", "label": "switch", "ip": "10.0.0.2" },
+            ],
+        }))
+        .unwrap();
+
+        let blocks = crate::helpers::output::capture(|| print_adjacency(&topology));
+        let text = crate::helpers::output::render(&blocks);
+        assert!(text.contains("switch (10.0.0.1)"));
+        assert!(text.contains("switch (10.0.0.2)"));
+        assert!(text.contains("2 devices, 0 links"));
+    }
+
+    #[test]
+    fn dot_draws_down_links_dashed() {
+        let dot = to_dot(&topology());
+        let edges: Vec<&str> = dot.lines().filter(|line| line.contains(" -- ")).collect();
+        assert_eq!(
+            edges,
+            [r#"    "n1" -- "n2" [label="Gi1/0/1 - Gi1/0/48", style=dashed, color=red];"#]
+        );
+        assert!(dot.starts_with("graph topology {"));
+        assert!(dot.ends_with('}'));
+    }
+}
@@ -0,0 +1,236 @@
+// src/handlers/show/wlc.rs
+
+use crate::api::authentication::auth;
+use crate::api::devices::getdevicedetail::{self, DeviceDetail};
+use crate::api::devices::getdevicehealth::{self, DeviceHealth};
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::app::config;
+use crate::commands::show::wlc::WlcCommands;
+use crate::handlers::show::image::load_devices;
+use crate::helpers::concurrency::LimitedJoinSet;
+use crate::helpers::{devicefilter, output, wireless};
+use log::error;
+use prettytable::{format, row, Table};
+use std::collections::{BTreeSet, HashMap};
+
+/// Beyond this many, the list shows a site count instead of the sites.
+const LISTED_SITES: usize = 3;
+
+pub fn handle_wlc_command(subcommand: WlcCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let (site, name, cache_args) = match subcommand {
+            WlcCommands::List { site, cache } => (site, None, cache),
+            WlcCommands::Detail { name, cache } => (None, Some(name), cache),
+        };
+
+        let fetched = tokio::try_join!(
+            load_devices(&config, &token, cache_args.mode(), None),
+            getdevicehealth::get_all_device_health(&config, &token)
+        );
+        let (devices, health) = match fetched {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+        let health: HashMap<&str, &DeviceHealth> = health
+            .iter()
+            .filter_map(|device| Some((device.uuid.as_deref()?, device)))
+            .collect();
+
+        if let Some(name) = name {
+            let wlc = match devicefilter::find_device(&devices, &name) {
+                Ok(wlc) if wireless::is_wlc(wlc) => wlc,
+                Ok(_) => {
+                    error!("'{}' is not a wireless controller", name);
+                    return;
+                }
+                Err(e) => {
+                    error!("{}", e);
+                    return;
+                }
+            };
+            let detail = getdevicedetail::get_device_detail(&config, &token, wlc.id.as_deref().unwrap_or_default()).await;
+            if let Err(e) = &detail {
+                eprintln!("HA state unavailable: {}", e);
+            }
+            print_wlc(&summarize(wlc, &devices, &health, detail.ok().as_ref()));
+            return;
+        }
+
+        let mut summaries: Vec<WlcSummary> = devices
+            .iter()
+            .filter(|device| wireless::is_wlc(device))
+            .map(|wlc| summarize(wlc, &devices, &health, None))
+            .collect();
+        if let Some(site) = &site {
+            summaries.retain(|summary| {
+                summary.site.as_deref().is_some_and(|own| devicefilter::in_site(own, site))
+                    || summary.sites.iter().any(|managed| devicefilter::in_site(managed, site))
+            });
+        }
+        if summaries.is_empty() {
+            outln!("No wireless controllers found matching the specified criteria.");
+            return;
+        }
+        summaries.sort_by(|a, b| a.wlc.hostname.cmp(&b.wlc.hostname));
+
+        // HA state needs one call per controller, so fetch them concurrently
+        let mut details = LimitedJoinSet::new();
+        for (idx, summary) in summaries.iter().enumerate() {
+            let (config, token) = (config.clone(), token.clone());
+            let device_id = summary.wlc.id.clone().unwrap_or_default();
+            details.spawn(async move { (idx, getdevicedetail::get_device_detail(&config, &token, &device_id).await) });
+        }
+        while let Some(Ok((idx, detail))) = details.join_next().await {
+            summaries[idx].ha_state = detail.map(|detail| ha_state(&detail)).unwrap_or_else(|_| "?".to_string());
+        }
+
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+        table.add_row(row!["Name", "IP Address", "Version", "HA State", "APs", "Clients", "Managed Sites"]);
+        for summary in &summaries {
+            let sites = if summary.sites.len() > LISTED_SITES {
+                format!("{} sites", summary.sites.len())
+            } else {
+                summary.sites.iter().cloned().collect::<Vec<_>>().join("\n")
+            };
+            table.add_row(row![
+                summary.wlc.hostname.as_deref().unwrap_or("N/A"),
+                summary.wlc.management_ip_address.as_deref().unwrap_or("N/A"),
+                summary.wlc.software_version.as_deref().unwrap_or("N/A"),
+                summary.ha_state,
+                summary.aps.len(),
+                summary.clients,
+                sites
+            ]);
+        }
+        output::print_table(&table);
+        outln!(
+            "{} controllers, {} APs, {} clients",
+            summaries.len(),
+            summaries.iter().map(|summary| summary.aps.len()).sum::<usize>(),
+            summaries.iter().map(|summary| summary.clients).sum::<i64>()
+        );
+    });
+}
+
+/// A controller with its joined APs and what they serve.
+struct WlcSummary<'a> {
+    wlc: &'a AllDevices,
+    /// Site the controller itself is assigned to
+    site: Option<String>,
+    aps: Vec<(&'a AllDevices, Option<&'a DeviceHealth>)>,
+    clients: i64,
+    /// Sites of the joined APs
+    sites: BTreeSet<String>,
+    ha_state: String,
+}
+
+fn summarize<'a>(
+    wlc: &'a AllDevices,
+    devices: &'a [AllDevices],
+    health: &HashMap<&str, &'a DeviceHealth>,
+    detail: Option<&DeviceDetail>,
+) -> WlcSummary<'a> {
+    let lookup = |device: &AllDevices| device.id.as_deref().and_then(|id| health.get(id).copied());
+    let aps: Vec<_> = wireless::joined_aps(devices, wlc)
+        .into_iter()
+        .map(|ap| (ap, lookup(ap)))
+        .collect();
+
+    WlcSummary {
+        wlc,
+        site: lookup(wlc).and_then(|health| health.location.clone()),
+        clients: aps
+            .iter()
+            .filter_map(|(_, health)| health.and_then(|health| health.client_count.as_ref()))
+            .map(|count| count.total())
+            .sum(),
+        sites: aps
+            .iter()
+            .filter_map(|(_, health)| health.and_then(|health| health.location.clone()))
+            .collect(),
+        aps,
+        ha_state: detail.map(ha_state).unwrap_or_else(|| "?".to_string()),
+    }
+}
+
+/// Redundancy state such as `Active (peer Standby Hot)`, or `Standalone`.
+fn ha_state(detail: &DeviceDetail) -> String {
+    let mode = detail.redundancy_mode.as_deref().unwrap_or_default();
+    if mode.is_empty() || mode.eq_ignore_ascii_case("N/A") || mode.to_lowercase().contains("disabled") {
+        return "Standalone".to_string();
+    }
+    let state = detail
+        .redundancy_state
+        .as_deref()
+        .or(detail.ha_status.as_deref())
+        .unwrap_or("Unknown");
+    match detail.redundancy_peer_state.as_deref() {
+        Some(peer) if !peer.is_empty() => format!("{} (peer {})", state, peer),
+        _ => state.to_string(),
+    }
+}
+
+fn print_wlc(summary: &WlcSummary) {
+    let wlc = summary.wlc;
+    let mut table = Table::new();
+    table.add_row(row!["Name", wlc.hostname.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["IP Address", wlc.management_ip_address.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Platform", wlc.platform_id.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Serial Number", wlc.serial_number.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Version", wlc.software_version.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["Reachability", wlc.reachability_status.as_deref().unwrap_or("N/A")]);
+    table.add_row(row!["HA State", summary.ha_state]);
+    table.add_row(row!["Site", summary.site.as_deref().unwrap_or("-")]);
+    table.add_row(row!["APs", summary.aps.len()]);
+    table.add_row(row!["Clients", summary.clients]);
+    table.add_row(row!["Managed Sites", summary.sites.iter().cloned().collect::<Vec<_>>().join("\n")]);
+    output::print_table(&table);
+
+    if summary.aps.is_empty() {
+        outln!("\nNo access points are joined to this controller.");
+        return;
+    }
+
+    let mut aps = summary.aps.clone();
+    aps.sort_by(|a, b| a.0.hostname.cmp(&b.0.hostname));
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["AP Name", "Model", "IP Address", "Version", "Reachability", "Clients", "Site"]);
+    for (ap, health) in aps {
+        table.add_row(row![
+            ap.hostname.as_deref().unwrap_or("N/A"),
+            ap.platform_id.as_deref().unwrap_or("N/A"),
+            ap.management_ip_address.as_deref().unwrap_or("N/A"),
+            ap.software_version.as_deref().unwrap_or("N/A"),
+            ap.reachability_status.as_deref().unwrap_or("N/A"),
+            health
+                .and_then(|health| health.client_count.as_ref())
+                .map(|count| count.total().to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            health.and_then(|health| health.location.as_deref()).unwrap_or("-")
+        ]);
+    }
+    outln!("\nJoined access points:");
+    output::print_table(&table);
+}
@@ -0,0 +1,184 @@
+// src/handlers/tag.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::interfaces::getinterfaces;
+use crate::api::tags::gettags::{self, DEVICE_MEMBER, INTERFACE_MEMBER};
+use crate::api::tags::updatetags;
+use crate::api::tasks::gettask;
+use crate::app::cache::CacheMode;
+use crate::app::config::{self, Config};
+use crate::commands::tag::{TagCommands, TagMemberArgs};
+use crate::handlers::show::image::load_devices;
+use crate::helpers::tasktracker::{self, TrackedTask};
+use crate::helpers::{devicefilter, output, utils};
+use anyhow::{anyhow, Result};
+use log::error;
+use prettytable::{format, row, Table};
+use std::time::Duration;
+
+const CREATE_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub fn handle_tag_command(subcommand: TagCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let (tag_name, members, adding) = match subcommand {
+            TagCommands::Create { name, description } => {
+                let created = match updatetags::create_tag(&config, &token, &name, description.as_deref()).await {
+                    Ok(task) => gettask::wait_for_task(&config, &token, &task.task_id, CREATE_TIMEOUT).await,
+                    Err(e) => Err(e),
+                };
+                match created {
+                    Ok(_) => println!("Created tag '{}'.", name),
+                    Err(e) => error!("{}", e),
+                }
+                return;
+            }
+            TagCommands::Add { tag, members } => (tag, members, true),
+            TagCommands::Remove { tag, members } => (tag, members, false),
+        };
+
+        let tag = match gettags::get_tag_by_name(&config, &token, &tag_name).await {
+            Ok(tag) => tag,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+        let tag_id = tag.id.clone().unwrap_or_default();
+        if !tag.dynamic_rules.is_empty() {
+            eprintln!("Tag '{}' also selects members by rules; only its static members change.", tag_name);
+        }
+
+        let member_type = if members.interfaces.is_empty() { DEVICE_MEMBER } else { INTERFACE_MEMBER };
+        let candidates = match resolve_members(&config, &token, &members).await {
+            Ok(candidates) => candidates,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+        let tagged = match gettags::get_tag_member_ids(&config, &token, &tag_id, member_type).await {
+            Ok(tagged) => tagged,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+        table.add_row(row!["Member", "Action"]);
+        let mut targets = Vec::new();
+        for (label, id) in candidates {
+            let has_tag = tagged.contains(&id);
+            let action = match (adding, has_tag) {
+                (true, true) => "Skip: already tagged",
+                (true, false) => "Tag",
+                (false, true) => "Untag",
+                (false, false) => "Skip: not tagged",
+            };
+            table.add_row(row![label, action]);
+            if adding != has_tag {
+                targets.push((label, id));
+            }
+        }
+        output::print_table(&table);
+
+        if targets.is_empty() {
+            println!("Nothing to do.");
+            return;
+        }
+        if members.dry_run {
+            println!("Dry run: nothing was changed.");
+            return;
+        }
+        let question = if adding {
+            format!("Tag {} members with '{}'?", targets.len(), tag_name)
+        } else {
+            format!("Remove '{}' from {} members?", tag_name, targets.len())
+        };
+        if !members.yes && !utils::confirm(&question) {
+            println!("Aborted.");
+            return;
+        }
+
+        // Members are added in one request but removed one at a time
+        let mut tasks = Vec::new();
+        if adding {
+            let ids: Vec<String> = targets.iter().map(|(_, id)| id.clone()).collect();
+            let started = updatetags::add_members(&config, &token, &tag_id, member_type, &ids).await;
+            tasks.push(TrackedTask::new(&format!("{} members", ids.len()), started));
+        } else {
+            for (label, id) in &targets {
+                let started = updatetags::remove_member(&config, &token, &tag_id, id).await;
+                tasks.push(TrackedTask::new(label, started));
+            }
+        }
+
+        let title = format!("{} tag '{}'", if adding { "Add" } else { "Remove" }, tag_name);
+        tasktracker::track(&config, &token, &title, &mut tasks, members.timeout).await;
+    });
+}
+
+/// Label and id of each device or interface the arguments select.
+async fn resolve_members(config: &Config, token: &Token, members: &TagMemberArgs) -> Result<Vec<(String, String)>> {
+    let devices = load_devices(config, token, CacheMode::Default, members.site.as_deref()).await?;
+
+    if let Some(device) = &members.device {
+        let device = devicefilter::find_device(&devices, device)?;
+        let hostname = device.hostname.clone().unwrap_or_default();
+        let device_id = device.id.as_deref().unwrap_or_default();
+        let interfaces = getinterfaces::get_device_interfaces(config, token, device_id).await?;
+
+        return members
+            .interfaces
+            .iter()
+            .map(|query| {
+                interfaces
+                    .iter()
+                    .find(|interface| {
+                        interface
+                            .port_name
+                            .as_deref()
+                            .is_some_and(|name| devicefilter::interface_matches(name, query))
+                    })
+                    .map(|interface| {
+                        let name = interface.port_name.clone().unwrap_or_default();
+                        (format!("{} {}", hostname, name), interface.id.clone().unwrap_or_default())
+                    })
+                    .ok_or_else(|| anyhow!("No interface '{}' on {}", query, hostname))
+            })
+            .collect();
+    }
+
+    let pattern = members.devices.as_deref().unwrap_or("*");
+    let selected = devicefilter::select_devices(&devices, pattern);
+    if selected.is_empty() {
+        return Err(anyhow!("No devices match '{}'", pattern));
+    }
+    Ok(selected
+        .into_iter()
+        .map(|device| {
+            (
+                device.hostname.clone().unwrap_or_default(),
+                device.id.clone().unwrap_or_default(),
+            )
+        })
+        .collect())
+}
@@ -0,0 +1,177 @@
+// src/handlers/template.rs
+
+use crate::api::authentication::auth::{self, Token};
+use crate::api::templates::deploytemplate;
+use crate::api::templates::gettemplates::{self, Template};
+use crate::app::cache::CacheMode;
+use crate::app::config::{self, Config};
+use crate::commands::template::TemplateCommands;
+use crate::handlers::show::image::load_devices;
+use crate::helpers::tasktracker::{self, TrackedTask};
+use crate::helpers::template::{self, TemplateVars};
+use crate::helpers::{devicefilter, output, utils};
+use anyhow::{anyhow, Result};
+use log::error;
+use prettytable::{format, row, Table};
+use std::path::Path;
+
+pub fn handle_template_command(subcommand: TemplateCommands) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        match subcommand {
+            TemplateCommands::Render { name, vars, device, remote } => {
+                let (version_id, template, vars) = match load_template(&config, &token, &name, vars.as_deref()).await {
+                    Ok(loaded) => loaded,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                let params = vars.for_device(device.as_deref());
+
+                let missing = template::missing_params(&template, &params);
+                if !missing.is_empty() {
+                    eprintln!("Missing required variables: {}", missing.join(", "));
+                }
+
+                if remote {
+                    match deploytemplate::preview_template(&config, &token, &version_id, &params).await {
+                        Ok(preview) => {
+                            for line in preview.cli_preview.as_deref().unwrap_or_default().lines() {
+                                outln!("{}", line);
+                            }
+                            if !preview.validation_errors.is_null() {
+                                eprintln!("Validation errors: {}", preview.validation_errors);
+                            }
+                        }
+                        Err(e) => error!("{}", e),
+                    }
+                    return;
+                }
+
+                // Notes go to stderr so the rendered config can be redirected to a file
+                let rendered = template::render(&template, &params);
+                for line in rendered.text.lines() {
+                    outln!("{}", line);
+                }
+                if !rendered.unresolved.is_empty() {
+                    let names: Vec<&str> = rendered.unresolved.iter().map(String::as_str).collect();
+                    eprintln!("Left unrendered (no value given): {}", names.join(", "));
+                }
+                if rendered.has_directives {
+                    eprintln!("The template has loops or conditionals, which are only evaluated with --remote.");
+                }
+            }
+            TemplateCommands::Deploy { name, devices, site, vars, dry_run, yes, timeout } => {
+                let (version_id, template, vars) = match load_template(&config, &token, &name, vars.as_deref()).await {
+                    Ok(loaded) => loaded,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+
+                let inventory = match load_devices(&config, &token, CacheMode::Default, site.as_deref()).await {
+                    Ok(inventory) => inventory,
+                    Err(e) => {
+                        error!("{}", e);
+                        return;
+                    }
+                };
+                let pattern = devices.as_deref().unwrap_or("*");
+                let selected = devicefilter::select_devices(&inventory, pattern);
+                if selected.is_empty() {
+                    println!("No devices match '{}'.", pattern);
+                    return;
+                }
+
+                // Pre-check every device's variables before deploying anything
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+                table.add_row(row!["Device", "IP", "Action"]);
+                let mut targets = Vec::new();
+                for device in selected {
+                    let params = vars.for_device(device.hostname.as_deref());
+                    let missing = template::missing_params(&template, &params);
+                    let action = match &device.management_ip_address {
+                        None => "Skip: no management IP".to_string(),
+                        Some(_) if !missing.is_empty() => format!("Skip: missing {}", missing.join(", ")),
+                        Some(ip) => {
+                            targets.push((device.hostname.clone().unwrap_or_else(|| ip.clone()), ip.clone(), params));
+                            "Deploy".to_string()
+                        }
+                    };
+                    table.add_row(row![
+                        device.hostname.as_deref().unwrap_or("Unknown"),
+                        device.management_ip_address.as_deref().unwrap_or("N/A"),
+                        action
+                    ]);
+                }
+                output::print_table(&table);
+
+                if targets.is_empty() {
+                    println!("Nothing to do.");
+                    return;
+                }
+                if dry_run {
+                    println!("Dry run: nothing was started.");
+                    return;
+                }
+                if !yes && !utils::confirm(&format!("Deploy '{}' to {} devices?", name, targets.len())) {
+                    println!("Aborted.");
+                    return;
+                }
+
+                let mut tasks = Vec::new();
+                for (hostname, ip, params) in &targets {
+                    let started = deploytemplate::deploy_template(&config, &token, &version_id, ip, params).await;
+                    tasks.push(TrackedTask::new(hostname, started));
+                }
+
+                let title = format!("Deploy {} to {} devices", name, tasks.len());
+                tasktracker::track(&config, &token, &title, &mut tasks, timeout).await;
+            }
+        }
+    });
+}
+
+/// The id and content of the named template's latest committed version, which
+/// is what gets deployed, and the variables file if given. Uncommitted edits
+/// are ignored so renders and checks match the deployed config.
+pub async fn load_template(
+    config: &Config,
+    token: &Token,
+    name: &str,
+    vars: Option<&Path>,
+) -> Result<(String, Template, TemplateVars)> {
+    let vars = match vars {
+        Some(path) => TemplateVars::load(path)?,
+        None => TemplateVars::default(),
+    };
+
+    let templates = gettemplates::get_templates(config, token).await?;
+    let summary = template::find_template(&templates, name)?;
+    let version_id = summary
+        .latest_version()
+        .and_then(|version| version.id.clone())
+        .ok_or_else(|| anyhow!("Template '{}' has no committed version; commit it first", name))?;
+    let template = gettemplates::get_template(config, token, &version_id).await?;
+
+    Ok((version_id, template, vars))
+}
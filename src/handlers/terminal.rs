@@ -0,0 +1,32 @@
+// src/handlers/terminal.rs
+
+use crate::commands::terminal::{PagerAction, TerminalCommands, TerminalLength};
+use crate::helpers::session;
+
+pub fn handle_terminal_command(subcommand: TerminalCommands) {
+    match subcommand {
+        TerminalCommands::Length { lines } => match lines {
+            TerminalLength::Auto => {
+                session::set_terminal_length(None);
+                println!("Terminal length follows the terminal height.");
+            }
+            TerminalLength::Lines(0) => {
+                session::set_terminal_length(Some(0));
+                println!("Paging disabled for this session.");
+            }
+            TerminalLength::Lines(lines) => {
+                session::set_terminal_length(Some(lines));
+                println!("Terminal length set to {} lines.", lines);
+            }
+        },
+        TerminalCommands::Pager { action } => {
+            let enable = matches!(action, PagerAction::Enable);
+            session::set_pager_enabled(enable);
+            if enable {
+                println!("Pager enabled for this session.");
+            } else {
+                println!("Pager disabled for this session.");
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+// src/handlers/trace.rs
+
+use crate::api::authentication::auth;
+use crate::api::pathtrace::flowanalysis::{
+    self, ElementInterface, FlowAnalysis, FlowAnalysisRequest, NetworkElement,
+};
+use crate::app::config;
+use crate::commands::trace::{TraceArgs, TraceProtocol};
+use crate::helpers::interrupt;
+use crate::helpers::output;
+use anyhow::{anyhow, Result};
+use log::error;
+use prettytable::{row, Table};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+pub fn handle_trace_command(args: TraceArgs) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    runtime.block_on(async {
+        let config = match config::load_config() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("Failed to load configuration: {}", e);
+                return;
+            }
+        };
+
+        let token = match auth::authenticate(&config).await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Authentication failed: {}", e);
+                return;
+            }
+        };
+
+        let request = FlowAnalysisRequest {
+            source_ip: args.source.to_string(),
+            dest_ip: args.destination.to_string(),
+            protocol: args.protocol.map(|protocol| match protocol {
+                TraceProtocol::Tcp => "TCP".to_string(),
+                TraceProtocol::Udp => "UDP".to_string(),
+            }),
+            source_port: args.src_port.map(|port| port.to_string()),
+            dest_port: args.dst_port.map(|port| port.to_string()),
+            inclusions: ["INTERFACE", "QOS", "ACL-TRACE"].map(String::from).to_vec(),
+            periodic_refresh: false,
+        };
+
+        let started = match flowanalysis::start_flow_analysis(&config, &token, &request).await {
+            Ok(started) => started,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+        println!(
+            "Tracing {} -> {} (id {}), Ctrl-C to stop waiting...",
+            args.source, args.destination, started.flow_analysis_id
+        );
+
+        let wait = wait_for_trace(&config, &token, &started.flow_analysis_id, args.timeout);
+        let analysis = match interrupt::until_ctrl_c(wait).await {
+            Some(analysis) => analysis,
+            None => {
+                println!("Stopped waiting. The trace continues to run in Catalyst Center.");
+                return;
+            }
+        };
+
+        match analysis {
+            Ok(analysis) => print_trace(&analysis),
+            Err(e) => error!("{}", e),
+        }
+    });
+}
+
+/// Poll the trace until it completes, fails or `timeout` passes.
+async fn wait_for_trace(
+    config: &config::Config,
+    token: &auth::Token,
+    id: &str,
+    timeout: Duration,
+) -> Result<FlowAnalysis> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let analysis = flowanalysis::get_flow_analysis(config, token, id).await?;
+        let status = analysis
+            .request
+            .as_ref()
+            .and_then(|request| request.status.clone())
+            .unwrap_or_default();
+
+        match status.as_str() {
+            "COMPLETED" => return Ok(analysis),
+            "FAILED" => {
+                let reason = analysis
+                    .request
+                    .and_then(|request| request.failure_reason)
+                    .unwrap_or_else(|| "no reason given".to_string());
+                return Err(anyhow!("Path trace failed: {}", reason));
+            }
+            _ if Instant::now() >= deadline => {
+                return Err(anyhow!(
+                    "Path trace did not complete within {}s (last status {})",
+                    timeout.as_secs(),
+                    status
+                ))
+            }
+            _ => tokio::time::sleep(POLL_INTERVAL).await,
+        }
+    }
+}
+
+fn print_trace(analysis: &FlowAnalysis) {
+    let hops = analysis.network_elements_info.as_deref().unwrap_or_default();
+    if hops.is_empty() {
+        outln!("The path trace returned no hops.");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.add_row(row!["Hop", "Device", "IP", "Ingress", "Egress", "ACL", "QoS Drops"]);
+    for (idx, hop) in hops.iter().enumerate() {
+        table.add_row(row![
+            idx + 1,
+            hop_name(hop),
+            hop.ip.as_deref().unwrap_or("N/A"),
+            interface_name(&hop.ingress_interface),
+            interface_name(&hop.egress_interface),
+            acl_summary(hop),
+            qos_drops(&hop.egress_interface)
+        ]);
+    }
+    output::print_table(&table);
+
+    if let Some(reason) = analysis
+        .detailed_status
+        .as_ref()
+        .and_then(|status| status.acl_trace_calculation_failure_reason.as_deref())
+    {
+        outln!("ACL trace unavailable: {}", reason);
+    }
+}
+
+fn hop_name(hop: &NetworkElement) -> String {
+    let name = hop.name.clone().unwrap_or_else(|| "Unknown".to_string());
+    match &hop.element_type {
+        Some(element_type) => format!("{} ({})", name, element_type),
+        None => name,
+    }
+}
+
+fn interface_name(interface: &Option<ElementInterface>) -> String {
+    interface
+        .as_ref()
+        .and_then(|interface| interface.physical_interface.as_ref())
+        .and_then(|physical| physical.name.clone())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// ACL results on the ingress and egress interfaces, e.g. `in ACL-WEB: PERMIT`.
+fn acl_summary(hop: &NetworkElement) -> String {
+    let directions = [("in", &hop.ingress_interface), ("out", &hop.egress_interface)];
+    let results: Vec<String> = directions
+        .iter()
+        .filter_map(|(direction, interface)| {
+            let acl = interface
+                .as_ref()?
+                .physical_interface
+                .as_ref()?
+                .acl_analysis
+                .as_ref()?;
+            Some(format!(
+                "{} {}: {}",
+                direction,
+                acl.acl_name.as_deref().unwrap_or("ACL"),
+                acl.result.as_deref().unwrap_or("N/A")
+            ))
+        })
+        .collect();
+
+    if results.is_empty() {
+        "-".to_string()
+    } else {
+        results.join("\n")
+    }
+}
+
+/// Queue drops per QoS class on the egress interface.
+fn qos_drops(interface: &Option<ElementInterface>) -> String {
+    let stats = interface
+        .as_ref()
+        .and_then(|interface| interface.physical_interface.as_ref())
+        .and_then(|physical| physical.qos_statistics.as_deref())
+        .unwrap_or_default();
+
+    let drops: Vec<String> = stats
+        .iter()
+        .filter(|stat| stat.queue_total_drops.unwrap_or(0) > 0)
+        .map(|stat| {
+            format!(
+                "{}: {}",
+                stat.class_map_name.as_deref().unwrap_or("class"),
+                stat.queue_total_drops.unwrap_or(0)
+            )
+        })
+        .collect();
+
+    if drops.is_empty() {
+        "0".to_string()
+    } else {
+        drops.join("\n")
+    }
+}
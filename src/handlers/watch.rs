@@ -0,0 +1,232 @@
+// src/handlers/watch.rs
+
+use crate::app::cache;
+use crate::commands::watch::WatchCommands;
+use crate::handlers::handle_show_command;
+use crate::helpers::interrupt;
+use crate::helpers::logger;
+use crate::helpers::output::{self, Block};
+use chrono::Local;
+use crossterm::cursor::MoveTo;
+use crossterm::event::KeyCode;
+use crossterm::execute;
+use crossterm::style::Stylize;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType};
+use log::error;
+use prettytable::{color, Attr, Row, Table};
+use std::collections::HashMap;
+use std::io::stdout;
+use std::time::Duration;
+
+#[derive(Default)]
+struct Changes {
+    added: usize,
+    changed: usize,
+    removed: usize,
+}
+
+pub fn handle_watch_command(interval: Duration, command: WatchCommands) {
+    // In raw mode Ctrl-C arrives as a key press, so it only stops this loop
+    // and no signal handler is left behind once we return to the prompt
+    let raw = match enable_raw_mode() {
+        Ok(()) => true,
+        Err(e) => {
+            error!("Failed to enable raw mode, Ctrl-C will exit catalysh: {}", e);
+            false
+        }
+    };
+
+    // Polling the cache would show the same data until its TTL passed
+    cache::set_live(true);
+    let mut previous: Option<Vec<Block>> = None;
+
+    loop {
+        let mut current = Vec::new();
+        let errors = logger::collect_errors(|| {
+            current = output::capture(|| run_command(command.clone()));
+        });
+        if raw && stop_requested(Duration::ZERO) {
+            break;
+        }
+
+        let (highlighted, changes) = highlight(previous.as_deref(), &current);
+
+        if raw {
+            let _ = disable_raw_mode();
+        }
+        if let Err(e) = execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0)) {
+            error!("Failed to clear screen: {}", e);
+        }
+        println!(
+            "Every {}s - last refresh {} (Ctrl-C or q to stop)",
+            interval.as_secs(),
+            Local::now().format("%Y-%m-%d %H:%M:%S")
+        );
+        if previous.is_some() {
+            println!(
+                "{} added, {} changed, {} removed",
+                changes.added.to_string().green(),
+                changes.changed.to_string().yellow(),
+                changes.removed.to_string().red()
+            );
+        }
+        // Errors from this refresh are drawn into the frame so the next
+        // clear doesn't wipe them
+        for e in &errors {
+            println!("{}", format!("Error: {}", e).red());
+        }
+        println!();
+        output::print_blocks(&highlighted);
+
+        previous = Some(current);
+
+        if raw {
+            let _ = enable_raw_mode();
+            if stop_requested(interval) {
+                break;
+            }
+        } else {
+            std::thread::sleep(interval);
+        }
+    }
+
+    if raw {
+        let _ = disable_raw_mode();
+    }
+    cache::set_live(false);
+    println!();
+}
+
+/// Wait up to `timeout` for Ctrl-C or `q`; any other key is ignored.
+fn stop_requested(timeout: Duration) -> bool {
+    interrupt::wait_for_key(timeout, |key| {
+        interrupt::is_ctrl_c(key) || key.code == KeyCode::Char('q')
+    })
+}
+
+fn run_command(command: WatchCommands) {
+    match command {
+        WatchCommands::Show { subcommand } => handle_show_command(subcommand),
+    }
+}
+
+/// Compare the current output against the previous run and colour the rows
+/// that were added (green), changed (yellow) or removed (red).
+fn highlight(previous: Option<&[Block]>, current: &[Block]) -> (Vec<Block>, Changes) {
+    let mut changes = Changes::default();
+
+    let previous = match previous {
+        Some(previous) => previous,
+        None => return (current.to_vec(), changes),
+    };
+
+    let previous_lines: Vec<&String> = previous
+        .iter()
+        .filter_map(|block| match block {
+            Block::Line(line) => Some(line),
+            Block::Table(_) => None,
+        })
+        .collect();
+    let current_lines: Vec<&String> = current
+        .iter()
+        .filter_map(|block| match block {
+            Block::Line(line) => Some(line),
+            Block::Table(_) => None,
+        })
+        .collect();
+    let previous_tables: Vec<&Table> = previous
+        .iter()
+        .filter_map(|block| match block {
+            Block::Table(table) => Some(table),
+            Block::Line(_) => None,
+        })
+        .collect();
+
+    let mut highlighted = Vec::new();
+    let mut table_index = 0;
+
+    for block in current {
+        match block {
+            Block::Line(line) => {
+                if line.trim().is_empty() || previous_lines.contains(&line) {
+                    highlighted.push(block.clone());
+                } else {
+                    changes.added += 1;
+                    highlighted.push(Block::Line(line.clone().green().to_string()));
+                }
+            }
+            Block::Table(table) => {
+                let table = match previous_tables.get(table_index) {
+                    Some(before) => highlight_table(before, table, &mut changes),
+                    None => table.clone(),
+                };
+                highlighted.push(Block::Table(table));
+                table_index += 1;
+            }
+        }
+    }
+
+    for line in previous_lines {
+        if !line.trim().is_empty() && !current_lines.contains(&line) {
+            changes.removed += 1;
+            highlighted.push(Block::Line(format!("- {}", line).red().to_string()));
+        }
+    }
+
+    (highlighted, changes)
+}
+
+/// Rows are matched on their first column; the first row is treated as the header.
+fn highlight_table(before: &Table, after: &Table, changes: &mut Changes) -> Table {
+    let previous_rows: HashMap<String, String> = before
+        .row_iter()
+        .skip(1)
+        .map(|row| (row_key(row), output::row_text(row)))
+        .collect();
+
+    let mut table = after.clone();
+    let mut seen = Vec::new();
+
+    for row in table.row_iter_mut().skip(1) {
+        let key = row_key(row);
+        let colour = match previous_rows.get(&key) {
+            None => {
+                changes.added += 1;
+                Some(color::GREEN)
+            }
+            Some(text) if *text != output::row_text(row) => {
+                changes.changed += 1;
+                Some(color::YELLOW)
+            }
+            Some(_) => None,
+        };
+
+        if let Some(colour) = colour {
+            paint(row, colour);
+        }
+        seen.push(key);
+    }
+
+    for row in before.row_iter().skip(1) {
+        if !seen.contains(&row_key(row)) {
+            changes.removed += 1;
+            let mut row = row.clone();
+            paint(&mut row, color::RED);
+            table.add_row(row);
+        }
+    }
+
+    table
+}
+
+fn row_key(row: &Row) -> String {
+    row.get_cell(0)
+        .map(|cell| cell.get_content())
+        .unwrap_or_default()
+}
+
+fn paint(row: &mut Row, colour: color::Color) {
+    for cell in row.iter_mut() {
+        cell.style(Attr::ForegroundColor(colour));
+    }
+}
@@ -0,0 +1,317 @@
+// src/helpers/completion.rs
+
+use crate::api::authentication::auth;
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::issues::getissuelist::{self, Issue};
+use crate::api::sites::getsitelist::Site;
+use crate::api::wireless::rfprofile::{self, RFProfile};
+use crate::app::cache::{self, InventoryCache};
+use crate::app::config;
+use crate::commands::Cli;
+use clap::{CommandFactory, Subcommand};
+use clap_repl::reedline::{Completer, Span, Suggestion};
+use log::debug;
+use std::collections::{BTreeSet, HashMap};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// How often fetched values are refreshed in the background once populated.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+// Flags whose next word is a value rather than a positional argument.
+const VALUE_FLAGS: [&str; 24] = [
+    "--columns", "--sort-by", "--interval", "-i", "--site", "--label", "--status", "--mode",
+    "--vlan", "--version", "--save", "--context", "-U", "--type", "--timeout", "--image",
+    "--vars", "--devices", "--device", "--project", "--ip", "--credentials", "--tag", "--interfaces",
+];
+
+/// Values learned from earlier API calls, offered as tab completions.
+#[derive(Default)]
+struct CompletionValues {
+    hostnames: BTreeSet<String>,
+    ips: BTreeSet<String>,
+    macs: BTreeSet<String>,
+    device_ids: BTreeSet<String>,
+    wlc_ips: BTreeSet<String>,
+    ap_macs: BTreeSet<String>,
+    sites: BTreeSet<String>,
+    rf_profiles: BTreeSet<String>,
+    issue_ids: BTreeSet<String>,
+    /// When devices were last recorded; they are only re-fetched once a command has loaded them
+    last_refresh: Option<Instant>,
+    /// When sites, RF profiles and issue IDs were last fetched in the background
+    others_refreshed: Option<Instant>,
+}
+
+static VALUES: Mutex<Option<CompletionValues>> = Mutex::new(None);
+
+fn with_values<R>(f: impl FnOnce(&mut CompletionValues) -> R) -> R {
+    let mut values = VALUES.lock().unwrap_or_else(|e| e.into_inner());
+    f(values.get_or_insert_with(CompletionValues::default))
+}
+
+pub fn record_devices(devices: &[AllDevices]) {
+    with_values(|values| {
+        values.hostnames.clear();
+        values.ips.clear();
+        values.macs.clear();
+        values.device_ids.clear();
+        values.wlc_ips.clear();
+        values.ap_macs.clear();
+
+        for device in devices {
+            values.hostnames.extend(device.hostname.clone());
+            values.ips.extend(device.management_ip_address.clone());
+            values.macs.extend(device.mac_address.clone());
+            values.device_ids.extend(device.id.clone());
+            values.wlc_ips.extend(device.associated_wlc_ip.clone());
+            if device.family.as_deref() == Some("Unified AP") {
+                values.ap_macs.extend(device.mac_address.clone());
+            }
+        }
+        values.last_refresh = Some(Instant::now());
+    });
+}
+
+pub fn record_sites(sites: &[Site]) {
+    with_values(|values| {
+        values.sites = sites
+            .iter()
+            .filter_map(|site| site.site_name_hierarchy.clone())
+            .collect();
+    });
+}
+
+pub fn record_rf_profiles(profiles: &[RFProfile]) {
+    with_values(|values| {
+        values.rf_profiles = profiles
+            .iter()
+            .filter_map(|profile| profile.name.clone())
+            .collect();
+    });
+}
+
+pub fn record_issues(issues: &[Issue]) {
+    with_values(|values| {
+        values.issue_ids = issues
+            .iter()
+            .filter_map(|issue| issue.issueId.clone())
+            .collect();
+    });
+}
+
+/// Seed completions from the local inventory cache, if there is one.
+pub fn load_from_cache() {
+    if !config::get_cache_db_path().exists() {
+        return;
+    }
+    let cache = match InventoryCache::open() {
+        Ok(cache) => cache,
+        Err(e) => return debug!("Completion cache unavailable: {}", e),
+    };
+
+    if let Ok(Some((devices, _))) = cache.load_all::<AllDevices>(cache::DEVICES) {
+        record_devices(&devices);
+    }
+    if let Ok(Some((sites, _))) = cache.load_all::<Site>(cache::SITES) {
+        record_sites(&sites);
+    }
+}
+
+/// Fetch sites, RF profiles and issue IDs straight away, then periodically
+/// re-fetch them, and devices once a command has populated those.
+pub fn start_background_refresh() {
+    thread::spawn(|| {
+        refresh_in_background(false);
+        loop {
+            thread::sleep(Duration::from_secs(30));
+
+            let due = |refreshed: Option<Instant>| refreshed.is_none_or(|at| at.elapsed() >= REFRESH_INTERVAL);
+            let (devices_due, others_due) = with_values(|values| {
+                (
+                    values.last_refresh.is_some() && due(values.last_refresh),
+                    due(values.others_refreshed),
+                )
+            });
+            if devices_due || others_due {
+                refresh_in_background(devices_due);
+            }
+        }
+    });
+}
+
+fn refresh_in_background(devices: bool) {
+    if !config::get_config_path().exists() {
+        return;
+    }
+    match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime.block_on(refresh(devices)),
+        Err(e) => debug!("Completion refresh could not start a runtime: {}", e),
+    }
+}
+
+async fn refresh(devices: bool) {
+    let config = match config::load_config() {
+        Ok(config) => config,
+        Err(e) => return debug!("Completion refresh skipped: {}", e),
+    };
+    // Failed fetches wait for the next interval rather than retrying every poll
+    with_values(|values| values.others_refreshed = Some(Instant::now()));
+
+    if devices {
+        match cache::refresh_devices(&config).await {
+            Ok(devices) => record_devices(&devices),
+            Err(e) => debug!("Completion refresh of devices failed: {}", e),
+        }
+    }
+    match cache::refresh_sites(&config).await {
+        Ok(sites) => record_sites(&sites),
+        Err(e) => debug!("Completion refresh of sites failed: {}", e),
+    }
+
+    let token = match auth::authenticate(&config).await {
+        Ok(token) => token,
+        Err(e) => return debug!("Completion refresh skipped: {}", e),
+    };
+    match rfprofile::get_all_rf_profiles(&config, &token).await {
+        Ok(profiles) => record_rf_profiles(&profiles),
+        Err(e) => debug!("Completion refresh of RF profiles failed: {}", e),
+    }
+    match getissuelist::get_issue_list(&config, &token, &HashMap::new()).await {
+        Ok(response) => record_issues(&response.response.unwrap_or_default()),
+        Err(e) => debug!("Completion refresh of issues failed: {}", e),
+    }
+}
+
+/// Completes command keywords through clap and argument values from the
+/// values recorded above.
+pub struct CatalyshCompleter;
+
+impl Completer for CatalyshCompleter {
+    fn complete(&mut self, line: &str, pos: usize) -> Vec<Suggestion> {
+        let line = &line[..pos];
+        let mut words: Vec<&str> = line.split_whitespace().collect();
+        let partial = if line.ends_with(char::is_whitespace) {
+            ""
+        } else {
+            words.pop().unwrap_or("")
+        };
+        let span = Span::new(pos - partial.len(), pos);
+
+        match candidates(&words) {
+            Some(values) => {
+                let prefix = partial.trim_start_matches(['"', '\'']).to_lowercase();
+                values
+                    .into_iter()
+                    .filter(|value| value.to_lowercase().starts_with(&prefix))
+                    .map(|value| suggestion(quote(&value), None, span))
+                    .collect()
+            }
+            None => complete_command(line, span),
+        }
+    }
+}
+
+/// Values for the argument being typed after `words`, if it is one we know about.
+fn candidates(words: &[&str]) -> Option<Vec<String>> {
+    if words.last() == Some(&"--site") {
+        return Some(with_values(|values| values.sites.iter().cloned().collect()));
+    }
+
+    let mut positional = Vec::new();
+    let mut skip_value = false;
+    for word in words {
+        if skip_value {
+            skip_value = false;
+        } else if VALUE_FLAGS.contains(word) {
+            skip_value = true;
+        } else if !word.starts_with('-') {
+            positional.push(*word);
+        }
+    }
+
+    // `watch show ...` completes the same as `show ...`
+    let start = positional
+        .iter()
+        .position(|word| matches!(*word, "show" | "diff" | "compliance" | "image" | "device"))?;
+
+    with_values(|values| {
+        let set = match &positional[start..] {
+            ["show", "device", "list" | "detail", "hostname"] => &values.hostnames,
+            ["show", "interface"] | ["show", "interface", "detail"] => &values.hostnames,
+            ["show", "device", "config"] => &values.hostnames,
+            ["diff", "device", "config"] | ["diff", "device", "config", _] => &values.hostnames,
+            ["show", "compliance", "detail"] | ["compliance", "run"] => &values.hostnames,
+            ["show", "image", "readiness"] => &values.hostnames,
+            ["show", "wlc", "detail"] => &values.hostnames,
+            ["image", "distribute" | "activate"] => &values.hostnames,
+            ["device", "resync" | "delete" | "assign-site"] => &values.hostnames,
+            ["show", "device", "list" | "detail" | "enrichment", "ip"] => &values.ips,
+            ["show", "device", "detail" | "enrichment", "mac"] => &values.macs,
+            ["show", "device", "list", "wlc"] => &values.wlc_ips,
+            ["show", "ap", "config"] => &values.ap_macs,
+            ["show", "ap", "rf-profile"] => &values.rf_profiles,
+            ["show", "issue", "list", "device-id"] => &values.device_ids,
+            ["show", "issue", "list", "mac-address"] => &values.macs,
+            ["show", "issue", "detail"] => &values.issue_ids,
+            _ => return None,
+        };
+        Some(set.iter().cloned().collect())
+    })
+}
+
+fn complete_command(line: &str, span: Span) -> Vec<Suggestion> {
+    let cmd = Cli::command();
+    let mut cmd = clap_complete::dynamic::command::CompleteCommand::augment_subcommands(cmd);
+    let mut args = std::iter::once(String::new())
+        .chain(shlex::Shlex::new(line))
+        .map(OsString::from)
+        .collect::<Vec<_>>();
+    if line.ends_with(char::is_whitespace) {
+        args.push(OsString::new());
+    }
+    let arg_index = args.len() - 1;
+
+    let candidates = match clap_complete::dynamic::complete(
+        &mut cmd,
+        args,
+        arg_index,
+        Some(PathBuf::from(".").as_path()),
+    ) {
+        Ok(candidates) => candidates,
+        Err(_) => return Vec::new(),
+    };
+
+    candidates
+        .into_iter()
+        .map(|c| {
+            suggestion(
+                c.get_content().to_string_lossy().into_owned(),
+                c.get_help().map(|help| help.to_string()),
+                span,
+            )
+        })
+        .collect()
+}
+
+fn suggestion(value: String, description: Option<String>, span: Span) -> Suggestion {
+    Suggestion {
+        value,
+        description,
+        style: None,
+        extra: None,
+        span,
+        append_whitespace: true,
+    }
+}
+
+fn quote(value: &str) -> String {
+    if value.contains(char::is_whitespace) {
+        format!("\"{}\"", value)
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,47 @@
+// src/helpers/concurrency.rs
+
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::{JoinError, JoinSet};
+
+/// Requests allowed in flight at once when fanning out over many devices.
+/// Catalyst Center answers bursts above its API rate limit with 429.
+pub const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// A `JoinSet` that runs at most `MAX_CONCURRENT_REQUESTS` of its tasks at a time.
+pub struct LimitedJoinSet<T> {
+    tasks: JoinSet<T>,
+    permits: Arc<Semaphore>,
+}
+
+impl<T: Send + 'static> LimitedJoinSet<T> {
+    pub fn new() -> Self {
+        LimitedJoinSet {
+            tasks: JoinSet::new(),
+            permits: Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS)),
+        }
+    }
+
+    /// Queue `task`; it starts once fewer than the limit are running.
+    pub fn spawn<F>(&mut self, task: F)
+    where
+        F: Future<Output = T> + Send + 'static,
+    {
+        let permits = Arc::clone(&self.permits);
+        self.tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            task.await
+        });
+    }
+
+    pub async fn join_next(&mut self) -> Option<Result<T, JoinError>> {
+        self.tasks.join_next().await
+    }
+}
+
+impl<T: Send + 'static> Default for LimitedJoinSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,150 @@
+// src/helpers/devicefilter.rs
+
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::sites::getsitelist::Site;
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// Find one device by hostname (with or without its domain), management IP,
+/// serial number or id.
+pub fn find_device<'a>(devices: &'a [AllDevices], name: &str) -> Result<&'a AllDevices> {
+    let query = name.to_lowercase();
+    devices
+        .iter()
+        .find(|device| {
+            device.hostname.as_deref().is_some_and(|hostname| {
+                let hostname = hostname.to_lowercase();
+                hostname == query || hostname.split('.').next() == Some(query.as_str())
+            }) || [&device.management_ip_address, &device.serial_number, &device.id]
+                .iter()
+                .any(|value| value.as_deref().is_some_and(|value| value.eq_ignore_ascii_case(&query)))
+        })
+        .ok_or_else(|| anyhow!("No device found matching '{}'", name))
+}
+
+/// Whether `query` names the interface `name`, allowing IOS-style
+/// abbreviations such as `Gi1/0/1` for `GigabitEthernet1/0/1`.
+pub fn interface_matches(name: &str, query: &str) -> bool {
+    if name.eq_ignore_ascii_case(query) {
+        return true;
+    }
+
+    let split = |value: &str| {
+        let idx = value.find(|c: char| c.is_ascii_digit()).unwrap_or(value.len());
+        (value[..idx].to_lowercase(), value[idx..].to_lowercase())
+    };
+    let (name_type, name_number) = split(name);
+    let (query_type, query_number) = split(query);
+
+    !query_type.is_empty() && name_type.starts_with(&query_type) && name_number == query_number
+}
+
+/// Whether a site hierarchy is `site` or one of its children. The leading
+/// `Global/` is optional.
+pub fn in_site(hierarchy: &str, site: &str) -> bool {
+    let (hierarchy, site) = (normalize_site(hierarchy), normalize_site(site));
+    hierarchy == site || hierarchy.starts_with(&format!("{}/", site))
+}
+
+/// Whether two site hierarchies name the same site.
+pub fn same_site(hierarchy: &str, site: &str) -> bool {
+    normalize_site(hierarchy) == normalize_site(site)
+}
+
+/// The site whose hierarchy is exactly `site`. The leading `Global/` is optional.
+pub fn find_site<'a>(sites: &'a [Site], site: &str) -> Result<&'a Site> {
+    sites
+        .iter()
+        .find(|candidate| {
+            candidate
+                .site_name_hierarchy
+                .as_deref()
+                .is_some_and(|hierarchy| same_site(hierarchy, site))
+        })
+        .ok_or_else(|| anyhow!("No site '{}'", site))
+}
+
+fn normalize_site(value: &str) -> String {
+    let value = value.trim_end_matches('/').to_lowercase();
+    value.strip_prefix("global/").map(str::to_string).unwrap_or(value)
+}
+
+/// Whether a hostname matches a pattern where `*` stands for any run of
+/// characters, e.g. `edge-*` or `*-core-*`. Case-insensitive.
+pub fn hostname_matches(hostname: &str, pattern: &str) -> bool {
+    let (hostname, pattern) = (hostname.to_lowercase(), pattern.to_lowercase());
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return hostname == pattern || hostname.split('.').next() == Some(pattern.as_str());
+    }
+
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    // Stripping the ends in turn also stops them overlapping
+    let mut rest = match hostname
+        .strip_prefix(first)
+        .and_then(|rest| rest.strip_suffix(last))
+    {
+        Some(rest) => rest,
+        None => return false,
+    };
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Devices other than access points whose hostname matches `pattern`.
+pub fn select_devices<'a>(devices: &'a [AllDevices], pattern: &str) -> Vec<&'a AllDevices> {
+    devices
+        .iter()
+        .filter(|device| device.family.as_deref() != Some("Unified AP"))
+        .filter(|device| {
+            device
+                .hostname
+                .as_deref()
+                .is_some_and(|hostname| hostname_matches(hostname, pattern))
+        })
+        .collect()
+}
+
+/// Keep only devices in `site` or below it, given each device's site hierarchy by id.
+pub fn retain_site(devices: &mut Vec<&AllDevices>, sites: &HashMap<String, String>, site: &str) {
+    devices.retain(|device| {
+        let hierarchy = device.id.as_ref().and_then(|id| sites.get(id));
+        hierarchy.is_some_and(|hierarchy| in_site(hierarchy, site))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hostname_without_wildcard_matches_exactly_or_without_domain() {
+        assert!(hostname_matches("edge-1.example.com", "edge-1"));
+        assert!(hostname_matches("EDGE-1", "edge-1"));
+        assert!(!hostname_matches("edge-10", "edge-1"));
+    }
+
+    #[test]
+    fn hostname_wildcards() {
+        assert!(hostname_matches("edge-1", "edge-*"));
+        assert!(hostname_matches("sjc-core-1", "*-core-*"));
+        assert!(hostname_matches("anything", "*"));
+        assert!(!hostname_matches("core-1", "edge-*"));
+        assert!(!hostname_matches("sjc-edge-1", "*-core-*"));
+        // Prefix and suffix may not overlap
+        assert!(!hostname_matches("ab", "ab*b"));
+    }
+
+    #[test]
+    fn hostname_wildcards_handle_non_ascii() {
+        assert!(!hostname_matches("aé", "*x"));
+        assert!(!hostname_matches("éa", "x*"));
+        assert!(hostname_matches("café-1", "caf*-1"));
+        assert!(hostname_matches("edge-é", "*é"));
+    }
+}
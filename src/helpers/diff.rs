@@ -0,0 +1,195 @@
+// src/helpers/diff.rs
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Change<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+/// Unified diff of two texts, line by line, with `context` unchanged lines
+/// around each change. Empty when the texts are the same.
+pub fn unified(old: &str, new: &str, old_name: &str, new_name: &str, context: usize) -> Vec<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let changes = diff_lines(&old, &new);
+    if changes.iter().all(|change| matches!(change, Change::Equal(_))) {
+        return Vec::new();
+    }
+
+    // Line counts consumed on each side before each change
+    let mut positions = Vec::with_capacity(changes.len() + 1);
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for change in &changes {
+        positions.push((old_pos, new_pos));
+        match change {
+            Change::Equal(_) => (old_pos, new_pos) = (old_pos + 1, new_pos + 1),
+            Change::Delete(_) => old_pos += 1,
+            Change::Insert(_) => new_pos += 1,
+        }
+    }
+    positions.push((old_pos, new_pos));
+
+    // Group changes closer than twice the context into one hunk
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (idx, change) in changes.iter().enumerate() {
+        if matches!(change, Change::Equal(_)) {
+            continue;
+        }
+        let start = idx.saturating_sub(context);
+        let end = (idx + context + 1).min(changes.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut lines = vec![format!("--- {}", old_name), format!("+++ {}", new_name)];
+    for (start, end) in hunks {
+        let (old_start, new_start) = positions[start];
+        let (old_end, new_end) = positions[end];
+        lines.push(format!(
+            "@@ -{} +{} @@",
+            range(old_start, old_end - old_start),
+            range(new_start, new_end - new_start)
+        ));
+        for change in &changes[start..end] {
+            lines.push(match change {
+                Change::Equal(line) => format!(" {}", line),
+                Change::Delete(line) => format!("-{}", line),
+                Change::Insert(line) => format!("+{}", line),
+            });
+        }
+    }
+    lines
+}
+
+/// Hunk range in unified diff notation. An empty range names the line before it.
+fn range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Change<'a>> {
+    // Configs mostly differ in a few places, so trim the common ends first
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut changes: Vec<Change> = old[..prefix].iter().map(|line| Change::Equal(line)).collect();
+    hirschberg(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+        &mut changes,
+    );
+    changes.extend(old[old.len() - suffix..].iter().map(|line| Change::Equal(line)));
+    changes
+}
+
+/// Longest-common-subsequence alignment in linear space.
+fn hirschberg<'a>(old: &[&'a str], new: &[&'a str], changes: &mut Vec<Change<'a>>) {
+    if old.is_empty() {
+        changes.extend(new.iter().map(|line| Change::Insert(line)));
+        return;
+    }
+    if new.is_empty() {
+        changes.extend(old.iter().map(|line| Change::Delete(line)));
+        return;
+    }
+    if old.len() == 1 {
+        match new.iter().position(|line| *line == old[0]) {
+            Some(idx) => {
+                changes.extend(new[..idx].iter().map(|line| Change::Insert(line)));
+                changes.push(Change::Equal(old[0]));
+                changes.extend(new[idx + 1..].iter().map(|line| Change::Insert(line)));
+            }
+            None => {
+                changes.push(Change::Delete(old[0]));
+                changes.extend(new.iter().map(|line| Change::Insert(line)));
+            }
+        }
+        return;
+    }
+
+    let mid = old.len() / 2;
+    let forward = lcs_row(&old[..mid], new);
+    let old_tail: Vec<&str> = old[mid..].iter().rev().copied().collect();
+    let new_rev: Vec<&str> = new.iter().rev().copied().collect();
+    let backward = lcs_row(&old_tail, &new_rev);
+    let split = (0..=new.len())
+        .max_by_key(|&idx| (forward[idx] + backward[new.len() - idx], std::cmp::Reverse(idx)))
+        .unwrap_or(0);
+
+    hirschberg(&old[..mid], &new[..split], changes);
+    hirschberg(&old[mid..], &new[split..], changes);
+}
+
+/// LCS lengths of all of `old` against each prefix of `new`.
+fn lcs_row(old: &[&str], new: &[&str]) -> Vec<usize> {
+    let mut row = vec![0; new.len() + 1];
+    for line in old {
+        let mut diagonal = 0;
+        for (idx, other) in new.iter().enumerate() {
+            let above = row[idx + 1];
+            row[idx + 1] = if line == other {
+                diagonal + 1
+            } else {
+                above.max(row[idx])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_texts_have_no_diff() {
+        assert!(unified("a\nb\n", "a\nb\n", "old", "new", 3).is_empty());
+    }
+
+    #[test]
+    fn changed_line_with_context() {
+        let diff = unified("a\nb\nc\nd\ne\n", "a\nb\nX\nd\ne\n", "old", "new", 1);
+        assert_eq!(diff, ["--- old", "+++ new", "@@ -2,3 +2,3 @@", " b", "-c", "+X", " d"]);
+    }
+
+    #[test]
+    fn distant_changes_get_separate_hunks() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        let new = "1\nX\n3\n4\n5\n6\nY\n8\n";
+        let diff = unified(old, new, "old", "new", 1);
+        assert_eq!(
+            diff,
+            [
+                "--- old", "+++ new",
+                "@@ -1,3 +1,3 @@", " 1", "-2", "+X", " 3",
+                "@@ -6,3 +6,3 @@", " 6", "-7", "+Y", " 8",
+            ]
+        );
+    }
+
+    #[test]
+    fn insertions_into_empty_text() {
+        assert_eq!(
+            unified("", "a\nb\n", "old", "new", 3),
+            ["--- old", "+++ new", "@@ -0,0 +1,2 @@", "+a", "+b"]
+        );
+    }
+
+    #[test]
+    fn aligns_on_common_lines() {
+        let diff = unified("a\nb\nc\nd\n", "b\nc\nd\ne\n", "old", "new", 0);
+        assert_eq!(diff, ["--- old", "+++ new", "@@ -1 +0,0 @@", "-a", "@@ -4,0 +4 @@", "+e"]);
+    }
+}
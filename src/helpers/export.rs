@@ -0,0 +1,311 @@
+// src/helpers/export.rs
+
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::commands::export::GroupBy;
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// The parts of a device that are exported to other tools.
+pub struct ExportHost {
+    pub name: String,
+    pub ip: Option<String>,
+    pub platform: Option<String>,
+    pub software_type: Option<String>,
+    pub software_version: Option<String>,
+    pub family: Option<String>,
+    pub role: Option<String>,
+    pub serial: Option<String>,
+    pub site: Option<String>,
+}
+
+impl ExportHost {
+    /// `sites` maps device ids to their site hierarchy. Devices without a
+    /// hostname are named by management IP; those with neither are left out.
+    pub fn from_devices(devices: &[AllDevices], sites: &HashMap<String, String>) -> Vec<Self> {
+        let mut hosts: Vec<Self> = devices
+            .iter()
+            .filter_map(|device| {
+                Some(ExportHost {
+                    name: device
+                        .hostname
+                        .clone()
+                        .or_else(|| device.management_ip_address.clone())?,
+                    ip: device.management_ip_address.clone(),
+                    platform: device.platform_id.clone(),
+                    software_type: device.software_type.clone(),
+                    software_version: device.software_version.clone(),
+                    family: device.family.clone(),
+                    role: device.role.clone(),
+                    serial: device.serial_number.clone(),
+                    site: device.id.as_ref().and_then(|id| sites.get(id)).cloned(),
+                })
+            })
+            .collect();
+        hosts.sort_by(|a, b| a.name.cmp(&b.name));
+        hosts
+    }
+
+    fn group_value(&self, group: GroupBy) -> Option<&str> {
+        match group {
+            GroupBy::Site => self.site.as_deref(),
+            GroupBy::Role => self.role.as_deref(),
+            GroupBy::Family => self.family.as_deref(),
+        }
+    }
+
+    /// Group names for this host, e.g. `site_usa_sjc_bldg23` or `role_access`.
+    fn groups(&self, group_by: &[GroupBy]) -> Vec<String> {
+        group_by
+            .iter()
+            .filter_map(|&group| {
+                let prefix = match group {
+                    GroupBy::Site => "site",
+                    GroupBy::Role => "role",
+                    GroupBy::Family => "family",
+                };
+                let value = self.group_value(group)?;
+                let value = value.strip_prefix("Global/").unwrap_or(value);
+                Some(format!("{}_{}", prefix, group_name(value)))
+            })
+            .collect()
+    }
+
+    /// Leaf site name, as NetBox expects.
+    fn site_name(&self) -> Option<&str> {
+        self.site.as_deref().and_then(|site| site.rsplit('/').next())
+    }
+}
+
+/// Ansible group names may only contain letters, digits and underscores.
+fn group_name(value: &str) -> String {
+    let name: String = value
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn ansible_network_os(software_type: Option<&str>) -> Option<&'static str> {
+    match software_type? {
+        "IOS" | "IOS-XE" => Some("cisco.ios.ios"),
+        "NX-OS" => Some("cisco.nxos.nxos"),
+        "IOS-XR" => Some("cisco.iosxr.iosxr"),
+        "Cisco Controller" => Some("cisco.aireos.aireos"),
+        _ => None,
+    }
+}
+
+/// Netmiko platform names, which Nornir's connection plugins use.
+fn nornir_platform(software_type: Option<&str>) -> Option<&'static str> {
+    match software_type? {
+        "IOS" => Some("cisco_ios"),
+        "IOS-XE" => Some("cisco_xe"),
+        "NX-OS" => Some("cisco_nxos"),
+        "IOS-XR" => Some("cisco_xr"),
+        "Cisco Controller" => Some("cisco_wlc"),
+        _ => None,
+    }
+}
+
+/// Ansible dynamic inventory JSON, as returned by an inventory script's `--list`.
+pub fn to_ansible(hosts: &[ExportHost], group_by: &[GroupBy]) -> Result<String> {
+    let mut hostvars = Map::new();
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for host in hosts {
+        hostvars.insert(
+            host.name.clone(),
+            json!({
+                "ansible_host": host.ip,
+                "ansible_network_os": ansible_network_os(host.software_type.as_deref()),
+                "platform": host.platform,
+                "software_version": host.software_version,
+                "family": host.family,
+                "role": host.role,
+                "serial_number": host.serial,
+                "site": host.site,
+            }),
+        );
+
+        let host_groups = host.groups(group_by);
+        if host_groups.is_empty() {
+            groups.entry("ungrouped".to_string()).or_default().push(host.name.clone());
+        }
+        for group in host_groups {
+            groups.entry(group).or_default().push(host.name.clone());
+        }
+    }
+
+    let mut inventory = Map::new();
+    inventory.insert("_meta".to_string(), json!({ "hostvars": hostvars }));
+    inventory.insert("all".to_string(), json!({ "children": groups.keys().collect::<Vec<_>>() }));
+    for (group, members) in groups {
+        inventory.insert(group, json!({ "hosts": members }));
+    }
+
+    Ok(serde_json::to_string_pretty(&Value::Object(inventory))?)
+}
+
+#[derive(Serialize)]
+struct NornirHost<'a> {
+    hostname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    groups: Vec<String>,
+    data: BTreeMap<&'static str, Option<&'a str>>,
+}
+
+/// Nornir SimpleInventory `hosts.yaml`. Groups referenced here must exist in `groups.yaml`.
+pub fn to_nornir(hosts: &[ExportHost], group_by: &[GroupBy]) -> Result<String> {
+    let inventory: BTreeMap<&str, NornirHost> = hosts
+        .iter()
+        .map(|host| {
+            let data = BTreeMap::from([
+                ("platform_id", host.platform.as_deref()),
+                ("software_version", host.software_version.as_deref()),
+                ("family", host.family.as_deref()),
+                ("role", host.role.as_deref()),
+                ("serial_number", host.serial.as_deref()),
+                ("site", host.site.as_deref()),
+            ]);
+            let nornir_host = NornirHost {
+                hostname: host.ip.as_deref(),
+                platform: nornir_platform(host.software_type.as_deref()),
+                groups: host.groups(group_by),
+                data,
+            };
+            (host.name.as_str(), nornir_host)
+        })
+        .collect();
+
+    Ok(serde_yaml::to_string(&inventory)?)
+}
+
+/// CSV for NetBox's device bulk import. Sites, roles and device types must
+/// already exist in NetBox.
+pub fn to_netbox_csv(hosts: &[ExportHost]) -> String {
+    let mut lines = vec![
+        "name,status,role,manufacturer,device_type,platform,site,serial,description".to_string(),
+    ];
+    for host in hosts {
+        let description = host.ip.as_ref().map(|ip| format!("Management IP {}", ip));
+        let fields = [
+            Some(host.name.as_str()),
+            Some("active"),
+            host.role.as_deref(),
+            Some("Cisco"),
+            host.platform.as_deref(),
+            host.software_type.as_deref(),
+            host.site_name(),
+            host.serial.as_deref(),
+            description.as_deref(),
+        ];
+        lines.push(
+            fields
+                .iter()
+                .map(|field| csv_field(field.unwrap_or_default()))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+    lines.join("\n")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(name: &str, site: Option<&str>) -> ExportHost {
+        ExportHost {
+            name: name.to_string(),
+            ip: Some("10.0.0.1".to_string()),
+            platform: Some("C9300-48P".to_string()),
+            software_type: Some("IOS-XE".to_string()),
+            software_version: Some("17.9.4".to_string()),
+            family: Some("Switches and Hubs".to_string()),
+            role: Some("ACCESS".to_string()),
+            serial: Some("FOC1234".to_string()),
+            site: site.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn from_devices_falls_back_to_management_ip() {
+        let devices: Vec<AllDevices> = serde_json::from_value(json!([
+            { "hostname": "edge-1", "managementIpAddress": "10.0.0.1", "id": "d1" },
+            { "managementIpAddress": "10.0.0.2" },
+            { "serialNumber": "FOC9999" },
+        ]))
+        .unwrap();
+        let sites = HashMap::from([("d1".to_string(), "Global/USA/SJC".to_string())]);
+
+        let hosts = ExportHost::from_devices(&devices, &sites);
+        let names: Vec<&str> = hosts.iter().map(|host| host.name.as_str()).collect();
+        assert_eq!(names, ["10.0.0.2", "edge-1"]);
+        assert_eq!(hosts[1].site.as_deref(), Some("Global/USA/SJC"));
+    }
+
+    #[test]
+    fn group_names_are_sanitised() {
+        let host = host("edge-1", Some("Global/USA/San Jose/Bldg 23"));
+        assert_eq!(
+            host.groups(&[GroupBy::Site, GroupBy::Role, GroupBy::Family]),
+            ["site_usa_san_jose_bldg_23", "role_access", "family_switches_and_hubs"]
+        );
+    }
+
+    #[test]
+    fn ansible_inventory_groups_hosts() {
+        let hosts = [host("edge-1", Some("Global/SJC")), host("edge-2", None)];
+        let inventory: Value =
+            serde_json::from_str(&to_ansible(&hosts, &[GroupBy::Site]).unwrap()).unwrap();
+
+        assert_eq!(inventory["site_sjc"]["hosts"], json!(["edge-1"]));
+        assert_eq!(inventory["ungrouped"]["hosts"], json!(["edge-2"]));
+        assert_eq!(inventory["all"]["children"], json!(["site_sjc", "ungrouped"]));
+        let vars = &inventory["_meta"]["hostvars"]["edge-1"];
+        assert_eq!(vars["ansible_host"], "10.0.0.1");
+        assert_eq!(vars["ansible_network_os"], "cisco.ios.ios");
+    }
+
+    #[test]
+    fn nornir_inventory_uses_netmiko_platforms() {
+        let yaml = to_nornir(&[host("edge-1", None)], &[GroupBy::Role]).unwrap();
+        let inventory: Value = serde_yaml::from_str(&yaml).unwrap();
+
+        let edge = &inventory["edge-1"];
+        assert_eq!(edge["hostname"], "10.0.0.1");
+        assert_eq!(edge["platform"], "cisco_xe");
+        assert_eq!(edge["groups"], json!(["role_access"]));
+        assert_eq!(edge["data"]["serial_number"], "FOC1234");
+    }
+
+    #[test]
+    fn netbox_csv_quotes_fields() {
+        let mut host = host("edge,1", Some("Global/USA/SJC"));
+        host.role = Some("say \"hi\"".to_string());
+        let csv = to_netbox_csv(&[host]);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "name,status,role,manufacturer,device_type,platform,site,serial,description");
+        assert_eq!(
+            lines[1],
+            "\"edge,1\",active,\"say \"\"hi\"\"\",Cisco,C9300-48P,IOS-XE,SJC,FOC1234,Management IP 10.0.0.1"
+        );
+    }
+}
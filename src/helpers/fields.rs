@@ -0,0 +1,485 @@
+// src/helpers/fields.rs
+
+use crate::api::clients::getclientdetail::ClientDetail;
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::interfaces::getinterfaces::Interface;
+use crate::api::issues::getissuelist::Issue;
+use crate::commands::show::table::TableArgs;
+use anyhow::{anyhow, Result};
+use chrono::DateTime;
+use prettytable::{row, Cell, Row, Table};
+use serde::Serialize;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// A named field that can be shown as a table column.
+pub struct Field<T> {
+    pub name: &'static str,
+    pub header: &'static str,
+    pub value: fn(&T) -> Option<String>,
+}
+
+/// Field registry for a type returned by the API.
+///
+/// Columns can be any registered field name or, failing that, any field the
+/// API returned, looked up by its JSON key.
+pub trait FieldRegistry: Serialize + Sized {
+    fn fields() -> Vec<Field<Self>>;
+    fn default_columns() -> &'static [&'static str];
+}
+
+enum Column<T> {
+    Known(Field<T>),
+    Raw(String),
+}
+
+impl<T: FieldRegistry> Column<T> {
+    fn header(&self) -> String {
+        match self {
+            Column::Known(field) => field.header.to_string(),
+            Column::Raw(key) => key.clone(),
+        }
+    }
+
+    fn value(&self, item: &T, raw: &Value) -> Option<String> {
+        match self {
+            Column::Known(field) => (field.value)(item),
+            Column::Raw(key) => raw_value(raw, key),
+        }
+    }
+}
+
+/// Build a table with one row per item, honouring `--columns`, `--sort-by` and `--wide`.
+pub fn build_table<T: FieldRegistry>(items: &[T], args: &TableArgs) -> Result<Table> {
+    let raw: Vec<Value> = items
+        .iter()
+        .map(|item| serde_json::to_value(item).unwrap_or(Value::Null))
+        .collect();
+
+    let columns = selected_columns::<T>(args, &raw)?;
+
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    if let Some(ref sort_by) = args.sort_by {
+        let (name, descending) = match sort_by.rsplit_once(':') {
+            Some((name, "desc")) => (name, true),
+            Some((name, "asc")) => (name, false),
+            _ => (sort_by.as_str(), false),
+        };
+        let column = resolve_column::<T>(name, &raw)?;
+        let keys: Vec<Option<String>> = items
+            .iter()
+            .zip(&raw)
+            .map(|(item, raw)| column.value(item, raw))
+            .collect();
+        order.sort_by(|&a, &b| {
+            let ordering = compare_values(keys[a].as_deref(), keys[b].as_deref());
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    let mut table = Table::new();
+    table.add_row(Row::new(
+        columns.iter().map(|column| Cell::new(&column.header())).collect(),
+    ));
+    for idx in order {
+        table.add_row(Row::new(
+            columns
+                .iter()
+                .map(|column| {
+                    Cell::new(
+                        &column
+                            .value(&items[idx], &raw[idx])
+                            .unwrap_or_else(|| "N/A".to_string()),
+                    )
+                })
+                .collect(),
+        ));
+    }
+
+    Ok(table)
+}
+
+/// Build a Field/Value table for a single item.
+pub fn build_detail_table<T: FieldRegistry>(item: &T, args: &TableArgs) -> Result<Table> {
+    let raw = vec![serde_json::to_value(item).unwrap_or(Value::Null)];
+    let columns = selected_columns::<T>(args, &raw)?;
+
+    let mut table = Table::new();
+    table.add_row(row!["Field", "Value"]);
+    for column in columns {
+        table.add_row(row![
+            column.header(),
+            column
+                .value(item, &raw[0])
+                .unwrap_or_else(|| "N/A".to_string())
+        ]);
+    }
+    Ok(table)
+}
+
+fn selected_columns<T: FieldRegistry>(args: &TableArgs, raw: &[Value]) -> Result<Vec<Column<T>>> {
+    if let Some(ref names) = args.columns {
+        return names
+            .iter()
+            .map(|name| resolve_column::<T>(name.trim(), raw))
+            .collect();
+    }
+
+    if args.wide {
+        return Ok(T::fields().into_iter().map(Column::Known).collect());
+    }
+
+    T::default_columns()
+        .iter()
+        .map(|name| resolve_column::<T>(name, raw))
+        .collect()
+}
+
+fn resolve_column<T: FieldRegistry>(name: &str, raw: &[Value]) -> Result<Column<T>> {
+    if let Some(field) = T::fields()
+        .into_iter()
+        .find(|field| field.name.eq_ignore_ascii_case(name))
+    {
+        return Ok(Column::Known(field));
+    }
+
+    let wanted = normalize(name);
+    let key = raw
+        .iter()
+        .filter_map(Value::as_object)
+        .flat_map(|object| object.keys())
+        .find(|key| normalize(key) == wanted);
+
+    match key {
+        Some(key) => Ok(Column::Raw(key.clone())),
+        None if raw.is_empty() => Ok(Column::Raw(name.to_string())),
+        None => Err(anyhow!(
+            "Unknown column '{}'. Available columns: {}",
+            name,
+            T::fields()
+                .iter()
+                .map(|field| field.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn raw_value(raw: &Value, key: &str) -> Option<String> {
+    match raw.get(key)? {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+/// Numbers sort numerically, everything else case-insensitively; missing values sort last.
+fn compare_values(a: Option<&str>, b: Option<&str>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => match (a.parse::<f64>(), b.parse::<f64>()) {
+            (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            _ => a.to_lowercase().cmp(&b.to_lowercase()),
+        },
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    DateTime::from_timestamp_millis(timestamp)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "Invalid Timestamp".to_string())
+}
+
+fn json_text(value: &Option<Value>) -> Option<String> {
+    match value.as_ref()? {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+macro_rules! field {
+    ($name:expr, $header:expr, $value:expr) => {
+        Field {
+            name: $name,
+            header: $header,
+            value: $value,
+        }
+    };
+}
+
+impl FieldRegistry for AllDevices {
+    fn fields() -> Vec<Field<Self>> {
+        vec![
+            field!("hostname", "Hostname", |d| d.hostname.clone()),
+            field!("ip", "Management IP", |d| d.management_ip_address.clone()),
+            field!("serial", "Serial Number", |d| d.serial_number.clone()),
+            field!("mac", "MAC Address", |d| d.mac_address.clone()),
+            field!("ap_ethernet_mac", "Ethernet MAC Address", |d| d.ap_ethernet_mac_address.clone()),
+            field!("platform", "Platform ID", |d| d.platform_id.clone()),
+            field!("version", "Software Version", |d| d.software_version.clone()),
+            field!("role", "Role", |d| d.role.clone()),
+            field!("family", "Family", |d| d.family.clone()),
+            field!("series", "Series", |d| d.series.clone()),
+            field!("software_type", "Software Type", |d| d.software_type.clone()),
+            field!("reachability", "Reachability", |d| d.reachability_status.clone()),
+            field!("reachability_reason", "Reachability Failure Reason", |d| d.reachability_failure_reason.clone()),
+            field!("uptime", "Uptime", |d| d.up_time.clone()),
+            field!("uptime_seconds", "Uptime (s)", |d| d.uptime_seconds.map(|v| v.to_string())),
+            field!("boot_time", "Boot Time", |d| d.boot_date_time.clone()),
+            field!("last_updated", "Last Updated", |d| d.last_update_time.map(format_timestamp)),
+            field!("collection_status", "Collection Status", |d| d.collection_status.clone()),
+            field!("collection_interval", "Collection Interval", |d| d.collection_interval.clone()),
+            field!("inventory_status", "Inventory Status", |d| d.inventory_status_detail.clone()),
+            field!("management_state", "Management State", |d| d.management_state.clone()),
+            field!("wlc_ip", "WLC IP", |d| d.associated_wlc_ip.clone()),
+            field!("ap_manager_ip", "AP Manager IP", |d| d.ap_manager_interface_ip.clone()),
+            field!("interfaces", "Interfaces", |d| d.interface_count.clone()),
+            field!("line_cards", "Line Cards", |d| d.line_card_count.clone()),
+            field!("memory", "Memory Size", |d| d.memory_size.clone()),
+            field!("tags", "Tags", |d| d.tag_count.clone()),
+            field!("location", "Location", |d| json_text(&d.location_name).or_else(|| json_text(&d.location))),
+            field!("snmp_location", "SNMP Location", |d| d.snmp_location.clone()),
+            field!("snmp_contact", "SNMP Contact", |d| d.snmp_contact.clone()),
+            field!("description", "Description", |d| d.description.clone()),
+            field!("support_level", "Support Level", |d| d.device_support_level.clone()),
+            field!("error_code", "Error Code", |d| d.error_code.clone()),
+            field!("error_description", "Error Description", |d| d.error_description.clone()),
+            field!("id", "ID", |d| d.id.clone()),
+        ]
+    }
+
+    fn default_columns() -> &'static [&'static str] {
+        &["hostname", "ip", "serial", "mac", "ap_ethernet_mac", "platform", "version", "role"]
+    }
+}
+
+impl FieldRegistry for Issue {
+    fn fields() -> Vec<Field<Self>> {
+        vec![
+            field!("issue_id", "Issue ID", |i| i.issueId.clone()),
+            field!("name", "Name", |i| i.name.clone()),
+            field!("site_id", "Site ID", |i| i.siteId.clone()),
+            field!("device_id", "Device ID", |i| i.deviceId.clone()),
+            field!("device_role", "Device Role", |i| i.deviceRole.clone()),
+            field!("ai_driven", "AI Driven", |i| i.aiDriven.clone()),
+            field!("client_mac", "Client MAC", |i| i.clientMac.clone()),
+            field!("occurrences", "Occurrences", |i| i.issue_occurence_count.map(|v| v.to_string())),
+            field!("status", "Status", |i| i.status.clone()),
+            field!("priority", "Priority", |i| i.priority.clone()),
+            field!("category", "Category", |i| i.category.clone()),
+            field!("last_occurrence", "Last Occurrence Time", |i| i.last_occurence_time.map(format_timestamp)),
+        ]
+    }
+
+    fn default_columns() -> &'static [&'static str] {
+        &[
+            "issue_id",
+            "name",
+            "device_id",
+            "device_role",
+            "client_mac",
+            "status",
+            "priority",
+            "category",
+            "last_occurrence",
+        ]
+    }
+}
+
+impl FieldRegistry for ClientDetail {
+    fn fields() -> Vec<Field<Self>> {
+        vec![
+            field!("id", "ID", |c| c.id.clone()),
+            field!("hostname", "Host Name", |c| c.hostName.clone()),
+            field!("mac", "Host MAC", |c| c.hostMac.clone()),
+            field!("ipv4", "Host IPv4", |c| c.hostIpV4.clone()),
+            field!("ipv6", "Host IPv6", |c| c.hostIpV6.as_ref().map(|ips| ips.join(", "))),
+            field!("user", "User ID", |c| c.userId.clone()),
+            field!("status", "Connection Status", |c| c.connectionStatus.clone()),
+            field!("host_type", "Host Type", |c| c.hostType.clone()),
+            field!("os", "Host OS", |c| c.hostOs.clone()),
+            field!("os_version", "Host Version", |c| c.hostVersion.clone()),
+            field!("last_updated", "Last Updated", |c| c.lastUpdated.map(|t| format_timestamp(t as i64))),
+            field!("health", "Health Score", |c| {
+                c.healthScore.as_ref().and_then(|scores| {
+                    scores
+                        .iter()
+                        .find(|hs| hs.healthType.as_deref() == Some("OVERALL"))
+                        .and_then(|hs| hs.score.map(|s| s.to_string()))
+                })
+            }),
+            field!("auth_type", "Auth Type", |c| c.authType.clone()),
+            field!("vlan", "VLAN ID", |c| c.vlanId.map(|v| v.to_string())),
+            field!("ssid", "SSID", |c| c.ssid.clone()),
+            field!("frequency", "Frequency", |c| c.frequency.clone()),
+            field!("channel", "Channel", |c| c.channel.clone()),
+            field!("ap_group", "AP Group", |c| c.apGroup.clone()),
+            field!("location", "Location", |c| c.location.clone()),
+            field!("connection", "Client Connection", |c| c.clientConnection.clone()),
+            field!("port", "Port", |c| c.port.clone()),
+            field!("wlc", "WLC Name", |c| c.wlcName.clone()),
+            field!("issues", "Issue Count", |c| c.issueCount.map(|v| v.to_string())),
+            field!("rssi", "RSSI", |c| c.rssi.clone()),
+            field!("snr", "SNR", |c| c.snr.clone()),
+            field!("data_rate", "Data Rate", |c| c.dataRate.clone()),
+            field!("tx_bytes", "TX Bytes", |c| c.txBytes.clone()),
+            field!("rx_bytes", "RX Bytes", |c| c.rxBytes.clone()),
+            field!("protocol", "802.11 Protocol", |c| c.dot11Protocol.clone()),
+            field!("vendor", "Device Vendor", |c| c.deviceVendor.clone()),
+            field!("model", "Model Name", |c| c.modelName.clone()),
+            field!("session_duration", "Session Duration", |c| c.sessionDuration.clone()),
+            field!("dhcp_server", "DHCP Server IP", |c| c.dhcpServerIp.clone()),
+            field!("aaa_server", "AAA Server IP", |c| c.aaaServerIp.clone()),
+        ]
+    }
+
+    fn default_columns() -> &'static [&'static str] {
+        &["hostname", "mac", "ipv4", "user", "status", "health", "ssid", "vlan", "location"]
+    }
+}
+
+/// Interface speeds are reported in kbps.
+fn format_speed(speed: &str) -> String {
+    match speed.parse::<u64>() {
+        Ok(kbps) if kbps >= 1_000_000 && kbps % 1_000_000 == 0 => format!("{}G", kbps / 1_000_000),
+        Ok(kbps) if kbps >= 1_000 => format!("{}M", kbps / 1_000),
+        Ok(kbps) => format!("{}K", kbps),
+        Err(_) => speed.to_string(),
+    }
+}
+
+impl FieldRegistry for Interface {
+    fn fields() -> Vec<Field<Self>> {
+        vec![
+            field!("device", "Device", |i| i.device_name.clone()),
+            field!("port", "Port", |i| i.port_name.clone()),
+            field!("description", "Description", |i| i.description.clone()),
+            field!("admin", "Admin Status", |i| i.admin_status.clone()),
+            field!("status", "Oper Status", |i| i.status.clone()),
+            field!("speed", "Speed", |i| i.speed.as_deref().map(format_speed)),
+            field!("duplex", "Duplex", |i| i.duplex.clone()),
+            field!("vlan", "VLAN", |i| i.vlan_id.clone()),
+            field!("native_vlan", "Native VLAN", |i| i.native_vlan_id.clone()),
+            field!("voice_vlan", "Voice VLAN", |i| i.voice_vlan.clone()),
+            field!("mode", "Mode", |i| i.port_mode.clone()),
+            field!("type", "Type", |i| i.interface_type.clone()),
+            field!("media_type", "Media Type", |i| i.media_type.clone()),
+            field!("mac", "MAC Address", |i| i.mac_address.clone()),
+            field!("ipv4", "IPv4 Address", |i| i.ipv4_address.clone()),
+            field!("mtu", "MTU", |i| i.mtu.clone()),
+            field!("poe", "PoE", |i| i.poe.as_ref().and_then(|poe| poe.oper_status.clone())),
+            field!("poe_drawn", "PoE Drawn (W)", |i| i.poe.as_ref().and_then(|poe| poe.port_power_drawn.clone())),
+            field!("poe_allocated", "PoE Allocated (W)", |i| i.poe.as_ref().and_then(|poe| poe.allocated_power.clone())),
+            field!("last_updated", "Last Updated", |i| i.last_updated.clone()),
+            field!("id", "ID", |i| i.id.clone()),
+        ]
+    }
+
+    fn default_columns() -> &'static [&'static str] {
+        &["port", "status", "admin", "speed", "duplex", "vlan", "mode", "poe", "description"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Item {
+        name: String,
+        #[serde(rename = "snmpLocation")]
+        snmp_location: Option<String>,
+    }
+
+    impl FieldRegistry for Item {
+        fn fields() -> Vec<Field<Self>> {
+            vec![field!("name", "Name", |i| Some(i.name.clone()))]
+        }
+
+        fn default_columns() -> &'static [&'static str] {
+            &["name"]
+        }
+    }
+
+    fn items() -> Vec<Value> {
+        vec![serde_json::to_value(Item {
+            name: "edge-1".to_string(),
+            snmp_location: Some("Lab".to_string()),
+        })
+        .unwrap()]
+    }
+
+    #[test]
+    fn compare_values_orders_numbers_numerically() {
+        assert_eq!(compare_values(Some("9"), Some("10")), Ordering::Less);
+        assert_eq!(compare_values(Some("2.5"), Some("2.25")), Ordering::Greater);
+    }
+
+    #[test]
+    fn compare_values_ignores_case_and_sorts_missing_last() {
+        assert_eq!(compare_values(Some("abc"), Some("ABD")), Ordering::Less);
+        assert_eq!(compare_values(Some("b"), Some("B")), Ordering::Equal);
+        assert_eq!(compare_values(None, Some("a")), Ordering::Greater);
+        assert_eq!(compare_values(Some("a"), None), Ordering::Less);
+        assert_eq!(compare_values(None, None), Ordering::Equal);
+    }
+
+    #[test]
+    fn resolve_column_prefers_registered_fields() {
+        assert!(matches!(resolve_column::<Item>("NAME", &items()).unwrap(), Column::Known(_)));
+    }
+
+    #[test]
+    fn resolve_column_falls_back_to_api_keys() {
+        for name in ["snmpLocation", "snmp_location", "SNMP-LOCATION"] {
+            match resolve_column::<Item>(name, &items()).unwrap() {
+                Column::Raw(key) => assert_eq!(key, "snmpLocation"),
+                Column::Known(_) => panic!("{} should be a raw column", name),
+            }
+        }
+    }
+
+    #[test]
+    fn resolve_column_rejects_unknown_names() {
+        let err = resolve_column::<Item>("bogus", &items()).err().unwrap();
+        assert!(err.to_string().contains("Available columns: name"));
+        // Nothing to check against when there are no rows
+        assert!(resolve_column::<Item>("bogus", &[]).is_ok());
+    }
+
+    #[test]
+    fn build_table_sorts_descending() {
+        let items: Vec<Item> = ["b", "c", "a"]
+            .iter()
+            .map(|name| Item {
+                name: name.to_string(),
+                snmp_location: None,
+            })
+            .collect();
+        let args = TableArgs {
+            sort_by: Some("name:desc".to_string()),
+            ..TableArgs::default()
+        };
+        let table = build_table(&items, &args).unwrap();
+        let names: Vec<String> = table
+            .row_iter()
+            .skip(1)
+            .map(|row| row.get_cell(0).unwrap().get_content())
+            .collect();
+        assert_eq!(names, ["c", "b", "a"]);
+    }
+}
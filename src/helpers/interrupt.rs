@@ -0,0 +1,78 @@
+// src/helpers/interrupt.rs
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use log::error;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often the key reader checks whether it should stop.
+const KEY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+pub fn is_ctrl_c(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Wait up to `timeout` for a key press that `stop` accepts. Must be called
+/// in raw mode, where Ctrl-C arrives as a key rather than as SIGINT.
+pub fn wait_for_key<F: Fn(&KeyEvent) -> bool>(timeout: Duration, stop: F) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match event::poll(deadline.saturating_duration_since(Instant::now())) {
+            Ok(true) => match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press && stop(&key) => return true,
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Failed to read key: {}", e);
+                    return true;
+                }
+            },
+            Ok(false) => return false,
+            Err(e) => {
+                error!("Failed to poll for keys: {}", e);
+                return true;
+            }
+        }
+    }
+}
+
+/// Run `future` until it completes or Ctrl-C is pressed, in which case
+/// `None` is returned and the future is dropped.
+///
+/// Ctrl-C is read as a key in raw mode instead of through a signal handler,
+/// which could not be removed again and would stop Ctrl-C from interrupting
+/// anything else for the rest of the session. Without a terminal the future
+/// simply runs and Ctrl-C exits as usual.
+pub async fn until_ctrl_c<F: Future>(future: F) -> Option<F::Output> {
+    if enable_raw_mode().is_err() {
+        return Some(future.await);
+    }
+
+    let done = Arc::new(AtomicBool::new(false));
+    let mut keys = tokio::task::spawn_blocking({
+        let done = Arc::clone(&done);
+        move || {
+            while !done.load(Ordering::SeqCst) {
+                if wait_for_key(KEY_POLL_INTERVAL, is_ctrl_c) {
+                    return true;
+                }
+            }
+            false
+        }
+    });
+
+    let output = tokio::select! {
+        output = future => Some(output),
+        Ok(true) = &mut keys => None,
+    };
+
+    // Stop reading keys before leaving raw mode so none are swallowed
+    done.store(true, Ordering::SeqCst);
+    if !keys.is_finished() {
+        let _ = keys.await;
+    }
+    let _ = disable_raw_mode();
+    output
+}
@@ -0,0 +1,51 @@
+// src/helpers/logger.rs
+
+use log::{Level, Log, Metadata, Record};
+use std::sync::Mutex;
+
+/// Error messages held back while a caller is collecting them.
+static COLLECTED: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+/// env_logger, except that errors can be collected instead of written to
+/// stderr, e.g. so `watch` can redraw them after clearing the screen.
+struct Logger(env_logger::Logger);
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.0.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() == Level::Error && self.0.matches(record) {
+            let mut collected = COLLECTED.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(errors) = collected.as_mut() {
+                errors.push(record.args().to_string());
+                return;
+            }
+        }
+        self.0.log(record);
+    }
+
+    fn flush(&self) {
+        self.0.flush();
+    }
+}
+
+/// Install the logger, configured from `RUST_LOG` like `env_logger::init`.
+pub fn init() {
+    let logger = env_logger::Logger::from_default_env();
+    log::set_max_level(logger.filter());
+    log::set_logger(Box::leak(Box::new(Logger(logger))))
+        .expect("Logger initialised twice");
+}
+
+/// Run `f` and return the errors it logged instead of printing them.
+pub fn collect_errors<F: FnOnce()>(f: F) -> Vec<String> {
+    *COLLECTED.lock().unwrap_or_else(|e| e.into_inner()) = Some(Vec::new());
+    f();
+    COLLECTED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+        .unwrap_or_default()
+}
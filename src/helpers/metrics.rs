@@ -0,0 +1,87 @@
+// src/helpers/metrics.rs
+
+use std::fmt::Write;
+
+/// A Prometheus gauge and its samples.
+pub struct Gauge {
+    name: &'static str,
+    help: &'static str,
+    samples: Vec<(Vec<(&'static str, String)>, f64)>,
+}
+
+impl Gauge {
+    pub fn new(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            samples: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, labels: Vec<(&'static str, String)>, value: f64) {
+        self.samples.push((labels, value));
+    }
+}
+
+/// Render gauges in the Prometheus text exposition format.
+pub fn render(gauges: &[Gauge]) -> String {
+    let mut text = String::new();
+    for gauge in gauges.iter().filter(|gauge| !gauge.samples.is_empty()) {
+        let _ = writeln!(text, "# HELP {} {}", gauge.name, gauge.help);
+        let _ = writeln!(text, "# TYPE {} gauge", gauge.name);
+        for (labels, value) in &gauge.samples {
+            if labels.is_empty() {
+                let _ = writeln!(text, "{} {}", gauge.name, value);
+            } else {
+                let labels = labels
+                    .iter()
+                    .map(|(key, value)| format!("{}=\"{}\"", key, escape(value)))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let _ = writeln!(text, "{}{{{}}} {}", gauge.name, labels, value);
+            }
+        }
+    }
+    text
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_help_type_and_samples() {
+        let mut up = Gauge::new("catalysh_up", "Whether polling works");
+        up.add(Vec::new(), 1.0);
+        let mut reachable = Gauge::new("catalysh_device_reachable", "Device reachability");
+        reachable.add(vec![("hostname", "edge-1".to_string()), ("ip", "10.0.0.1".to_string())], 0.0);
+
+        assert_eq!(
+            render(&[up, reachable]),
+            "# HELP catalysh_up Whether polling works\n\
+             # TYPE catalysh_up gauge\n\
+             catalysh_up 1\n\
+             # HELP catalysh_device_reachable Device reachability\n\
+             # TYPE catalysh_device_reachable gauge\n\
+             catalysh_device_reachable{hostname=\"edge-1\",ip=\"10.0.0.1\"} 0\n"
+        );
+    }
+
+    #[test]
+    fn skips_gauges_without_samples() {
+        assert_eq!(render(&[Gauge::new("catalysh_empty", "Nothing")]), "");
+    }
+
+    #[test]
+    fn escapes_label_values() {
+        assert_eq!(escape(r#"a\b "c""#), r#"a\\b \"c\""#);
+        assert_eq!(escape("line\nbreak"), "line\\nbreak");
+    }
+}
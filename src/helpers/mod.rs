@@ -1,2 +1,19 @@
+#[macro_use]
+pub mod output;
+pub mod completion;
+pub mod concurrency;
+pub mod devicefilter;
+pub mod diff;
+pub mod export;
+pub mod fields;
+pub mod interrupt;
+pub mod logger;
+pub mod metrics;
+pub mod pager;
+pub mod pipeline;
+pub mod session;
+pub mod swim;
+pub mod tasktracker;
+pub mod template;
 pub mod utils;
-
+pub mod wireless;
@@ -0,0 +1,103 @@
+// src/helpers/output.rs
+
+use prettytable::Table;
+use std::cell::RefCell;
+
+/// A single piece of command output, kept structured so that tables can be
+/// post-processed row by row before they are drawn.
+#[derive(Clone)]
+pub enum Block {
+    Line(String),
+    Table(Table),
+}
+
+thread_local! {
+    static CAPTURE: RefCell<Option<Vec<Block>>> = const { RefCell::new(None) };
+}
+
+/// Print a line of output, or record it if output is currently being captured.
+macro_rules! outln {
+    () => {
+        $crate::helpers::output::print_line(String::new())
+    };
+    ($($arg:tt)*) => {
+        $crate::helpers::output::print_line(format!($($arg)*))
+    };
+}
+
+pub fn print_line(line: String) {
+    emit(Block::Line(line));
+}
+
+pub fn print_table(table: &Table) {
+    emit(Block::Table(table.clone()));
+}
+
+fn emit(block: Block) {
+    let uncaptured = CAPTURE.with(|capture| match capture.borrow_mut().as_mut() {
+        Some(blocks) => {
+            blocks.push(block);
+            None
+        }
+        None => Some(block),
+    });
+
+    if let Some(block) = uncaptured {
+        print_block(&block);
+    }
+}
+
+/// Whether output is being captured for a filter, redirect or the pager.
+pub fn is_capturing() -> bool {
+    CAPTURE.with(|capture| capture.borrow().is_some())
+}
+
+/// Run `f` and return everything it printed instead of writing it to the terminal.
+pub fn capture<F: FnOnce()>(f: F) -> Vec<Block> {
+    let previous = CAPTURE.with(|capture| capture.borrow_mut().replace(Vec::new()));
+    f();
+    CAPTURE.with(|capture| {
+        let mut capture = capture.borrow_mut();
+        let blocks = capture.take().unwrap_or_default();
+        *capture = previous;
+        blocks
+    })
+}
+
+pub fn print_block(block: &Block) {
+    match block {
+        Block::Line(line) => println!("{}", line),
+        Block::Table(table) => {
+            table.printstd();
+        }
+    }
+}
+
+pub fn print_blocks(blocks: &[Block]) {
+    for block in blocks {
+        print_block(block);
+    }
+}
+
+/// Render blocks as plain text, without any terminal colouring.
+pub fn render(blocks: &[Block]) -> String {
+    let mut text = String::new();
+    for block in blocks {
+        match block {
+            Block::Line(line) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+            Block::Table(table) => text.push_str(&table.to_string()),
+        }
+    }
+    text
+}
+
+/// Text of a table row, used when matching rows against a pattern.
+pub fn row_text(row: &prettytable::Row) -> String {
+    row.iter()
+        .map(|cell| cell.get_content())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
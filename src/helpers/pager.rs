@@ -0,0 +1,221 @@
+// src/helpers/pager.rs
+
+use crate::helpers::output::{self, Block};
+use crate::helpers::session;
+use anyhow::Result;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{
+    self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+    LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+use log::error;
+use std::io::{stdout, IsTerminal, Write};
+
+/// Whether output should be captured so it can be paged.
+pub fn is_active() -> bool {
+    session::pager_enabled() && stdout().is_terminal()
+}
+
+/// Print blocks, switching to the pager when they don't fit on one page.
+pub fn print_paged(blocks: &[Block]) {
+    let text = output::render(blocks);
+    let page_height = page_height();
+
+    if !is_active() || text.lines().count() <= page_height {
+        output::print_blocks(blocks);
+        return;
+    }
+
+    if let Err(e) = page(&text) {
+        error!("Pager failed: {}", e);
+        output::print_blocks(blocks);
+    }
+}
+
+fn page_height() -> usize {
+    match session::terminal_length() {
+        Some(length) => length as usize,
+        None => terminal::size()
+            .map(|(_, rows)| rows.saturating_sub(1) as usize)
+            .unwrap_or(24),
+    }
+}
+
+struct Pager<'a> {
+    lines: Vec<&'a str>,
+    top: usize,
+    left: usize,
+    search: Option<String>,
+    message: Option<String>,
+}
+
+/// A `less`-style pager: space/b page, j/k scroll, g/G jump, `/` search,
+/// n/N repeat the search, q quits.
+fn page(text: &str) -> Result<()> {
+    let mut pager = Pager {
+        lines: text.lines().collect(),
+        top: 0,
+        left: 0,
+        search: None,
+        message: None,
+    };
+
+    let mut out = stdout();
+    enable_raw_mode()?;
+    execute!(out, EnterAlternateScreen, Hide)?;
+
+    let result = pager.run();
+
+    execute!(out, Show, LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    result
+}
+
+impl Pager<'_> {
+    fn run(&mut self) -> Result<()> {
+        loop {
+            let (width, rows) = terminal::size()?;
+            let height = (rows.saturating_sub(1) as usize).min(page_height()).max(1);
+            self.draw(width as usize, rows, height)?;
+
+            let key = match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => key,
+                _ => continue,
+            };
+            self.message = None;
+
+            let last_top = self.lines.len().saturating_sub(height);
+            match key {
+                KeyEvent {
+                    code: KeyCode::Char('c'),
+                    modifiers: KeyModifiers::CONTROL,
+                    ..
+                } => return Ok(()),
+                KeyEvent { code, .. } => match code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char(' ') | KeyCode::Char('f') | KeyCode::PageDown => {
+                        self.top = (self.top + height).min(last_top)
+                    }
+                    KeyCode::Char('b') | KeyCode::PageUp => {
+                        self.top = self.top.saturating_sub(height)
+                    }
+                    KeyCode::Char('j') | KeyCode::Enter | KeyCode::Down => {
+                        self.top = (self.top + 1).min(last_top)
+                    }
+                    KeyCode::Char('k') | KeyCode::Up => self.top = self.top.saturating_sub(1),
+                    KeyCode::Char('g') | KeyCode::Home => self.top = 0,
+                    KeyCode::Char('G') | KeyCode::End => self.top = last_top,
+                    KeyCode::Right => self.left += 8,
+                    KeyCode::Left => self.left = self.left.saturating_sub(8),
+                    KeyCode::Char('/') => {
+                        if let Some(term) = self.prompt(rows)? {
+                            self.search = Some(term);
+                            self.find(true, last_top);
+                        }
+                    }
+                    KeyCode::Char('n') => self.find(true, last_top),
+                    KeyCode::Char('N') => self.find(false, last_top),
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    fn draw(&self, width: usize, rows: u16, height: usize) -> Result<()> {
+        let mut out = stdout();
+        queue!(out, Clear(ClearType::All))?;
+
+        for (row, line) in self.lines.iter().skip(self.top).take(height).enumerate() {
+            let visible: String = line.chars().skip(self.left).take(width).collect();
+            queue!(out, MoveTo(0, row as u16))?;
+            match self.search.as_deref() {
+                Some(term) if !term.is_empty() && visible.contains(term) => {
+                    let mut rest = visible.as_str();
+                    while let Some(idx) = rest.find(term) {
+                        queue!(
+                            out,
+                            Print(&rest[..idx]),
+                            SetAttribute(Attribute::Reverse),
+                            Print(term),
+                            SetAttribute(Attribute::Reset)
+                        )?;
+                        rest = &rest[idx + term.len()..];
+                    }
+                    queue!(out, Print(rest))?;
+                }
+                _ => queue!(out, Print(visible))?,
+            }
+        }
+
+        let last = (self.top + height).min(self.lines.len());
+        let status = self.message.clone().unwrap_or_else(|| {
+            format!(
+                "lines {}-{} of {} ({}%) - space/b page, / search, n/N next/prev, q quit",
+                self.top + 1,
+                last,
+                self.lines.len(),
+                last * 100 / self.lines.len().max(1)
+            )
+        });
+        queue!(
+            out,
+            MoveTo(0, rows.saturating_sub(1)),
+            SetAttribute(Attribute::Reverse),
+            Print(status.chars().take(width).collect::<String>()),
+            SetAttribute(Attribute::Reset)
+        )?;
+        out.flush()?;
+        Ok(())
+    }
+
+    /// Read a search term on the status line; Esc cancels.
+    fn prompt(&self, rows: u16) -> Result<Option<String>> {
+        let mut out = stdout();
+        let mut term = String::new();
+        loop {
+            queue!(
+                out,
+                MoveTo(0, rows.saturating_sub(1)),
+                Clear(ClearType::CurrentLine),
+                Print(format!("/{}", term))
+            )?;
+            out.flush()?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Release {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Enter => return Ok(Some(term)),
+                    KeyCode::Esc => return Ok(None),
+                    KeyCode::Backspace => {
+                        term.pop();
+                    }
+                    KeyCode::Char(c) => term.push(c),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn find(&mut self, forward: bool, last_top: usize) {
+        let term = match self.search.as_deref() {
+            Some(term) if !term.is_empty() => term,
+            _ => return,
+        };
+
+        let found = if forward {
+            (self.top + 1..self.lines.len()).find(|&idx| self.lines[idx].contains(term))
+        } else {
+            (0..self.top).rev().find(|&idx| self.lines[idx].contains(term))
+        };
+
+        match found {
+            Some(idx) => self.top = idx.min(last_top),
+            None => self.message = Some(format!("Pattern not found: {}", term)),
+        }
+    }
+}
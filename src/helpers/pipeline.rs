@@ -0,0 +1,464 @@
+// src/helpers/pipeline.rs
+
+use crate::app::config;
+use crate::helpers::output::{self, Block};
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// An IOS-style output filter applied after `|` on the command line.
+pub enum Filter {
+    Include(Regex),
+    Exclude(Regex),
+    Begin(Regex),
+    Section(Regex),
+    Count(Option<Regex>),
+    Tee(Redirect),
+}
+
+/// Output redirection to a file with `> file`, `>> file` or `| tee`.
+pub struct Redirect {
+    pub path: String,
+    pub append: bool,
+}
+
+const FILTER_NAMES: [&str; 6] = ["include", "exclude", "begin", "section", "count", "tee"];
+
+/// Split a REPL line into the command and its `|` filter segments.
+///
+/// Only a `|` outside quotes and preceded by whitespace starts a new segment,
+/// so regex alternation such as `include C9300|C9200` stays in one pattern.
+pub fn split_pipeline(line: &str) -> (String, Vec<String>) {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut previous = ' ';
+
+    for c in line.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '|' && previous.is_whitespace() => {
+                segments.push(std::mem::take(&mut current));
+                previous = c;
+                continue;
+            }
+            None => {}
+        }
+        current.push(c);
+        previous = c;
+    }
+    segments.push(current);
+
+    let command = segments.remove(0).trim().to_string();
+    let filters = segments.into_iter().map(|s| s.trim().to_string()).collect();
+    (command, filters)
+}
+
+/// Split a trailing `> file` or `>> file` off a command or filter segment.
+pub fn split_redirect(segment: &str) -> (String, Option<Redirect>) {
+    let mut quote: Option<char> = None;
+    let mut previous = ' ';
+
+    for (idx, c) in segment.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' && previous.is_whitespace() => {
+                let rest = &segment[idx + 1..];
+                let (append, path) = match rest.strip_prefix('>') {
+                    Some(path) => (true, path),
+                    None => (false, rest),
+                };
+                let redirect = Redirect {
+                    path: strip_quotes(path.trim()).to_string(),
+                    append,
+                };
+                return (segment[..idx].trim().to_string(), Some(redirect));
+            }
+            None => {}
+        }
+        previous = c;
+    }
+
+    (segment.to_string(), None)
+}
+
+impl Redirect {
+    /// The file to write. Relative paths are resolved under the configured
+    /// captures directory.
+    pub fn target(&self) -> Result<PathBuf> {
+        if self.path.is_empty() {
+            return Err(anyhow!("No file given for output redirection"));
+        }
+
+        let path = PathBuf::from(&self.path);
+        if path.is_relative() {
+            return Ok(config::get_captures_dir().join(path));
+        }
+        Ok(path)
+    }
+
+    /// Write rendered output to the target file.
+    pub fn write(&self, blocks: &[Block]) -> Result<PathBuf> {
+        let path = self.target()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(self.append)
+            .truncate(!self.append)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        file.write_all(output::render(blocks).as_bytes())
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(path)
+    }
+}
+
+impl Filter {
+    /// Parse a segment such as `include C9300`; keywords may be abbreviated.
+    pub fn parse(segment: &str) -> Result<Filter> {
+        let (keyword, pattern) = match segment.split_once(char::is_whitespace) {
+            Some((keyword, pattern)) => (keyword, strip_quotes(pattern.trim())),
+            None => (segment, ""),
+        };
+
+        let matches: Vec<&str> = FILTER_NAMES
+            .iter()
+            .copied()
+            .filter(|name| !keyword.is_empty() && name.starts_with(keyword))
+            .collect();
+        let name = match matches.as_slice() {
+            [name] => *name,
+            _ => {
+                return Err(anyhow!(
+                    "Unknown output filter '{}'. Expected one of: {}",
+                    keyword,
+                    FILTER_NAMES.join(", ")
+                ))
+            }
+        };
+
+        if name == "tee" {
+            let (append, path) = match pattern.split_once(char::is_whitespace) {
+                Some(("-a" | "--append", path)) => (true, strip_quotes(path.trim())),
+                _ => (false, pattern),
+            };
+            let redirect = Redirect {
+                path: path.to_string(),
+                append,
+            };
+            redirect.target()?;
+            return Ok(Filter::Tee(redirect));
+        }
+
+        if name == "count" {
+            return if pattern.is_empty() {
+                Ok(Filter::Count(None))
+            } else {
+                Ok(Filter::Count(Some(compile(pattern)?)))
+            };
+        }
+
+        if pattern.is_empty() {
+            return Err(anyhow!("Output filter '{}' requires a pattern", name));
+        }
+        let regex = compile(pattern)?;
+
+        Ok(match name {
+            "include" => Filter::Include(regex),
+            "exclude" => Filter::Exclude(regex),
+            "begin" => Filter::Begin(regex),
+            _ => Filter::Section(regex),
+        })
+    }
+
+    /// Apply the filter to captured output. Tables are filtered per row and
+    /// always keep their header row.
+    pub fn apply(&self, blocks: Vec<Block>) -> Result<Vec<Block>> {
+        Ok(match self {
+            Filter::Include(regex) => filter_rows(blocks, |text| regex.is_match(text)),
+            Filter::Exclude(regex) => filter_rows(blocks, |text| !regex.is_match(text)),
+            Filter::Begin(regex) => begin(blocks, regex),
+            Filter::Section(regex) => section(blocks, regex),
+            Filter::Tee(redirect) => {
+                let path = redirect.write(&blocks)?;
+                println!("Output saved to {}", path.display());
+                blocks
+            }
+            Filter::Count(regex) => {
+                let count = filter_rows(blocks, |text| {
+                    regex.as_ref().is_none_or(|regex| regex.is_match(text))
+                })
+                .iter()
+                .map(|block| match block {
+                    Block::Line(_) => 1,
+                    Block::Table(table) => table.len().saturating_sub(1),
+                })
+                .sum::<usize>();
+                vec![Block::Line(format!(
+                    "Number of lines which match regexp = {}",
+                    count
+                ))]
+            }
+        })
+    }
+}
+
+/// Parse every filter segment, stopping at the first invalid one.
+pub fn parse_filters(segments: &[String]) -> Result<Vec<Filter>> {
+    segments.iter().map(|segment| Filter::parse(segment)).collect()
+}
+
+/// Apply parsed filters in order.
+pub fn apply_filters(filters: &[Filter], blocks: Vec<Block>) -> Result<Vec<Block>> {
+    filters
+        .iter()
+        .try_fold(blocks, |blocks, filter| filter.apply(blocks))
+}
+
+fn compile(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| anyhow!("Invalid regular expression '{}': {}", pattern, e))
+}
+
+fn strip_quotes(pattern: &str) -> &str {
+    for q in ['"', '\''] {
+        if pattern.len() >= 2 && pattern.starts_with(q) && pattern.ends_with(q) {
+            return &pattern[1..pattern.len() - 1];
+        }
+    }
+    pattern
+}
+
+fn filter_rows<F: Fn(&str) -> bool>(blocks: Vec<Block>, keep: F) -> Vec<Block> {
+    blocks
+        .into_iter()
+        .filter_map(|block| match block {
+            Block::Line(line) => keep(&line).then_some(Block::Line(line)),
+            Block::Table(mut table) => {
+                for idx in (1..table.len()).rev() {
+                    let matched = table
+                        .get_row(idx)
+                        .is_some_and(|row| keep(&output::row_text(row)));
+                    if !matched {
+                        table.remove_row(idx);
+                    }
+                }
+                Some(Block::Table(table))
+            }
+        })
+        .collect()
+}
+
+fn begin(blocks: Vec<Block>, regex: &Regex) -> Vec<Block> {
+    let mut started = false;
+    blocks
+        .into_iter()
+        .filter_map(|block| {
+            if started {
+                return Some(block);
+            }
+            match block {
+                Block::Line(line) => {
+                    started = regex.is_match(&line);
+                    started.then_some(Block::Line(line))
+                }
+                Block::Table(mut table) => {
+                    let first = (1..table.len()).find(|&idx| {
+                        table
+                            .get_row(idx)
+                            .is_some_and(|row| regex.is_match(&output::row_text(row)))
+                    })?;
+                    for idx in (1..first).rev() {
+                        table.remove_row(idx);
+                    }
+                    started = true;
+                    Some(Block::Table(table))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Lines that match, plus the more-indented lines that follow them.
+fn section(blocks: Vec<Block>, regex: &Regex) -> Vec<Block> {
+    let mut section_indent: Option<usize> = None;
+    blocks
+        .into_iter()
+        .filter_map(|block| match block {
+            Block::Line(line) => {
+                let indent = line.len() - line.trim_start().len();
+                if regex.is_match(&line) {
+                    section_indent = Some(indent);
+                    return Some(Block::Line(line));
+                }
+                match section_indent {
+                    Some(parent) if indent > parent && !line.trim().is_empty() => {
+                        Some(Block::Line(line))
+                    }
+                    _ => {
+                        section_indent = None;
+                        None
+                    }
+                }
+            }
+            Block::Table(table) => {
+                section_indent = None;
+                filter_rows(vec![Block::Table(table)], |text| regex.is_match(text)).pop()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prettytable::{row, Table};
+
+    fn lines(text: &[&str]) -> Vec<Block> {
+        text.iter().map(|line| Block::Line(line.to_string())).collect()
+    }
+
+    fn texts(blocks: &[Block]) -> Vec<String> {
+        blocks
+            .iter()
+            .map(|block| match block {
+                Block::Line(line) => line.clone(),
+                Block::Table(_) => "<table>".to_string(),
+            })
+            .collect()
+    }
+
+    fn run(filter: &str, blocks: Vec<Block>) -> Vec<Block> {
+        Filter::parse(filter).unwrap().apply(blocks).unwrap()
+    }
+
+    #[test]
+    fn split_pipeline_keeps_regex_alternation() {
+        let (command, filters) = split_pipeline("show device list all | include C9300|C9200 | count");
+        assert_eq!(command, "show device list all");
+        assert_eq!(filters, vec!["include C9300|C9200", "count"]);
+    }
+
+    #[test]
+    fn split_pipeline_ignores_quoted_bars() {
+        let (command, filters) = split_pipeline("show device list all | include \"a | b\"");
+        assert_eq!(command, "show device list all");
+        assert_eq!(filters, vec!["include \"a | b\""]);
+    }
+
+    #[test]
+    fn split_redirect_detects_append() {
+        let (command, redirect) = split_redirect("show issue list >> 'out file.txt'");
+        let redirect = redirect.unwrap();
+        assert_eq!(command, "show issue list");
+        assert_eq!(redirect.path, "out file.txt");
+        assert!(redirect.append);
+
+        let (_, redirect) = split_redirect("show issue list > out.txt");
+        assert!(!redirect.unwrap().append);
+        assert!(split_redirect("include a>b").1.is_none());
+    }
+
+    #[test]
+    fn redirect_without_path_is_an_error() {
+        let (_, redirect) = split_redirect("show issue list >");
+        assert!(redirect.unwrap().target().is_err());
+        assert!(Filter::parse("tee").is_err());
+    }
+
+    #[test]
+    fn parse_accepts_abbreviations_and_rejects_unknown() {
+        assert!(matches!(Filter::parse("inc up").unwrap(), Filter::Include(_)));
+        assert!(matches!(Filter::parse("ex down").unwrap(), Filter::Exclude(_)));
+        assert!(matches!(Filter::parse("c").unwrap(), Filter::Count(None)));
+        assert!(Filter::parse("grep up").is_err());
+        assert!(Filter::parse("include").is_err());
+        assert!(Filter::parse("include (").is_err());
+        assert!(parse_filters(&["include a".to_string(), "bogus".to_string()]).is_err());
+    }
+
+    #[test]
+    fn include_and_exclude_filter_lines() {
+        let blocks = lines(&["Gi1/0/1 up", "Gi1/0/2 down", "Gi1/0/3 up"]);
+        assert_eq!(texts(&run("include up", blocks.clone())), ["Gi1/0/1 up", "Gi1/0/3 up"]);
+        assert_eq!(texts(&run("exclude up", blocks)), ["Gi1/0/2 down"]);
+    }
+
+    #[test]
+    fn table_filters_keep_header() {
+        let mut table = Table::new();
+        table.add_row(row!["Hostname", "Model"]);
+        table.add_row(row!["edge-1", "C9300"]);
+        table.add_row(row!["edge-2", "C9200"]);
+
+        let filtered = run("include C9300", vec![Block::Table(table)]);
+        match filtered.as_slice() {
+            [Block::Table(table)] => {
+                assert_eq!(table.len(), 2);
+                assert_eq!(table.get_row(1).unwrap().get_cell(0).unwrap().get_content(), "edge-1");
+            }
+            _ => panic!("expected one table"),
+        }
+    }
+
+    #[test]
+    fn begin_starts_at_first_match() {
+        let blocks = lines(&["a", "b", "c", "b"]);
+        assert_eq!(texts(&run("begin b", blocks)), ["b", "c", "b"]);
+    }
+
+    #[test]
+    fn section_keeps_indented_children() {
+        let blocks = lines(&[
+            "interface Gi1",
+            " description uplink",
+            "interface Gi2",
+            " shutdown",
+            "!",
+        ]);
+        assert_eq!(
+            texts(&run("section Gi1", blocks)),
+            ["interface Gi1", " description uplink"]
+        );
+    }
+
+    #[test]
+    fn count_counts_lines_and_table_rows() {
+        let mut table = Table::new();
+        table.add_row(row!["Hostname"]);
+        table.add_row(row!["edge-1"]);
+        table.add_row(row!["core-1"]);
+        let mut blocks = lines(&["edge-2"]);
+        blocks.push(Block::Table(table));
+
+        assert_eq!(
+            texts(&run("count edge", blocks.clone())),
+            ["Number of lines which match regexp = 2"]
+        );
+        assert_eq!(
+            texts(&run("count", blocks)),
+            ["Number of lines which match regexp = 3"]
+        );
+    }
+
+    #[test]
+    fn filters_apply_in_order() {
+        let filters = parse_filters(&["include up".to_string(), "count".to_string()]).unwrap();
+        let blocks = lines(&["Gi1 up", "Gi2 down", "Gi3 up"]);
+        assert_eq!(
+            texts(&apply_filters(&filters, blocks).unwrap()),
+            ["Number of lines which match regexp = 2"]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+// src/helpers/session.rs
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+// Settings that only last for the current REPL session, like `terminal length` on IOS.
+static PAGER_ENABLED: AtomicBool = AtomicBool::new(true);
+// 0 disables paging; None means "use the terminal height".
+static TERMINAL_LENGTH: Mutex<Option<u16>> = Mutex::new(None);
+
+pub fn pager_enabled() -> bool {
+    PAGER_ENABLED.load(Ordering::Relaxed) && terminal_length() != Some(0)
+}
+
+pub fn set_pager_enabled(enabled: bool) {
+    PAGER_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// The configured page length, or `None` to follow the terminal height.
+pub fn terminal_length() -> Option<u16> {
+    *TERMINAL_LENGTH.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn set_terminal_length(length: Option<u16>) {
+    *TERMINAL_LENGTH.lock().unwrap_or_else(|e| e.into_inner()) = length;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terminal_length_keeps_every_value() {
+        set_terminal_length(Some(u16::MAX));
+        assert_eq!(terminal_length(), Some(u16::MAX));
+        set_terminal_length(Some(0));
+        assert!(!pager_enabled());
+        set_terminal_length(None);
+        assert_eq!(terminal_length(), None);
+    }
+}
@@ -0,0 +1,190 @@
+// src/helpers/swim.rs
+
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::images::getimages::SoftwareImage;
+use std::cmp::Ordering;
+
+/// Compare software versions such as `17.9.4a`, `17.12.1` or `15.2(7)E4`.
+/// Numeric parts compare as numbers and letters as text, so `17.9.4a` is
+/// newer than `17.9.4` and older than `17.12.1`.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a, b) = (version_parts(a), version_parts(b));
+    for (x, y) in a.iter().zip(&b) {
+        let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Err(_), Err(_)) => x.to_lowercase().cmp(&y.to_lowercase()),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+/// Runs of digits and runs of letters, e.g. `15.2(7)E4` -> 15, 2, 7, E, 4.
+fn version_parts(version: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for c in version.chars() {
+        let same_kind = current
+            .chars()
+            .last()
+            .is_some_and(|last| last.is_ascii_digit() == c.is_ascii_digit());
+        if (!c.is_ascii_alphanumeric() || !same_kind) && !current.is_empty() {
+            parts.push(std::mem::take(&mut current));
+        }
+        if c.is_ascii_alphanumeric() {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+/// The golden image that applies to a device: one listing the device's
+/// product ID, otherwise one for the device's series.
+pub fn golden_for<'a>(device: &AllDevices, golden: &'a [SoftwareImage]) -> Option<&'a SoftwareImage> {
+    // Stacks report every member, e.g. "C9300-48P, C9300-48P"
+    let platforms: Vec<&str> = device
+        .platform_id
+        .as_deref()
+        .map(|platform| platform.split(',').map(str::trim).collect())
+        .unwrap_or_default();
+
+    golden
+        .iter()
+        .find(|image| platforms.iter().any(|platform| applies_to(image, platform)))
+        .or_else(|| {
+            let series = device.series.as_deref()?;
+            golden
+                .iter()
+                .find(|image| image.image_series.iter().any(|image_series| image_series.ends_with(series)))
+        })
+}
+
+/// The golden image listing a product ID, for devices not yet in inventory.
+pub fn golden_for_pid<'a>(pid: &str, golden: &'a [SoftwareImage]) -> Option<&'a SoftwareImage> {
+    golden.iter().find(|image| applies_to(image, pid))
+}
+
+fn applies_to(image: &SoftwareImage, product_id: &str) -> bool {
+    image
+        .applicable_devices_for_image
+        .iter()
+        .any(|applicable| applicable.product_id.iter().any(|product| product.eq_ignore_ascii_case(product_id)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReadinessStatus {
+    /// Already on or above the golden version
+    UpToDate,
+    /// Below golden and passing every pre-check
+    Ready,
+    /// Below golden but failing a pre-check
+    NotReady,
+    /// No golden image is tagged for the device's platform
+    NoGolden,
+}
+
+impl ReadinessStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReadinessStatus::UpToDate => "Up to date",
+            ReadinessStatus::Ready => "Ready",
+            ReadinessStatus::NotReady => "Not ready",
+            ReadinessStatus::NoGolden => "No golden image",
+        }
+    }
+}
+
+/// Where a device stands against its golden image, with the pre-checks
+/// that would block an upgrade.
+pub struct Readiness<'a> {
+    pub device: &'a AllDevices,
+    pub golden: Option<&'a SoftwareImage>,
+    pub status: ReadinessStatus,
+    pub failed_checks: Vec<String>,
+}
+
+impl Readiness<'_> {
+    pub fn hostname(&self) -> &str {
+        self.device.hostname.as_deref().unwrap_or("Unknown")
+    }
+
+    pub fn current_version(&self) -> &str {
+        self.device.software_version.as_deref().unwrap_or("N/A")
+    }
+}
+
+pub fn check_readiness<'a>(device: &'a AllDevices, golden: &'a [SoftwareImage]) -> Readiness<'a> {
+    let image = golden_for(device, golden);
+    let mut failed_checks = Vec::new();
+
+    if device.reachability_status.as_deref() != Some("Reachable") {
+        failed_checks.push(format!(
+            "unreachable ({})",
+            device.reachability_status.as_deref().unwrap_or("unknown")
+        ));
+    }
+    if device.collection_status.as_deref() != Some("Managed") {
+        failed_checks.push(format!(
+            "inventory {}",
+            device.collection_status.as_deref().unwrap_or("not collected")
+        ));
+    }
+    if device.software_version.is_none() {
+        failed_checks.push("current version unknown".to_string());
+    }
+
+    let status = match (image, &device.software_version) {
+        (None, _) => ReadinessStatus::NoGolden,
+        (Some(image), Some(current)) if compare_versions(current, image.version()) != Ordering::Less => {
+            ReadinessStatus::UpToDate
+        }
+        _ if failed_checks.is_empty() => ReadinessStatus::Ready,
+        _ => ReadinessStatus::NotReady,
+    };
+
+    Readiness {
+        device,
+        golden: image,
+        status,
+        failed_checks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_parts_compare_as_numbers() {
+        assert_eq!(compare_versions("17.12.1", "17.9.4"), Ordering::Greater);
+        assert_eq!(compare_versions("17.9.4", "17.9.4"), Ordering::Equal);
+        assert_eq!(compare_versions("16.12.10", "17.3.1"), Ordering::Less);
+    }
+
+    #[test]
+    fn letter_suffix_is_a_newer_rebuild() {
+        assert_eq!(compare_versions("17.9.4a", "17.9.4"), Ordering::Greater);
+        assert_eq!(compare_versions("17.9.4a", "17.9.4b"), Ordering::Less);
+        assert_eq!(compare_versions("17.9.4a", "17.12.1"), Ordering::Less);
+    }
+
+    #[test]
+    fn classic_ios_trains() {
+        assert_eq!(compare_versions("15.2(7)E4", "15.2(7)E10"), Ordering::Less);
+        assert_eq!(compare_versions("15.2(7)E4", "15.2(4)E10"), Ordering::Greater);
+        assert_eq!(compare_versions("15.2(7)e4", "15.2(7)E4"), Ordering::Equal);
+    }
+
+    #[test]
+    fn splits_digit_and_letter_runs() {
+        assert_eq!(version_parts("15.2(7)E4"), ["15", "2", "7", "E", "4"]);
+        assert_eq!(version_parts("17.9.4a"), ["17", "9", "4", "a"]);
+    }
+}
@@ -0,0 +1,152 @@
+// src/helpers/tasktracker.rs
+
+use crate::api::authentication::auth::Token;
+use crate::api::tasks::gettask::{self, TaskRef};
+use crate::app::config::Config;
+use crate::helpers::concurrency::LimitedJoinSet;
+use crate::helpers::interrupt;
+use anyhow::Result;
+use crossterm::cursor::MoveTo;
+use crossterm::execute;
+use crossterm::terminal::{Clear, ClearType};
+use prettytable::{row, Table};
+use std::io::{stdout, IsTerminal};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskState {
+    Running(String),
+    Succeeded(String),
+    Failed(String),
+}
+
+impl TaskState {
+    fn is_running(&self) -> bool {
+        matches!(self, TaskState::Running(_))
+    }
+
+    fn label(&self) -> (&'static str, &str) {
+        match self {
+            TaskState::Running(detail) => ("Running", detail),
+            TaskState::Succeeded(detail) => ("Succeeded", detail),
+            TaskState::Failed(detail) => ("Failed", detail),
+        }
+    }
+}
+
+/// One task per device (or other item), as shown in the live table.
+pub struct TrackedTask {
+    pub name: String,
+    pub task_id: Option<String>,
+    pub state: TaskState,
+}
+
+impl TrackedTask {
+    pub fn succeeded(&self) -> bool {
+        matches!(self.state, TaskState::Succeeded(_))
+    }
+
+    /// Track the task started for `name`, or record why it could not start.
+    pub fn new(name: &str, started: Result<TaskRef>) -> Self {
+        match started {
+            Ok(task) => TrackedTask {
+                name: name.to_string(),
+                task_id: Some(task.task_id),
+                state: TaskState::Running("Submitted".to_string()),
+            },
+            Err(e) => TrackedTask {
+                name: name.to_string(),
+                task_id: None,
+                state: TaskState::Failed(e.to_string()),
+            },
+        }
+    }
+}
+
+/// Poll every task until all finish, `timeout` passes or Ctrl-C is pressed.
+/// On a terminal the table is redrawn in place; otherwise each state change
+/// is printed as a line, which suits scripts and log files.
+///
+/// Returns false if the user stopped waiting.
+pub async fn track(config: &Config, token: &Token, title: &str, tasks: &mut [TrackedTask], timeout: Duration) -> bool {
+    let live = stdout().is_terminal();
+    let deadline = Instant::now() + timeout;
+    let mut printed: Vec<Option<TaskState>> = vec![None; tasks.len()];
+    render(title, tasks, live, &mut printed);
+
+    while tasks.iter().any(|task| task.state.is_running()) {
+        if Instant::now() >= deadline {
+            for task in tasks.iter_mut().filter(|task| task.state.is_running()) {
+                task.state = TaskState::Failed(format!("No result within {}s", timeout.as_secs()));
+            }
+            render(title, tasks, live, &mut printed);
+            break;
+        }
+
+        let polled = interrupt::until_ctrl_c(async {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            poll(config, token, tasks).await;
+        })
+        .await;
+        if polled.is_none() {
+            println!("Stopped waiting. The tasks continue to run in Catalyst Center.");
+            return false;
+        }
+        render(title, tasks, live, &mut printed);
+    }
+
+    let succeeded = tasks.iter().filter(|task| task.succeeded()).count();
+    println!("{} succeeded, {} failed", succeeded, tasks.len() - succeeded);
+    true
+}
+
+async fn poll(config: &Config, token: &Token, tasks: &mut [TrackedTask]) {
+    let mut polls = LimitedJoinSet::new();
+    for (idx, task) in tasks.iter().enumerate() {
+        if let (true, Some(task_id)) = (task.state.is_running(), task.task_id.clone()) {
+            let (config, token) = (config.clone(), token.clone());
+            polls.spawn(async move { (idx, gettask::get_task(&config, &token, &task_id).await) });
+        }
+    }
+
+    while let Some(joined) = polls.join_next().await {
+        // A failed poll is retried on the next round
+        let (idx, task) = match joined {
+            Ok((idx, Ok(task))) => (idx, task),
+            _ => continue,
+        };
+        let progress = task.progress.clone().unwrap_or_default();
+        tasks[idx].state = if task.is_error() {
+            TaskState::Failed(task.failure_reason.clone().unwrap_or(progress))
+        } else if task.is_done() {
+            TaskState::Succeeded(progress)
+        } else {
+            TaskState::Running(progress)
+        };
+    }
+}
+
+fn render(title: &str, tasks: &[TrackedTask], live: bool, printed: &mut [Option<TaskState>]) {
+    if !live {
+        for (task, last) in tasks.iter().zip(printed.iter_mut()) {
+            if last.as_ref() != Some(&task.state) {
+                let (state, detail) = task.state.label();
+                println!("{}: {} {}", task.name, state, detail);
+                *last = Some(task.state.clone());
+            }
+        }
+        return;
+    }
+
+    let _ = execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0));
+    println!("{} (Ctrl-C to stop waiting)", title);
+    let mut table = Table::new();
+    table.add_row(row!["Device", "Task", "Status", "Detail"]);
+    for task in tasks {
+        let (state, detail) = task.state.label();
+        table.add_row(row![task.name, task.task_id.as_deref().unwrap_or("-"), state, detail]);
+    }
+    table.printstd();
+}
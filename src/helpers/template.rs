@@ -0,0 +1,249 @@
+// src/helpers/template.rs
+
+use crate::api::templates::gettemplates::{Template, TemplateSummary};
+use anyhow::{anyhow, Result};
+use regex::{Captures, Regex};
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// Template variables from a YAML file. Top-level keys apply to every device;
+/// an optional `devices` map overrides them per hostname:
+///
+/// ```yaml
+/// ntp_server: 10.0.0.1
+/// devices:
+///   edge-1:
+///     ntp_server: 10.1.0.1
+/// ```
+#[derive(Debug, Default)]
+pub struct TemplateVars {
+    shared: Map<String, Value>,
+    devices: HashMap<String, Map<String, Value>>,
+}
+
+impl TemplateVars {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&text)
+            .map_err(|e| anyhow!("Failed to parse {}: {}", path.display(), e))?;
+
+        let mut shared = match serde_json::to_value(yaml)? {
+            Value::Object(map) => map,
+            Value::Null => Map::new(),
+            _ => return Err(anyhow!("{} must contain a mapping of variable names to values", path.display())),
+        };
+
+        let mut devices = HashMap::new();
+        if let Some(overrides) = shared.remove("devices") {
+            let overrides = match overrides {
+                Value::Object(map) => map,
+                _ => return Err(anyhow!("'devices' in {} must map hostnames to variables", path.display())),
+            };
+            for (hostname, vars) in overrides {
+                match vars {
+                    Value::Object(vars) => devices.insert(hostname.to_lowercase(), vars),
+                    _ => return Err(anyhow!("Variables for {} must be a mapping", hostname)),
+                };
+            }
+        }
+
+        Ok(TemplateVars { shared, devices })
+    }
+
+    /// Variables for one device: the shared ones with its overrides applied.
+    pub fn for_device(&self, hostname: Option<&str>) -> Map<String, Value> {
+        let mut vars = self.shared.clone();
+        if let Some(hostname) = hostname.map(str::to_lowercase) {
+            let short = hostname.split('.').next().unwrap_or_default().to_string();
+            if let Some(overrides) = self.devices.get(&hostname).or_else(|| self.devices.get(&short)) {
+                vars.extend(overrides.clone());
+            }
+        }
+        vars
+    }
+}
+
+/// Find a template by name, or by `<project>/<name>` when names repeat across projects.
+pub fn find_template<'a>(templates: &'a [TemplateSummary], name: &str) -> Result<&'a TemplateSummary> {
+    let (project, name) = match name.split_once('/') {
+        Some((project, name)) => (Some(project), name),
+        None => (None, name),
+    };
+    let matches: Vec<&TemplateSummary> = templates
+        .iter()
+        .filter(|template| template.name.as_deref().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+        .filter(|template| {
+            project.is_none_or(|project| {
+                template
+                    .project_name
+                    .as_deref()
+                    .is_some_and(|p| p.eq_ignore_ascii_case(project))
+            })
+        })
+        .collect();
+
+    match matches.as_slice() {
+        [template] => Ok(template),
+        [] => Err(anyhow!("No template named '{}'", name)),
+        _ => {
+            let projects: Vec<&str> = matches
+                .iter()
+                .filter_map(|template| template.project_name.as_deref())
+                .collect();
+            Err(anyhow!(
+                "Template '{}' exists in several projects ({}); use <project>/{}",
+                name,
+                projects.join(", "),
+                name
+            ))
+        }
+    }
+}
+
+/// Required parameters with no default or binding that `vars` does not set.
+pub fn missing_params(template: &Template, vars: &Map<String, Value>) -> Vec<String> {
+    let is_set = |value: &Option<String>| value.as_deref().is_some_and(|value| !value.is_empty());
+    template
+        .template_params
+        .iter()
+        .filter(|param| param.required.unwrap_or(false))
+        .filter(|param| !is_set(&param.default_value) && !is_set(&param.binding))
+        .filter_map(|param| param.parameter_name.clone())
+        .filter(|name| !vars.contains_key(name))
+        .collect()
+}
+
+pub struct Rendered {
+    pub text: String,
+    /// Variables referenced by the template but not given
+    pub unresolved: BTreeSet<String>,
+    /// Whether the template has loops or conditionals, which are left as-is
+    pub has_directives: bool,
+}
+
+/// Substitute variables into a Velocity or Jinja template. Only variable
+/// references are rendered; directives such as `#if` or `{% for %}` are
+/// kept in the output for Catalyst Center to evaluate.
+pub fn render(template: &Template, vars: &Map<String, Value>) -> Rendered {
+    let content = template.template_content.as_deref().unwrap_or_default();
+    let jinja = template.language.as_deref() == Some("JINJA");
+    let mut unresolved = BTreeSet::new();
+
+    let (pattern, directives) = if jinja {
+        (r"\{\{-?\s*([A-Za-z_][A-Za-z0-9_.]*)\s*(?:\|[^}]*)?-?\}\}", r"\{%")
+    } else {
+        (
+            r"\$(!)?(?:\{([A-Za-z_][A-Za-z0-9_-]*)\}|([A-Za-z_][A-Za-z0-9_-]*))",
+            r"#(?:if|elseif|else|end|foreach|set|macro|parse|include)\b",
+        )
+    };
+    let pattern = Regex::new(pattern).expect("valid template variable pattern");
+    let has_directives = Regex::new(directives).expect("valid directive pattern").is_match(content);
+
+    let text = pattern.replace_all(content, |caps: &Captures| {
+        let name = if jinja {
+            &caps[1]
+        } else {
+            caps.get(2).or_else(|| caps.get(3)).map_or("", |name| name.as_str())
+        };
+        match vars.get(name) {
+            Some(value) => value_text(value),
+            // Velocity's quiet references ($!name) render as nothing when unset
+            None if !jinja && caps.get(1).is_some() => String::new(),
+            None => {
+                unresolved.insert(name.to_string());
+                caps[0].to_string()
+            }
+        }
+    });
+
+    Rendered {
+        text: text.into_owned(),
+        unresolved,
+        has_directives,
+    }
+}
+
+/// A variable's value as it appears in rendered text.
+pub fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn template(language: &str, content: &str) -> Template {
+        serde_json::from_value(json!({
+            "language": language,
+            "templateContent": content,
+            "templateParams": [
+                { "parameterName": "hostname", "required": true },
+                { "parameterName": "vlan", "required": true, "defaultValue": "10" },
+                { "parameterName": "banner", "required": false },
+            ],
+        }))
+        .unwrap()
+    }
+
+    fn vars(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn renders_velocity_references() {
+        let template = template("VELOCITY", "hostname $hostname\nvlan ${vlan}\nbanner $!banner\nntp $ntp");
+        let rendered = render(&template, &vars(json!({ "hostname": "edge-1", "vlan": 20 })));
+
+        assert_eq!(rendered.text, "hostname edge-1\nvlan 20\nbanner \nntp $ntp");
+        assert_eq!(rendered.unresolved, BTreeSet::from(["ntp".to_string()]));
+        assert!(!rendered.has_directives);
+    }
+
+    #[test]
+    fn renders_jinja_references_and_keeps_directives() {
+        let template = template("JINJA", "{% if vlan %}vlan {{ vlan }}{% endif %}\nhostname {{hostname|upper}}");
+        let rendered = render(&template, &vars(json!({ "vlan": "30" })));
+
+        assert_eq!(rendered.text, "{% if vlan %}vlan 30{% endif %}\nhostname {{hostname|upper}}");
+        assert_eq!(rendered.unresolved, BTreeSet::from(["hostname".to_string()]));
+        assert!(rendered.has_directives);
+    }
+
+    #[test]
+    fn detects_velocity_directives() {
+        let template = template("VELOCITY", "#if($vlan)\nvlan $vlan\n#end");
+        assert!(render(&template, &Map::new()).has_directives);
+    }
+
+    #[test]
+    fn device_overrides_apply_by_short_hostname() {
+        let template_vars = TemplateVars {
+            shared: vars(json!({ "ntp": "10.0.0.1", "vlan": 10 })),
+            devices: HashMap::from([("edge-1".to_string(), vars(json!({ "ntp": "10.1.0.1" })))]),
+        };
+
+        let edge = template_vars.for_device(Some("EDGE-1.example.com"));
+        assert_eq!(edge["ntp"], "10.1.0.1");
+        assert_eq!(edge["vlan"], 10);
+        assert_eq!(template_vars.for_device(Some("edge-2"))["ntp"], "10.0.0.1");
+        assert_eq!(template_vars.for_device(None)["ntp"], "10.0.0.1");
+    }
+
+    #[test]
+    fn missing_params_ignores_defaults_and_given_vars() {
+        let template = template("VELOCITY", "");
+        assert_eq!(missing_params(&template, &Map::new()), ["hostname"]);
+        assert!(missing_params(&template, &vars(json!({ "hostname": "edge-1" }))).is_empty());
+    }
+}
@@ -24,45 +24,48 @@ use crate::api::devices::devicedetailenrichment::DeviceDetails;
 use crate::api::issues::getissuelist::{Issue as IssueListIssue, IssueListResponse};
 use crate::api::wireless::accesspointconfig::ApConfig;
 
+use crate::commands::show::table::TableArgs;
+use crate::helpers::{fields, output};
+
 use chrono::{DateTime, Utc};
+use log::error;
 use prettytable::{format, row, Table};
+use std::io::Write;
 
 pub fn current_timestamp() -> u64 {
     Utc::now().timestamp_millis() as u64
 }
 
+/// Ask a yes/no question on the terminal. Anything but `y` or `yes`,
+/// including end of input, is a no.
+///
+/// While output is captured the plan being confirmed has not been shown, so
+/// the question is refused and `--yes` is required instead.
+pub fn confirm(question: &str) -> bool {
+    if output::is_capturing() {
+        eprintln!("Output is filtered or redirected, so the plan was not shown. Re-run with --yes to proceed.");
+        return false;
+    }
+
+    print!("{} [y/N] ", question);
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    match std::io::stdin().read_line(&mut answer) {
+        Ok(_) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+        Err(_) => false,
+    }
+}
+
 // Function to print a list of devices
-pub fn print_devices(devices: Vec<AllDevices>) {
-    let mut table = Table::new();
-    table.add_row(row![
-        "Hostname",
-        "Management IP",
-        "Serial Number",
-        "MAC Address",
-        "Ethernet MAC Address",
-        "Platform ID",
-        "Software Version",
-        "Role"
-    ]);
-
-    for device in devices {
-        table.add_row(row![
-            device.hostname.unwrap_or_else(|| "N/A".to_string()),
-            device
-                .management_ip_address
-                .unwrap_or_else(|| "N/A".to_string()),
-            device.serial_number.unwrap_or_else(|| "N/A".to_string()),
-            device.mac_address.unwrap_or_else(|| "N/A".to_string()),
-            device
-                .ap_ethernet_mac_address
-                .unwrap_or_else(|| "N/A".to_string()),
-            device.platform_id.unwrap_or_else(|| "N/A".to_string()),
-            device.software_version.unwrap_or_else(|| "N/A".to_string()),
-            device.role.unwrap_or_else(|| "N/A".to_string()),
-        ]);
+pub fn print_devices(devices: Vec<AllDevices>, view: &TableArgs) {
+    match fields::build_table(&devices, view) {
+        Ok(mut table) => {
+            table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+            output::print_table(&table);
+        }
+        Err(e) => error!("{}", e),
     }
-    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
-    table.printstd();
 }
 
 // Function to print detailed information about a device
@@ -94,7 +97,7 @@ pub fn print_device_detail(device: AllDevices) {
     );
     // Add more fields as necessary
 
-    table.printstd();
+    output::print_table(&table);
 }
 
 // Function to print enriched device details
@@ -133,12 +136,21 @@ pub fn print_device_enrichment(device_details: DeviceDetails) {
     );
     // Add more fields as necessary
 
-    table.printstd();
+    output::print_table(&table);
 }
 
 // Function to print client detail with all fields
-pub fn print_client_detail(response: ClientDetailResponse) {
+pub fn print_client_detail(response: ClientDetailResponse, view: &TableArgs) {
     if let Some(detail) = response.detail {
+        // Explicit column options replace the full listing below
+        if !view.is_default() {
+            match fields::build_detail_table(&detail, view) {
+                Ok(table) => output::print_table(&table),
+                Err(e) => error!("{}", e),
+            }
+            return;
+        }
+
         let mut table = Table::new();
         table.add_row(row!["Field", "Value"]);
 
@@ -333,14 +345,14 @@ pub fn print_client_detail(response: ClientDetailResponse) {
             }
         }
 
-        table.printstd();
+        output::print_table(&table);
     } else {
-        println!("No client details available.");
+        outln!("No client details available.");
     }
 
     // Optionally, print ConnectionInfo and Topology
     if let Some(connection_info) = response.connectionInfo {
-        println!("\nConnection Info:");
+        outln!("\nConnection Info:");
         let mut table = Table::new();
         table.add_row(row!["Field", "Value"]);
 
@@ -376,11 +388,11 @@ pub fn print_client_detail(response: ClientDetailResponse) {
             add_field(&mut table, "Timestamp", None);
         }
 
-        table.printstd();
+        output::print_table(&table);
     }
 
     if let Some(topology) = response.topology {
-        println!("\nTopology Information:");
+        outln!("\nTopology Information:");
         // You can choose to display topology data as needed
         if let Some(nodes) = topology.nodes {
             for node in nodes {
@@ -415,7 +427,7 @@ pub fn print_client_detail(response: ClientDetailResponse) {
                     add_field(&mut table, "IPv6", Some(ipv6_list.join(", ")));
                 }
 
-                table.printstd();
+                output::print_table(&table);
             }
         }
 
@@ -427,53 +439,49 @@ fn add_field(table: &mut Table, field_name: &str, value: Option<String>) {
     table.add_row(row![field_name, value.unwrap_or_else(|| "N/A".to_string())]);
 }
 
-pub fn print_issue_list(response: IssueListResponse) {
+pub fn print_issue_list(response: IssueListResponse, view: &TableArgs) {
     if let Some(issues) = response.response {
-        let mut table = Table::new();
-        table.add_row(row![
-            "Issue ID",
-            "Name",
-            "Device ID",
-            "Device Role",
-            "Client MAC",
-            "Status",
-            "Priority",
-            "Category",
-            "Last Occurrence Time"
-        ]);
-
-        for issue in issues {
-            let last_occurrence =
-                issue
-                    .last_occurence_time
-                    .map_or("N/A".to_string(), |timestamp| {
-                        DateTime::from_timestamp_millis(timestamp)
-                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                            .unwrap_or_else(|| "Invalid Timestamp".to_string())
-                    });
-
-            table.add_row(row![
-                issue.issueId.clone().unwrap_or_else(|| "N/A".to_string()),
-                issue.name.clone().unwrap_or_else(|| "N/A".to_string()),
-                issue.deviceId.clone().unwrap_or_else(|| "N/A".to_string()),
-                issue
-                    .deviceRole
-                    .clone()
-                    .unwrap_or_else(|| "N/A".to_string()),
-                issue.clientMac.clone().unwrap_or_else(|| "N/A".to_string()),
-                issue.status.clone().unwrap_or_else(|| "N/A".to_string()),
-                issue.priority.clone().unwrap_or_else(|| "N/A".to_string()),
-                issue.category.clone().unwrap_or_else(|| "N/A".to_string()),
-                last_occurrence,
-            ]);
+        match fields::build_table(&issues, view) {
+            Ok(table) => output::print_table(&table),
+            Err(e) => error!("{}", e),
         }
-
-        table.printstd();
     } else {
-        println!("No issues found.");
+        outln!("No issues found.");
     }
 }
 
+// Function to print a single issue
+pub fn print_issue_detail(issue: IssueListIssue) {
+    let mut table = Table::new();
+    table.add_row(row!["Field", "Value"]);
+
+    add_field(&mut table, "Issue ID", issue.issueId);
+    add_field(&mut table, "Name", issue.name);
+    add_field(&mut table, "Status", issue.status);
+    add_field(&mut table, "Priority", issue.priority);
+    add_field(&mut table, "Category", issue.category);
+    add_field(&mut table, "AI Driven", issue.aiDriven);
+    add_field(&mut table, "Device ID", issue.deviceId);
+    add_field(&mut table, "Device Role", issue.deviceRole);
+    add_field(&mut table, "Client MAC", issue.clientMac);
+    add_field(&mut table, "Site ID", issue.siteId);
+    add_field(
+        &mut table,
+        "Occurrences",
+        issue.issue_occurence_count.map(|count| count.to_string()),
+    );
+    add_field(
+        &mut table,
+        "Last Occurrence",
+        issue
+            .last_occurence_time
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+    );
+
+    output::print_table(&table);
+}
+
 // Function to print AP configuration
 pub fn print_ap_config(ap_config: ApConfig) {
     let mut table = Table::new();
@@ -574,13 +582,13 @@ pub fn print_ap_config(ap_config: ApConfig) {
     }
 
     // Display the table
-    table.printstd();
+    output::print_table(&table);
 
 
     // Radio DTOs
     if let Some(radio_dtos) = ap_config.radioDTOs {
         for (i, radio) in radio_dtos.iter().enumerate() {
-            println!("\nRadio DTO [{}]:", i + 1);
+            outln!("\nRadio DTO [{}]:", i + 1);
             let mut radio_table = Table::new();
             radio_table.add_row(row!["Field", "Value"]);
 
@@ -715,18 +723,18 @@ pub fn print_ap_config(ap_config: ApConfig) {
             }
 
             // Display the radio table
-            radio_table.printstd();
+            output::print_table(&radio_table);
         }
     }
 }
 
 pub fn print_client_enrichment(response: ClientEnrichmentResponse) {
-    println!("Number of enrichment records: {}", response.0.len());
+    outln!("Number of enrichment records: {}", response.0.len());
 
     for enrichment in response.0 {
         // User Details
         if let Some(user_details) = enrichment.userDetails {
-            println!("User Details:");
+            outln!("User Details:");
             let mut table = Table::new();
             table.add_row(row!["Field", "Value"]);
 
@@ -867,11 +875,11 @@ pub fn print_client_enrichment(response: ClientEnrichmentResponse) {
 
             add_field(&mut table, "Port", user_details.port);
 
-            table.printstd();
+            output::print_table(&table);
 
             // Onboarding Details
             if let Some(onboarding) = user_details.onboarding {
-                println!("Onboarding Details:");
+                outln!("Onboarding Details:");
                 let mut table = Table::new();
                 table.add_row(row!["Field", "Value"]);
 
@@ -964,13 +972,13 @@ pub fn print_client_enrichment(response: ClientEnrichmentResponse) {
                     );
                 }
 
-                table.printstd();
+                output::print_table(&table);
             }
 
             // Connected Devices in UserDetails
             if let Some(connected_devices) = user_details.connectedDevice {
                 for (i, conn_dev) in connected_devices.iter().enumerate() {
-                    println!("\nConnected Device [{}]:", i + 1);
+                    outln!("\nConnected Device [{}]:", i + 1);
                     let mut table = Table::new();
                     table.add_row(row!["Field", "Value"]);
 
@@ -983,18 +991,18 @@ pub fn print_client_enrichment(response: ClientEnrichmentResponse) {
                     add_field(&mut table, "Band", conn_dev.band.clone());
                     add_field(&mut table, "Mode", conn_dev.mode.clone());
 
-                    table.printstd();
+                    output::print_table(&table);
                 }
             }
         } else {
-            println!("User Details Missing");
+            outln!("User Details Missing");
         }
 
         // Connected Devices in Enrichment
         if let Some(connected_devices) = enrichment.connectedDevice {
             for (i, conn_dev) in connected_devices.iter().enumerate() {
                 if let Some(device_details) = &conn_dev.deviceDetails {
-                    println!("\nConnected Device [{}]:", i + 1);
+                    outln!("\nConnected Device [{}]:", i + 1);
                     let mut table = Table::new();
                     table.add_row(row!["Field", "Value"]);
 
@@ -1026,18 +1034,18 @@ pub fn print_client_enrichment(response: ClientEnrichmentResponse) {
                     );
                     // Add more fields as needed
 
-                    table.printstd();
+                    output::print_table(&table);
                 }
             }
         } else {
-            println!("Connected Devices Missing");
+            outln!("Connected Devices Missing");
         }
 
         // Issue Details
         if let Some(issue_details) = enrichment.issueDetails {
             if let Some(issues) = issue_details.issue {
                 for (i, issue) in issues.iter().enumerate() {
-                    println!("\nIssue [{}]:", i + 1);
+                    outln!("\nIssue [{}]:", i + 1);
                     let mut table = Table::new();
                     table.add_row(row!["Field", "Value"]);
 
@@ -1078,13 +1086,13 @@ pub fn print_client_enrichment(response: ClientEnrichmentResponse) {
                     }
                     // Handle suggestedActions and impactedHosts if necessary
 
-                    table.printstd();
+                    output::print_table(&table);
                 }
             } else {
-                println!("No Issues Found");
+                outln!("No Issues Found");
             }
         } else {
-            println!("Issue Details Missing");
+            outln!("Issue Details Missing");
         }
     }
 }
@@ -0,0 +1,52 @@
+// src/helpers/wireless.rs
+
+use crate::api::devices::getdevicelist::AllDevices;
+use crate::api::wireless::getssids::{SsidDetails, BAND_SELECT};
+use crate::api::wireless::getwirelessprofiles::ProfileDetails;
+use crate::helpers::devicefilter;
+use anyhow::{anyhow, Result};
+
+pub fn is_wlc(device: &AllDevices) -> bool {
+    device.family.as_deref() == Some("Wireless Controller")
+}
+
+/// Access points joined to the controller, matched on its management IP.
+pub fn joined_aps<'a>(devices: &'a [AllDevices], wlc: &AllDevices) -> Vec<&'a AllDevices> {
+    let Some(wlc_ip) = wlc.management_ip_address.as_deref() else {
+        return Vec::new();
+    };
+    devices
+        .iter()
+        .filter(|device| device.family.as_deref() == Some("Unified AP"))
+        .filter(|device| device.associated_wlc_ip.as_deref() == Some(wlc_ip))
+        .collect()
+}
+
+/// Whether the SSID steers dual-band clients to 5 GHz.
+pub fn band_select(ssid: &SsidDetails) -> bool {
+    ssid.radio_policy.as_deref() == Some(BAND_SELECT)
+}
+
+/// Find an SSID by name, case-insensitively.
+pub fn find_ssid<'a>(ssids: &'a [SsidDetails], name: &str) -> Result<&'a SsidDetails> {
+    ssids
+        .iter()
+        .find(|ssid| ssid.name.as_deref().is_some_and(|ssid_name| ssid_name.eq_ignore_ascii_case(name)))
+        .ok_or_else(|| anyhow!("No SSID named '{}'", name))
+}
+
+/// Wireless profiles that carry the SSID.
+pub fn profiles_with_ssid<'a>(profiles: &'a [ProfileDetails], ssid: &str) -> Vec<&'a ProfileDetails> {
+    profiles.iter().filter(|profile| profile.ssid(ssid).is_some()).collect()
+}
+
+/// Whether the SSID is broadcast at `site`: a profile carrying it is assigned
+/// to the site, one of its parents or one of its children.
+pub fn ssid_at_site(profiles: &[ProfileDetails], ssid: &str, site: &str) -> bool {
+    profiles_with_ssid(profiles, ssid).iter().any(|profile| {
+        profile
+            .sites
+            .iter()
+            .any(|assigned| devicefilter::in_site(site, assigned) || devicefilter::in_site(assigned, site))
+    })
+}
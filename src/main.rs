@@ -1,13 +1,18 @@
 mod app;
+#[macro_use]
 mod helpers;
 mod api;
 
 mod commands;
 mod handlers;
 
+use clap::Parser;
 use commands::{Cli, route_command};
-use clap_repl::reedline::{DefaultPrompt, DefaultPromptSegment, FileBackedHistory};
+use clap_repl::reedline::{DefaultPrompt, DefaultPromptSegment, FileBackedHistory, Signal};
 use clap_repl::ClapEditor;
+use helpers::completion::{self, CatalyshCompleter};
+use helpers::{output, pager, pipeline};
+use log::error;
 use dirs::home_dir;
 use std::fs;
 use std::path::PathBuf;
@@ -31,30 +36,115 @@ fn perform_first_time_installation() -> Result<(), Box<dyn std::error::Error>> {
 
 #[allow(non_snake_case)]
 fn main() {
-    env_logger::init();
+    helpers::logger::init();
     // Initial check to confirm program is correctly installed
     if let Err(e) = perform_first_time_installation() {
         eprintln!("Error during installation: {}", e);
         return;
     }
 
+    // `catalysh <command>` runs a single command, e.g. `catalysh exporter`
+    if std::env::args().len() > 1 {
+        route_command(Cli::parse().command);
+        return;
+    }
+
     let prompt = DefaultPrompt {
         left_prompt: DefaultPromptSegment::Basic("catalysh".to_owned()),
         ..DefaultPrompt::default()
     };
 
     // Create the REPL
-    let rl = ClapEditor::<Cli>::builder()
-        .with_prompt(Box::new(prompt))
+    let mut rl = ClapEditor::<Cli>::builder()
+        .with_prompt(Box::new(prompt.clone()))
         .with_editor_hook(|reed| {
             reed.with_history(Box::new(
                 FileBackedHistory::with_file(10000, "/tmp/catalysh-cli-history".into()).unwrap(),
             ))
+            .with_completer(Box::new(CatalyshCompleter))
         })
         .build();
 
-    rl.repl(|cli| {
+    // Seed completions now and keep them current in the background
+    completion::load_from_cache();
+    completion::start_background_refresh();
+
+    // Lines are read here rather than through `ClapEditor::repl` so that
+    // output filters after `|` can be split off before clap sees the line.
+    loop {
+        match rl.get_editor().read_line(&prompt) {
+            Ok(Signal::Success(line)) => run_line(&line),
+            Ok(Signal::CtrlC) => continue,
+            Ok(Signal::CtrlD) => break,
+            Err(e) => {
+                error!("Failed to read input: {}", e);
+                break;
+            }
+        }
+    }
+}
+
+fn run_line(line: &str) {
+    let (mut command, mut filters) = pipeline::split_pipeline(line);
+    let last = filters.last_mut().unwrap_or(&mut command);
+    let (segment, redirect) = pipeline::split_redirect(last);
+    *last = segment;
+
+    if command.is_empty() {
+        return;
+    }
+
+    let args = match shlex::split(&command) {
+        Some(args) => args,
+        None => {
+            println!("Error: input was not valid and could not be processed");
+            return;
+        }
+    };
+
+    let cli = match Cli::try_parse_from(std::iter::once("").chain(args.iter().map(String::as_str))) {
+        Ok(cli) => cli,
+        Err(e) => {
+            let _ = e.print();
+            return;
+        }
+    };
+
+    // Check filters and the redirect target before running anything, so a
+    // typo after `|` cannot follow a command that already made changes
+    let filters = match pipeline::parse_filters(&filters) {
+        Ok(filters) => filters,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+    if let Some(Err(e)) = redirect.as_ref().map(|redirect| redirect.target()) {
+        error!("{}", e);
+        return;
+    }
+
+    let paged = redirect.is_none() && cli.command.is_pageable() && pager::is_active();
+    if filters.is_empty() && redirect.is_none() && !paged {
         route_command(cli.command);
-    });
+        return;
+    }
+
+    let blocks = output::capture(|| route_command(cli.command));
+    let blocks = match pipeline::apply_filters(&filters, blocks) {
+        Ok(blocks) => blocks,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    match redirect {
+        Some(redirect) => match redirect.write(&blocks) {
+            Ok(path) => println!("Output saved to {}", path.display()),
+            Err(e) => error!("Failed to save output: {}", e),
+        },
+        None => pager::print_paged(&blocks),
+    }
 }
 